@@ -0,0 +1,182 @@
+use crate::generator::banner::content_hash;
+use crate::generator::options::GenerateOptions;
+use crate::generator::ts_file_generator::{GenerateTsFilesResult, template_overrides_hash};
+use anyhow::Context;
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// The name of the cache manifest, stored in the output directory.
+pub const CACHE_FILE_NAME: &str = ".tauria-tsgen-cache.json";
+
+/// The content of the cache manifest.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+struct Manifest {
+    /// The version of tauria-tsgen that wrote the manifest.
+    version: String,
+    /// The options the cached results were generated with.
+    options: String,
+    /// The cached results, keyed by the input file path relative to the input directory.
+    entries: BTreeMap<String, CacheEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct CacheEntry {
+    /// The hash of the content of the input file.
+    hash: u64,
+    /// The output name the file was generated with (see `assign_output_names`).
+    file_name: String,
+    result: GenerateTsFilesResult,
+}
+
+/// Caches the results of `generate_ts_files` between runs, so unchanged input files are not
+/// parsed and rendered again.
+///
+/// The cache is invalidated as a whole when the tool version, the generate options or the
+/// overriding templates change.
+pub struct GenerationCache {
+    path: PathBuf,
+    output_dir: PathBuf,
+    loaded: Manifest,
+    current: Manifest,
+}
+
+impl GenerationCache {
+    /// Loads the cache manifest from `output_dir`. A missing or unreadable manifest yields an
+    /// empty cache.
+    pub fn load(output_dir: &Path, options: &GenerateOptions) -> Self {
+        let path = output_dir.join(CACHE_FILE_NAME);
+        let current = Manifest {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            // Debug 出力は安定した形式ではないため、シリアライズしたオプションをキーにする
+            options: format!(
+                "{} templates={}",
+                serde_json::to_string(options).unwrap_or_default(),
+                template_overrides_hash(options.templates_dir.as_deref())
+            ),
+            entries: BTreeMap::new(),
+        };
+        let loaded = match std::fs::read_to_string(&path) {
+            Ok(content) => match serde_json::from_str::<Manifest>(&content) {
+                Ok(manifest)
+                    if manifest.version == current.version
+                        && manifest.options == current.options =>
+                {
+                    manifest
+                }
+                Ok(_) => {
+                    debug!(
+                        "Cache was written by another version or with other options, ignoring it"
+                    );
+                    Manifest::default()
+                }
+                Err(e) => {
+                    warn!("Ignoring unreadable cache {}: {e}", path.display());
+                    Manifest::default()
+                }
+            },
+            Err(_) => Manifest::default(),
+        };
+        Self {
+            path,
+            output_dir: output_dir.to_path_buf(),
+            loaded,
+            current,
+        }
+    }
+
+    /// Returns the cached result of an input file if its content is unchanged and all of the
+    /// files generated from it still exist.
+    pub fn get(&self, key: &str, file_name: &str, code: &str) -> Option<GenerateTsFilesResult> {
+        let entry = self.loaded.entries.get(key)?;
+        if entry.hash != content_hash(code) || entry.file_name != file_name {
+            return None;
+        }
+        if !entry
+            .result
+            .files
+            .iter()
+            .all(|file| self.output_dir.join(&file.path).exists())
+        {
+            return None;
+        }
+        Some(entry.result.clone())
+    }
+
+    /// Records the result of an input file processed in this run.
+    ///
+    /// Files that are not recorded in a run are dropped from the manifest when it is saved.
+    pub fn insert(
+        &mut self,
+        key: String,
+        file_name: &str,
+        code: &str,
+        result: &GenerateTsFilesResult,
+    ) {
+        self.current.entries.insert(
+            key,
+            CacheEntry {
+                hash: content_hash(code),
+                file_name: file_name.to_string(),
+                result: result.clone(),
+            },
+        );
+    }
+
+    /// Writes the manifest, unless it is unchanged since it was loaded.
+    pub fn save(&self) -> anyhow::Result<()> {
+        if self.current == self.loaded {
+            return Ok(());
+        }
+        let content = serde_json::to_string(&self.current)?;
+        std::fs::write(&self.path, content).context("Failed to write cache file")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::ts_file_generator::generate_ts_files;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_generation_cache() {
+        let output_dir = tempdir().expect("Failed to create temp output dir");
+        let options = GenerateOptions::default();
+        let code = "#[tauri::command]\nfn greet() -> String { String::new() }";
+        let result = generate_ts_files(code, "greet", &options).unwrap();
+
+        let mut cache = GenerationCache::load(output_dir.path(), &options);
+        assert!(cache.get("greet.rs", "greet", code).is_none());
+        cache.insert("greet.rs".to_string(), "greet", code, &result);
+        cache.save().unwrap();
+
+        // 生成ファイルが存在しない場合は使わない
+        let cache = GenerationCache::load(output_dir.path(), &options);
+        assert!(cache.get("greet.rs", "greet", code).is_none());
+        crate::generator::ts_file_generator::write_generated_files(
+            output_dir.path(),
+            &result.files,
+        )
+        .unwrap();
+        assert_eq!(cache.get("greet.rs", "greet", code), Some(result));
+        assert!(cache.get("greet.rs", "greet", "// changed").is_none());
+        assert!(cache.get("greet.rs", "other", code).is_none());
+
+        let other_options = GenerateOptions {
+            generate_mock_api: true,
+            ..Default::default()
+        };
+        let cache = GenerationCache::load(output_dir.path(), &other_options);
+        assert!(cache.get("greet.rs", "greet", code).is_none());
+
+        // use_cache はキャッシュの内容に影響しないため、キーに含めない
+        let cached_options = GenerateOptions {
+            use_cache: true,
+            ..Default::default()
+        };
+        let cache = GenerationCache::load(output_dir.path(), &cached_options);
+        assert!(cache.get("greet.rs", "greet", code).is_some());
+    }
+}
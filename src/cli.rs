@@ -1,29 +1,209 @@
+use crate::generator::options::{
+    ApiStyle, Framework, OptionRepr, OutputDirs, OutputFormat, SortOrder, TauriVersion,
+};
 use anyhow::Context;
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use serde::{Deserialize, Serialize};
 use std::fs;
+use std::path::Path;
 
 /// Command-line interface (CLI) arguments for the tauria-tsgen application.
-#[derive(Parser, Debug)]
+#[derive(Parser, Debug, Default)]
 #[command(author, version, about, long_about = None)]
 pub struct Cli {
-    /// Path to the configuration file. You can set the input and output directories in JSON format.
+    /// Path to the configuration file. You can set the input and output directories in JSON,
+    /// TOML or YAML format, chosen by the file extension (`.json`, `.toml`, `.yaml`/`.yml`).
     #[arg(short, long, value_name = "FILE")]
     pub config: Option<String>,
 
-    /// Path to the directory containing the input Rust code.
-    /// This argument is required if no configuration file is specified.
-    #[arg(long, value_name = "DIR")]
+    /// Path to the directory containing the input Rust code, a single `.rs` file, or a glob
+    /// such as `src/commands/**/*.rs`.
+    /// This argument is required if no configuration file is specified, and overrides the
+    /// `input_path` of the configuration file otherwise.
+    #[arg(long, value_name = "PATH")]
     pub input_path: Option<String>,
 
     /// Path to the directory where the generated TypeScript files will be output.
-    /// This argument is required if no configuration file is specified.
+    /// This argument is required if no configuration file is specified, and overrides the
+    /// `output_path` of the configuration file otherwise.
     #[arg(long, value_name = "DIR")]
     pub output_path: Option<String>,
 
     /// Specify this flag to generate mock API files.
     #[arg(long)]
     pub mock_api: bool,
+
+    /// Export an `api` object from the `tauria-api` and `mock-api` index files that groups the
+    /// commands by source file (e.g. `api.userCommands.getUserData()`).
+    #[arg(long)]
+    pub api_object: bool,
+
+    /// Split the user-defined types into one `interface/types/<PascalName>.ts` per Rust file,
+    /// re-exported by `interface/types/index.ts`, instead of a single `index.ts`.
+    #[arg(long)]
+    pub split_types: bool,
+
+    /// The shape of the command wrappers: `functions` (a `create<FileName>()` factory function,
+    /// the default) or `class` (also an exported `<FileName>Api` class with a public constructor,
+    /// e.g. for dependency injection).
+    #[arg(long, value_enum, value_name = "STYLE", default_value_t)]
+    pub api_style: ApiStyle,
+
+    /// Generate additional bindings for the given frontend framework (e.g. `pinia` stores).
+    #[arg(long, value_enum, value_name = "FRAMEWORK")]
+    pub framework: Option<Framework>,
+
+    /// Warn about serde types that are defined but not used by any command or event.
+    #[arg(long)]
+    pub warn_unused_types: bool,
+
+    /// Warn about commands that are missing from `tauri::generate_handler![...]`, and about
+    /// registered commands that were not found in the input files.
+    #[arg(long)]
+    pub check_handler: bool,
+
+    /// Fail when a type cannot be resolved instead of falling back to `any` or `unknown`, or
+    /// when a command argument is left out because its type does not derive `Deserialize`.
+    #[arg(long)]
+    pub strict: bool,
+
+    /// Precede each generated command and type declaration with a comment naming the Rust file
+    /// and line it was generated from (e.g. `// from user.rs:42`).
+    #[arg(long)]
+    pub source_lines: bool,
+
+    /// Make the command wrappers throw a descriptive error when a required argument is
+    /// `undefined`, instead of passing it on to `invoke`.
+    #[arg(long)]
+    pub validate_args: bool,
+
+    /// Keep running and regenerate the output whenever a `.rs` file in the input directory changes.
+    #[arg(long)]
+    pub watch: bool,
+
+    /// Bundle all types, command interfaces, command wrappers and event handlers into this single
+    /// `.ts` file instead of generating the output directory tree.
+    /// When set, `--output-path` is optional.
+    #[arg(long, value_name = "FILE")]
+    pub single_file: Option<String>,
+
+    /// Skip `.rs` files whose path relative to the input directory matches this glob
+    /// (e.g. `tests/**` or `*_internal.rs`). Can be given multiple times.
+    #[arg(long, value_name = "GLOB")]
+    pub exclude: Vec<String>,
+
+    /// Map `u64`, `i64`, `u128` and `i128` to `bigint` instead of `number`.
+    /// Use this when the values can exceed `Number.MAX_SAFE_INTEGER`.
+    #[arg(long)]
+    pub bigint_for_64: bool,
+
+    /// Map `serde_json::Value` to `any` instead of `unknown`.
+    #[arg(long)]
+    pub json_value_as_any: bool,
+
+    /// How `Option<T>` is rendered: `null` (`T | null`), `undefined` (`T | undefined`) or
+    /// `optional` (the default; `name?: T` for struct fields and `T | undefined` elsewhere).
+    #[arg(long, value_enum, value_name = "REPR")]
+    pub option_repr: Option<OptionRepr>,
+
+    /// The module syntax of the generated files: `esm` (`import`/`export`, the default) or
+    /// `cjs` (`import T = require(...)` for the type namespace).
+    #[arg(long, value_enum, value_name = "FORMAT")]
+    pub output_format: Option<OutputFormat>,
+
+    /// The major version of Tauri the bindings target: `2` (the default) or `1`. Selects the
+    /// module `invoke` is imported from (`@tauri-apps/api/core` or `@tauri-apps/api/tauri`) and
+    /// whether command byte buffers are `Uint8Array` (v2) or `number[]` (v1).
+    #[arg(long, value_enum, value_name = "VERSION")]
+    pub tauri_version: Option<TauriVersion>,
+
+    /// The module the command wrappers import `invoke` from, e.g. a path alias of a local
+    /// re-export, instead of the module of `--tauri-version`. The value is used verbatim in
+    /// every generated file. Overrides `invoke_import` of the config file.
+    #[arg(long, value_name = "MODULE")]
+    pub invoke_import: Option<String>,
+
+    /// Generate `.d.ts` declaration files without runtime code instead of `.ts` files.
+    /// Mock API and framework bindings are not generated in this mode.
+    #[arg(long)]
+    pub declarations_only: bool,
+
+    /// Normalize the blank lines, indentation and trailing newline of the generated files.
+    #[arg(long)]
+    pub format: bool,
+
+    /// A directory of Tera templates (e.g. `tauria_api.tera`) overriding the embedded templates.
+    /// Templates missing from the directory fall back to the embedded ones.
+    #[arg(long, value_name = "DIR")]
+    pub templates: Option<String>,
+
+    /// List the files that would be generated without writing anything.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// The order of commands, types and event handlers in the generated files: `alphabetical`
+    /// (sorted by name, the default) or `source` (in the order of declaration).
+    #[arg(long, value_enum, value_name = "ORDER")]
+    pub sort_order: Option<SortOrder>,
+
+    /// Record the hash of each input file in `.tauria-tsgen-cache.json` in the output directory,
+    /// and skip processing the input files that are unchanged since the previous cached run.
+    #[arg(long)]
+    pub cache: bool,
+
+    /// Deprecated: the cache is off unless `--cache` is given, so this flag has no effect.
+    #[arg(long, hide = true, conflicts_with = "cache")]
+    pub no_cache: bool,
+
+    /// Read a single Rust source file from stdin instead of scanning `--input-path`, e.g. from
+    /// an editor save hook. Requires `--name`.
+    #[arg(long, requires = "name", conflicts_with_all = ["watch", "single_file"])]
+    pub stdin: bool,
+
+    /// The base name of the source read with `--stdin` (e.g. `user` for `user.rs`), used to
+    /// name the generated files.
+    #[arg(long, value_name = "NAME", requires = "stdin")]
+    pub name: Option<String>,
+
+    /// Print a summary of each run to stdout: `text` for people or `json` for CI.
+    #[arg(long, value_enum, value_name = "FORMAT")]
+    pub report: Option<ReportFormat>,
+
+    /// Print the resolved configuration (the config file merged with the command-line
+    /// arguments) as JSON and exit without generating anything.
+    #[arg(long)]
+    pub print_config: bool,
+
+    /// Only log warnings and errors.
+    #[arg(short, long, conflicts_with = "verbose")]
+    pub quiet: bool,
+
+    /// Also log debug messages, e.g. how each type was resolved.
+    #[arg(short, long)]
+    pub verbose: bool,
+}
+
+impl Cli {
+    /// The log level set by `--quiet` or `--verbose`, overriding `RUST_LOG`, or `None` if
+    /// neither is given.
+    pub fn log_level(&self) -> Option<log::LevelFilter> {
+        if self.quiet {
+            Some(log::LevelFilter::Warn)
+        } else if self.verbose {
+            Some(log::LevelFilter::Debug)
+        } else {
+            None
+        }
+    }
+}
+
+/// The format of the summary printed with `--report`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ReportFormat {
+    /// A short human-readable summary.
+    Text,
+    /// The `GenerateSummary` as a JSON object.
+    Json,
 }
 
 /// Configuration structure for input and output paths.
@@ -31,36 +211,157 @@ pub struct Cli {
 pub struct Config {
     pub input_path: String,
     pub output_path: String,
+    /// Globs of input files to skip, matched against the path relative to `input_path`.
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    /// Whether 64-bit and 128-bit integers are mapped to `bigint`.
+    #[serde(default)]
+    pub bigint_for_64: bool,
+    /// Whether `serde_json::Value` is mapped to `any` instead of `unknown`.
+    #[serde(default)]
+    pub json_value_as_any: bool,
+    /// How `Option<T>` is represented.
+    #[serde(default)]
+    pub option_repr: OptionRepr,
+    /// The module syntax of the generated files.
+    #[serde(default)]
+    pub output_format: OutputFormat,
+    /// The major version of Tauri the bindings target.
+    #[serde(default)]
+    pub tauri_version: TauriVersion,
+    /// The module the command wrappers import `invoke` from, if not the one of `tauri_version`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invoke_import: Option<String>,
+    /// The order of commands, types and event handlers in the generated files.
+    #[serde(default)]
+    pub sort_order: SortOrder,
+    /// The names of the `interface`, `tauria-api` and `mock-api` output directories.
+    #[serde(default)]
+    pub output_dirs: OutputDirs,
+}
+
+impl Config {
+    /// Renders the configuration as pretty-printed JSON, as printed with `--print-config`.
+    pub fn to_pretty_json(&self) -> anyhow::Result<String> {
+        serde_json::to_string_pretty(self).context("Failed to serialize the configuration")
+    }
 }
 
 /// Loads the configuration from the CLI arguments or a config file.
 ///
 /// This function first checks for a `--config` file path. If it exists, it reads and
-/// parses the JSON configuration file.
+/// parses the configuration file as JSON, TOML or YAML depending on its extension.
+/// `--input-path` and `--output-path` override the paths of the config file.
 ///
 /// If no config file is provided, it checks for `--input-path` and `--output-path`
-/// arguments to construct the configuration.
+/// arguments to construct the configuration. With `--single-file`, the output path
+/// defaults to the directory of the single file.
+///
+/// `--exclude` globs are added to the `exclude` list of the config file, if any, and
+/// `--bigint-for-64` and `--json-value-as-any` enable their options even if the config file
+/// does not, and `--output-format`, `--tauri-version`, `--invoke-import`, `--sort-order` and
+/// `--option-repr` override the config file's `output_format`, `tauri_version`, `invoke_import`,
+/// `sort_order` and `option_repr`.
 ///
 /// # Errors
 ///
 /// This function will return an `Err` if:
-/// - The config file cannot be read or parsed.
+/// - The config file cannot be read or parsed, or its extension is not supported.
 /// - Neither a config file nor the input/output path arguments are provided.
 pub fn load_config(cli: &Cli) -> anyhow::Result<Config> {
     if let Some(config_path) = &cli.config {
         let config_content =
             fs::read_to_string(config_path).context("Could not read config file")?;
-        serde_json::from_str(&config_content).context("Could not parse config file")
-    } else if let (Some(input), Some(output)) = (&cli.input_path, &cli.output_path) {
+        let mut config = parse_config(Path::new(config_path), &config_content)?;
+        // コマンドライン引数で指定された値は設定ファイルの値より優先する
+        if let Some(input_path) = &cli.input_path {
+            config.input_path = input_path.clone();
+        }
+        if let Some(output_path) = &cli.output_path {
+            config.output_path = output_path.clone();
+        }
+        config.exclude.extend(cli.exclude.iter().cloned());
+        config.bigint_for_64 |= cli.bigint_for_64;
+        config.json_value_as_any |= cli.json_value_as_any;
+        if let Some(output_format) = cli.output_format {
+            config.output_format = output_format;
+        }
+        if let Some(tauri_version) = cli.tauri_version {
+            config.tauri_version = tauri_version;
+        }
+        if cli.invoke_import.is_some() {
+            config.invoke_import = cli.invoke_import.clone();
+        }
+        if let Some(sort_order) = cli.sort_order {
+            config.sort_order = sort_order;
+        }
+        if let Some(option_repr) = cli.option_repr {
+            config.option_repr = option_repr;
+        }
+        Ok(config)
+    } else if let (Some(input), Some(output)) = (
+        cli.input_path.as_deref().or(cli.stdin.then_some("-")),
+        &cli.output_path,
+    ) {
         Ok(Config {
-            input_path: input.clone(),
+            input_path: input.to_string(),
             output_path: output.clone(),
+            exclude: cli.exclude.clone(),
+            bigint_for_64: cli.bigint_for_64,
+            json_value_as_any: cli.json_value_as_any,
+            option_repr: cli.option_repr.unwrap_or_default(),
+            output_format: cli.output_format.unwrap_or_default(),
+            tauri_version: cli.tauri_version.unwrap_or_default(),
+            invoke_import: cli.invoke_import.clone(),
+            sort_order: cli.sort_order.unwrap_or_default(),
+            output_dirs: OutputDirs::default(),
+        })
+    } else if let (Some(input), Some(single_file)) = (&cli.input_path, &cli.single_file) {
+        let output_dir = Path::new(single_file)
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        Ok(Config {
+            input_path: input.clone(),
+            output_path: output_dir.to_string_lossy().into_owned(),
+            exclude: cli.exclude.clone(),
+            bigint_for_64: cli.bigint_for_64,
+            json_value_as_any: cli.json_value_as_any,
+            option_repr: cli.option_repr.unwrap_or_default(),
+            output_format: cli.output_format.unwrap_or_default(),
+            tauri_version: cli.tauri_version.unwrap_or_default(),
+            invoke_import: cli.invoke_import.clone(),
+            sort_order: cli.sort_order.unwrap_or_default(),
+            output_dirs: OutputDirs::default(),
         })
     } else {
         anyhow::bail!("Either --config or both --input-path and --output-path must be provided.");
     }
 }
 
+/// Parses the content of a config file, choosing the format from the file extension.
+///
+/// `.json`, `.toml`, `.yaml` and `.yml` files are supported. Files without an extension
+/// are parsed as JSON, as they were before other formats were supported.
+fn parse_config(config_path: &Path, config_content: &str) -> anyhow::Result<Config> {
+    let extension = config_path
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_ascii_lowercase());
+    match extension.as_deref() {
+        None | Some("json") => {
+            serde_json::from_str(config_content).context("Could not parse config file")
+        }
+        Some("toml") => toml::from_str(config_content).context("Could not parse config file"),
+        Some("yaml" | "yml") => {
+            serde_norway::from_str(config_content).context("Could not parse config file")
+        }
+        _ => anyhow::bail!(
+            "Unsupported config file format: {}. Use a .json, .toml, .yaml or .yml file.",
+            config_path.display()
+        ),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -78,7 +379,7 @@ mod tests {
             config: Some(config_path),
             input_path: None,
             output_path: None,
-            mock_api: false,
+            ..Default::default()
         };
         let config = load_config(&cli).expect("Failed to load config from file");
         assert_eq!(config.input_path, "/tmp/input");
@@ -91,20 +392,327 @@ mod tests {
             config: None,
             input_path: Some("/tmp/input_arg".to_string()),
             output_path: Some("/tmp/output_arg".to_string()),
-            mock_api: false,
+            ..Default::default()
         };
         let config = load_config(&cli).expect("Failed to load config from args");
         assert_eq!(config.input_path, "/tmp/input_arg");
         assert_eq!(config.output_path, "/tmp/output_arg");
     }
 
+    #[test]
+    fn test_log_level() {
+        let parse = |args: &[&str]| {
+            Cli::try_parse_from([&["tauria-tsgen", "--input-path", "src"], args].concat())
+        };
+        assert_eq!(parse(&[]).unwrap().log_level(), None);
+        assert_eq!(
+            parse(&["--quiet"]).unwrap().log_level(),
+            Some(log::LevelFilter::Warn)
+        );
+        assert_eq!(
+            parse(&["-v"]).unwrap().log_level(),
+            Some(log::LevelFilter::Debug)
+        );
+        assert!(parse(&["-q", "--verbose"]).is_err());
+    }
+
+    #[test]
+    fn test_print_config_json_matches_merged_config() {
+        let config_file = write_config_file(
+            ".toml",
+            "input_path = \"src\"\noutput_path = \"bindings\"\nexclude = [\"tests/**\"]\n\n[output_dirs]\nmock_api = \"mocks\"\n",
+        );
+        let cli = Cli {
+            config: Some(config_file.path().to_str().unwrap().to_string()),
+            exclude: vec!["*_internal.rs".to_string()],
+            sort_order: Some(SortOrder::Source),
+            print_config: true,
+            ..Default::default()
+        };
+        let printed = load_config(&cli).unwrap().to_pretty_json().unwrap();
+        let printed: serde_json::Value = serde_json::from_str(&printed).unwrap();
+        assert_eq!(
+            printed,
+            serde_json::json!({
+                "input_path": "src",
+                "output_path": "bindings",
+                "exclude": ["tests/**", "*_internal.rs"],
+                "bigint_for_64": false,
+                "json_value_as_any": false,
+                "option_repr": "optional",
+                "output_format": "esm",
+                "tauri_version": "2",
+                "sort_order": "source",
+                "output_dirs": {
+                    "interface": "interface",
+                    "tauria_api": "tauria-api",
+                    "mock_api": "mocks",
+                },
+            })
+        );
+    }
+
+    fn write_config_file(suffix: &str, content: &str) -> NamedTempFile {
+        let mut temp_file = tempfile::Builder::new()
+            .suffix(suffix)
+            .tempfile()
+            .expect("Failed to create temp file");
+        write!(temp_file, "{}", content).expect("Failed to write to temp file");
+        temp_file
+    }
+
+    #[test]
+    fn test_load_config_from_file_formats() {
+        let json = write_config_file(
+            ".json",
+            r#"{ "input_path": "/tmp/input", "output_path": "/tmp/output" }"#,
+        );
+        let toml = write_config_file(
+            ".toml",
+            "input_path = \"/tmp/input\"\noutput_path = \"/tmp/output\"\n",
+        );
+        let yaml = write_config_file(
+            ".yaml",
+            "input_path: /tmp/input\noutput_path: /tmp/output\n",
+        );
+        let yml = write_config_file(".YML", "input_path: /tmp/input\noutput_path: /tmp/output\n");
+
+        for temp_file in [&json, &toml, &yaml, &yml] {
+            let cli = Cli {
+                config: Some(temp_file.path().to_str().unwrap().to_string()),
+                ..Default::default()
+            };
+            let config = load_config(&cli)
+                .unwrap_or_else(|e| panic!("Failed to load {:?}: {e:?}", temp_file.path()));
+            assert_eq!(config.input_path, "/tmp/input");
+            assert_eq!(config.output_path, "/tmp/output");
+        }
+    }
+
+    #[test]
+    fn test_load_config_exclude() {
+        let temp_file = write_config_file(
+            ".json",
+            r#"{ "input_path": "/tmp/input", "output_path": "/tmp/output", "exclude": ["a.rs"] }"#,
+        );
+        let cli = Cli {
+            config: Some(temp_file.path().to_str().unwrap().to_string()),
+            exclude: vec!["b/**".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(load_config(&cli).unwrap().exclude, vec!["a.rs", "b/**"]);
+
+        let cli = Cli {
+            input_path: Some("/tmp/input".to_string()),
+            output_path: Some("/tmp/output".to_string()),
+            exclude: vec!["b/**".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(load_config(&cli).unwrap().exclude, vec!["b/**"]);
+    }
+
+    #[test]
+    fn test_load_config_bigint_for_64() {
+        let temp_file = write_config_file(
+            ".json",
+            r#"{ "input_path": "/tmp/input", "output_path": "/tmp/output", "bigint_for_64": true }"#,
+        );
+        let cli = Cli {
+            config: Some(temp_file.path().to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+        assert!(load_config(&cli).unwrap().bigint_for_64);
+
+        let cli = Cli {
+            input_path: Some("/tmp/input".to_string()),
+            output_path: Some("/tmp/output".to_string()),
+            ..Default::default()
+        };
+        assert!(!load_config(&cli).unwrap().bigint_for_64);
+
+        let cli = Cli {
+            bigint_for_64: true,
+            ..cli
+        };
+        assert!(load_config(&cli).unwrap().bigint_for_64);
+    }
+
+    #[test]
+    fn test_load_config_option_repr() {
+        let temp_file = write_config_file(
+            ".toml",
+            "input_path = \"/tmp/input\"\noutput_path = \"/tmp/output\"\noption_repr = \"null\"\n",
+        );
+        let cli = Cli {
+            config: Some(temp_file.path().to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+        assert_eq!(load_config(&cli).unwrap().option_repr, OptionRepr::Null);
+
+        let cli = Cli {
+            option_repr: Some(OptionRepr::Undefined),
+            ..cli
+        };
+        assert_eq!(
+            load_config(&cli).unwrap().option_repr,
+            OptionRepr::Undefined
+        );
+
+        let cli = Cli {
+            input_path: Some("/tmp/input".to_string()),
+            output_path: Some("/tmp/output".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(load_config(&cli).unwrap().option_repr, OptionRepr::Optional);
+    }
+
+    #[test]
+    fn test_load_config_invoke_import() {
+        let temp_file = write_config_file(
+            ".json",
+            r#"{ "input_path": "/tmp/input", "output_path": "/tmp/output", "invoke_import": "@tauri-apps/api/tauri" }"#,
+        );
+        let cli = Cli {
+            config: Some(temp_file.path().to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            load_config(&cli).unwrap().invoke_import.as_deref(),
+            Some("@tauri-apps/api/tauri")
+        );
+
+        let cli = Cli {
+            invoke_import: Some("@/lib/tauri".to_string()),
+            ..cli
+        };
+        assert_eq!(
+            load_config(&cli).unwrap().invoke_import.as_deref(),
+            Some("@/lib/tauri")
+        );
+
+        let cli = Cli {
+            input_path: Some("/tmp/input".to_string()),
+            output_path: Some("/tmp/output".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(load_config(&cli).unwrap().invoke_import, None);
+    }
+
+    #[test]
+    fn test_load_config_tauri_version() {
+        let temp_file = write_config_file(
+            ".yaml",
+            "input_path: /tmp/input\noutput_path: /tmp/output\ntauri_version: \"1\"\n",
+        );
+        let cli = Cli {
+            config: Some(temp_file.path().to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+        assert_eq!(load_config(&cli).unwrap().tauri_version, TauriVersion::V1);
+
+        let cli = Cli::try_parse_from(["tauria-tsgen", "--tauri-version", "2"]).unwrap();
+        assert_eq!(cli.tauri_version, Some(TauriVersion::V2));
+        assert!(Cli::try_parse_from(["tauria-tsgen", "--tauri-version", "3"]).is_err());
+    }
+
+    #[test]
+    fn test_load_config_cli_paths_override_config_file() {
+        let temp_file = write_config_file(
+            ".json",
+            r#"{ "input_path": "/tmp/input", "output_path": "/tmp/output", "sort_order": "source" }"#,
+        );
+        let cli = Cli {
+            config: Some(temp_file.path().to_str().unwrap().to_string()),
+            output_path: Some("/tmp/other_output".to_string()),
+            ..Default::default()
+        };
+        let config = load_config(&cli).unwrap();
+        assert_eq!(config.input_path, "/tmp/input");
+        assert_eq!(config.output_path, "/tmp/other_output");
+        // 上書きされない設定はそのまま
+        assert_eq!(config.sort_order, SortOrder::Source);
+
+        let cli = Cli {
+            input_path: Some("/tmp/other_input".to_string()),
+            ..cli
+        };
+        let config = load_config(&cli).unwrap();
+        assert_eq!(config.input_path, "/tmp/other_input");
+        assert_eq!(config.output_path, "/tmp/other_output");
+    }
+
+    #[test]
+    fn test_load_config_output_format() {
+        let temp_file = write_config_file(
+            ".toml",
+            "input_path = \"/tmp/input\"\noutput_path = \"/tmp/output\"\noutput_format = \"cjs\"\n",
+        );
+        let cli = Cli {
+            config: Some(temp_file.path().to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+        assert_eq!(load_config(&cli).unwrap().output_format, OutputFormat::Cjs);
+
+        let cli = Cli {
+            output_format: Some(OutputFormat::Esm),
+            ..cli
+        };
+        assert_eq!(load_config(&cli).unwrap().output_format, OutputFormat::Esm);
+
+        let cli = Cli {
+            input_path: Some("/tmp/input".to_string()),
+            output_path: Some("/tmp/output".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(load_config(&cli).unwrap().output_format, OutputFormat::Esm);
+    }
+
+    #[test]
+    fn test_load_config_output_dirs() {
+        let temp_file = write_config_file(
+            ".toml",
+            "input_path = \"/tmp/input\"\noutput_path = \"/tmp/output\"\n\n[output_dirs]\ntauria_api = \"generated/api\"\n",
+        );
+        let cli = Cli {
+            config: Some(temp_file.path().to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+        let output_dirs = load_config(&cli).unwrap().output_dirs;
+        assert_eq!(output_dirs.tauria_api, "generated/api");
+        // 指定されていないディレクトリは既定の名前のまま
+        assert_eq!(output_dirs.interface, "interface");
+        assert_eq!(output_dirs.mock_api, "mock-api");
+    }
+
+    #[test]
+    fn test_load_config_invalid_toml() {
+        let temp_file = write_config_file(".toml", "input_path = ");
+        let cli = Cli {
+            config: Some(temp_file.path().to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+        let err = load_config(&cli).unwrap_err();
+        assert!(err.to_string().contains("Could not parse config file"));
+    }
+
+    #[test]
+    fn test_load_config_unsupported_extension() {
+        let temp_file = write_config_file(".ini", "input_path=/tmp/input");
+        let cli = Cli {
+            config: Some(temp_file.path().to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+        let err = load_config(&cli).unwrap_err();
+        assert!(err.to_string().contains("Unsupported config file format"));
+    }
+
     #[test]
     fn test_load_config_file_not_found() {
         let cli = Cli {
             config: Some("/nonexistent/path/config.json".to_string()),
             input_path: None,
             output_path: None,
-            mock_api: false,
+            ..Default::default()
         };
         let err = load_config(&cli).unwrap_err();
         assert!(err.to_string().contains("Could not read config file"));
@@ -121,7 +729,7 @@ mod tests {
             config: Some(config_path),
             input_path: None,
             output_path: None,
-            mock_api: false,
+            ..Default::default()
         };
         let err = load_config(&cli).unwrap_err();
         assert!(err.to_string().contains("Could not parse config file"));
@@ -133,7 +741,7 @@ mod tests {
             config: None,
             input_path: None,
             output_path: None,
-            mock_api: false,
+            ..Default::default()
         };
         let err = load_config(&cli).unwrap_err();
         assert!(
@@ -149,7 +757,7 @@ mod tests {
             config: None,
             input_path: Some("/tmp/input_only".to_string()),
             output_path: None,
-            mock_api: false,
+            ..Default::default()
         };
         let err = load_config(&cli).unwrap_err();
         assert!(
@@ -159,13 +767,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_load_config_single_file_without_output_path() {
+        let cli = Cli {
+            input_path: Some("/tmp/input_arg".to_string()),
+            single_file: Some("/tmp/bindings/tauri.ts".to_string()),
+            ..Default::default()
+        };
+        let config = load_config(&cli).expect("Failed to load config from args");
+        assert_eq!(config.output_path, "/tmp/bindings");
+
+        let cli = Cli {
+            input_path: Some("/tmp/input_arg".to_string()),
+            single_file: Some("tauri.ts".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(load_config(&cli).unwrap().output_path, ".");
+    }
+
     #[test]
     fn test_load_config_only_output_path() {
         let cli = Cli {
             config: None,
             input_path: None,
             output_path: Some("/tmp/output_only".to_string()),
-            mock_api: false,
+            ..Default::default()
         };
         let err = load_config(&cli).unwrap_err();
         assert!(
@@ -0,0 +1,82 @@
+/// The first line of the banner, followed by the tool version.
+const BANNER_PREFIX: &str = "// @generated by tauria-tsgen v";
+
+/// Prepends the `@generated` banner to the content of a generated file.
+///
+/// The banner names the tool version, the Rust file the content was generated from (if it
+/// comes from a single file) and a hash of the content. The hash does not depend on the tool
+/// version, so it only changes when the generated code does.
+///
+/// # Arguments
+///
+/// * `content` - The rendered TypeScript source.
+/// * `source_file_name` - The name of the input `.rs` file, or `None` for files built from
+///   all input files (index files, event handlers, the bundled single file).
+pub fn add_banner(content: &str, source_file_name: Option<&str>) -> String {
+    let source = match source_file_name {
+        Some(file_name) => format!("source: {file_name}, "),
+        None => String::new(),
+    };
+    format!(
+        "{BANNER_PREFIX}{} — do not edit\n// {source}hash: {:016x}\n{content}",
+        env!("CARGO_PKG_VERSION"),
+        content_hash(content)
+    )
+}
+
+/// Removes the banner added by `add_banner`, if any.
+#[cfg(test)]
+pub fn strip_banner(content: &str) -> &str {
+    if !content.starts_with(BANNER_PREFIX) {
+        return content;
+    }
+    // 1 行目がバージョン、2 行目がソースとハッシュ
+    content.splitn(3, '\n').nth(2).unwrap_or_default()
+}
+
+/// Computes the 64-bit FNV-1a hash of the content.
+///
+/// `std::hash::DefaultHasher` is not guaranteed to be stable across Rust releases, so a fixed
+/// algorithm is used to keep the banner identical between builds.
+pub(crate) fn content_hash(content: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    content.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_banner() {
+        let content = "export interface IBasic {}\n";
+        let with_banner = add_banner(content, Some("basic.rs"));
+        let mut lines = with_banner.lines();
+        assert_eq!(
+            lines.next().unwrap(),
+            format!(
+                "// @generated by tauria-tsgen v{} — do not edit",
+                env!("CARGO_PKG_VERSION")
+            )
+        );
+        assert!(
+            lines
+                .next()
+                .unwrap()
+                .starts_with("// source: basic.rs, hash: ")
+        );
+        assert_eq!(strip_banner(&with_banner), content);
+
+        // 同じ内容には同じハッシュ、異なる内容には異なるハッシュ
+        assert_eq!(add_banner(content, Some("basic.rs")), with_banner);
+        assert_ne!(
+            add_banner("export interface IOther {}\n", Some("basic.rs")),
+            with_banner.replace("IBasic", "IOther")
+        );
+        assert!(add_banner(content, None).contains("\n// hash: "));
+        assert_eq!(strip_banner(content), content);
+    }
+}
@@ -0,0 +1,83 @@
+/// The indentation a leading tab is replaced with.
+const INDENT: &str = "    ";
+
+/// Normalizes the whitespace of a generated TypeScript file (`--format`).
+///
+/// The templates leave blank lines behind where Tera tags were, so the pass is line-based and
+/// deterministic rather than a full TypeScript formatter:
+///
+/// - trailing whitespace is removed and leading tabs become four spaces,
+/// - the `*` lines of a JSDoc comment are aligned with its opening `/**`,
+/// - runs of blank lines are collapsed into one,
+/// - blank lines at the start of the file, after an opening bracket and before a closing bracket
+///   are removed, as are those after a line ending in `=` and before a `|` member of a union,
+/// - the file ends with a single newline.
+pub fn format_typescript(content: &str) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    let mut pending_blank = false;
+    // 複数行の JSDoc コメント内では、開始行 `/**` のインデント
+    let mut comment_indent: Option<String> = None;
+
+    for line in content.lines() {
+        let normalized = normalize_indentation(line.trim_end());
+        let trimmed = normalized.trim_start();
+        let line = match &comment_indent {
+            Some(indent) if trimmed.starts_with('*') => format!("{indent} {trimmed}"),
+            _ => normalized.clone(),
+        };
+        if comment_indent.is_some() {
+            if trimmed.contains("*/") {
+                comment_indent = None;
+            }
+        } else if trimmed.starts_with("/**") && !trimmed.contains("*/") {
+            comment_indent = Some(normalized[..normalized.len() - trimmed.len()].to_string());
+        }
+        if line.is_empty() {
+            pending_blank = true;
+            continue;
+        }
+        // 空行は直前の行が開き括弧や `=` で終わらず、この行が閉じ括弧やユニオン型の `|` で始まらない場合のみ残す
+        if pending_blank
+            && lines
+                .last()
+                .is_some_and(|previous| !previous.ends_with(['{', '(', '[', '=']))
+            && !line.trim_start().starts_with(['}', ')', ']', '|'])
+        {
+            lines.push(String::new());
+        }
+        pending_blank = false;
+        lines.push(line);
+    }
+
+    let mut formatted = lines.join("\n");
+    formatted.push('\n');
+    formatted
+}
+
+/// Replaces the leading tabs of a line with spaces.
+fn normalize_indentation(line: &str) -> String {
+    let content = line.trim_start_matches('\t');
+    let tabs = line.len() - content.len();
+    format!("{}{content}", INDENT.repeat(tabs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_typescript() {
+        let content = "\n\nimport * as T from \"../types/index\"\n\n\n\nexport interface IUser {\n\n    \n    /**\n* The user.\n*/\n\tgetUser(id: number): Promise<T.User>;   \n\n}\n\n\n";
+        assert_eq!(
+            format_typescript(content),
+            "import * as T from \"../types/index\"\n\nexport interface IUser {\n    /**\n     * The user.\n     */\n    getUser(id: number): Promise<T.User>;\n}\n"
+        );
+
+        // user_types.tera が出力するユニオン型の空行も取り除く
+        let content = "export type Ev =\n\n    | { t: \"started\" }\n\n    | { t: \"foo_bar\"; n: number }\n;\n\nexport type Message =\n\n    /**\n     * Quit.\n     */\n    | \"Quit\"\n\n    | \"Stop\"\n;\n";
+        assert_eq!(
+            format_typescript(content),
+            "export type Ev =\n    | { t: \"started\" }\n    | { t: \"foo_bar\"; n: number }\n;\n\nexport type Message =\n    /**\n     * Quit.\n     */\n    | \"Quit\"\n    | \"Stop\"\n;\n"
+        );
+    }
+}
@@ -1,184 +1,420 @@
+use crate::generator::banner::add_banner;
+use crate::generator::formatter::format_typescript;
+use crate::generator::options::{Framework, GenerateOptions, SortOrder};
+use crate::generator::single_file_generator::strip_namespace_prefix;
+use crate::generator::ts_file_generator::{
+    GeneratedFile, load_template, register_tera_filters, unique_window_names,
+};
 use convert_case::{Case, Casing};
 use std::path::Path;
 #[allow(unused_imports)]
 use tera::{Context, Tera};
 
-/// Generates index files for the TypeScript output.
+/// Renders the index files for the TypeScript output.
 ///
-/// This function creates `index.ts` files in the `interface`,
-/// `tauria-api`, and `mock-api` directories, as well as a root `index.ts` file.
+/// This function renders `index.ts` files for the `interface`,
+/// `tauria-api`, `mock-api`, and framework-specific (e.g. `pinia-api`) directories,
+/// as well as a root `index.ts` file.
 /// These files re-export all the generated command and type files, making them
-/// easily accessible to the frontend.
+/// easily accessible to the frontend. Nothing is written to disk; see `write_generated_files`.
 ///
 /// # Arguments
 ///
-/// * `output_dir` - The root directory where the `index.ts` files will be created.
-/// * `file_names` - A mutable vector of strings containing the base names of the generated command files. This vector will be sorted internally.
-/// * `generate_mock_api` - A boolean indicating whether mock API index files should be generated.
+/// * `file_names` - The sorted base names of the generated command files.
+/// * `command_names` - The Rust names of all Tauri commands, exported as the `CommandName` union.
+/// * `options` - The `GenerateOptions` deciding whether mock API and framework index files should be generated.
 /// * `global_events` - A slice of `EventInfo` representing global events, used to determine if global event handlers should be exported.
 /// * `window_events` - A slice of `WindowEventInfo` representing window-specific events, used to determine if window event handlers should be exported.
+/// * `has_user_types` - Whether `interface/types/index.ts` has content and should be re-exported.
 ///
 /// # Returns
 ///
-/// An `anyhow::Result` indicating whether the operation was successful.
-#[allow(clippy::ptr_arg)]
-pub fn generate_index_files(
-    output_dir: &Path,
-    file_names: &mut Vec<String>,
-    generate_mock_api: bool,
+/// The index files, with paths relative to the output directory, or an error if the `api`
+/// object template (see `GenerateOptions::api_object`) cannot be rendered.
+pub fn render_index_files(
+    file_names: &[String],
+    command_names: &[String],
+    options: &GenerateOptions,
     global_events: &[crate::generator::type_extractor::EventInfo],
     window_events: &[crate::generator::type_extractor::WindowEventInfo],
-) -> anyhow::Result<()> {
-    std::fs::create_dir_all(output_dir)?;
-    file_names.sort();
-    let interface_dir = output_dir.join("interface");
-    let tauri_api_dir = output_dir.join("tauria-api");
-    let mock_api_dir = output_dir.join("mock-api");
-
-    std::fs::create_dir_all(&interface_dir)?;
-    std::fs::create_dir_all(&tauri_api_dir)?;
-    if generate_mock_api {
-        std::fs::create_dir_all(&mock_api_dir)?;
-    }
+    has_user_types: bool,
+) -> anyhow::Result<Vec<GeneratedFile>> {
+    let mut files = Vec::new();
+    let index_file_name = format!("index.{}", options.file_extension());
 
     // interface/index.ts
     let mut interface_index_content = file_names
         .iter()
-        .map(|name| {
-            format!(
-                "export * from \"./commands/{}\";",
-                name.to_case(Case::Pascal)
-            )
-        })
+        .map(|name| reexport_statement(&format!("./commands/{}", name.to_case(Case::Pascal))))
         .collect::<Vec<_>>()
         .join("\n");
-    let types_index_path = interface_dir.join("types").join("index.ts");
-    if types_index_path.exists() {
-        let types_file_content = std::fs::read_to_string(&types_index_path)?;
-        if !types_file_content.trim().is_empty() {
-            interface_index_content.push_str("\nexport * from \"./types/\";"); // types/index.ts をエクスポート
-        }
+    if has_user_types {
+        // types/index.ts をエクスポート
+        interface_index_content.push('\n');
+        interface_index_content.push_str(&reexport_statement("./types/"));
     }
-    std::fs::write(interface_dir.join("index.ts"), interface_index_content)?;
+    files.push(GeneratedFile::new(
+        Path::new(&options.output_dirs.interface).join(&index_file_name),
+        interface_index_content,
+    ));
 
     let mut tauri_api_index_content = file_names
         .iter()
-        .map(|name| {
-            format!(
-                "export * from \"./commands/{}\";",
-                name.to_case(Case::Pascal)
-            )
-        }) // 変更
+        .map(|name| reexport_statement(&format!("./commands/{}", name.to_case(Case::Pascal)))) // 変更
         .collect::<Vec<_>>()
         .join("\n");
 
-    if !global_events.is_empty() {
-        tauri_api_index_content.push_str("\nexport * from \"./events/TauriGlobalEventHandlers\";"); // "event" から "events" に変更
+    let event_exports = event_handler_exports(global_events, window_events, options.sort_order);
+    for event_export in &event_exports {
+        tauri_api_index_content.push('\n');
+        tauri_api_index_content.push_str(event_export);
+    }
+    if !command_names.is_empty() {
+        tauri_api_index_content.push_str("\n\n");
+        tauri_api_index_content.push_str(&command_name_type(command_names, options.sort_order));
     }
+    if options.api_object && !file_names.is_empty() {
+        tauri_api_index_content.push_str("\n\n");
+        tauri_api_index_content.push_str(&render_api_object(file_names, "./commands/", options)?);
+    }
+
+    files.push(GeneratedFile::new(
+        Path::new(&options.output_dirs.tauria_api).join(&index_file_name),
+        tauri_api_index_content,
+    ));
 
-    if !window_events.is_empty() {
-        let mut unique_window_names: Vec<String> = window_events
+    if options.generate_mock_api && !options.declarations_only {
+        let mut mock_api_index_content = file_names
             .iter()
-            .map(|e| e.window_name.clone())
-            .collect();
-        unique_window_names.sort();
-        unique_window_names.dedup();
-        for window_name in unique_window_names {
-            tauri_api_index_content.push_str(&format!(
-                "\nexport * from \"./events/Tauri{}WindowEventHandlers\";",
-                window_name.to_case(Case::Pascal)
-            ));
+            .map(|name| reexport_statement(&format!("./{}", name.to_case(Case::Pascal))))
+            .collect::<Vec<_>>()
+            .join("\n");
+        // モックのイベントハンドラも mock-api/events に生成されている
+        for event_export in &event_exports {
+            mock_api_index_content.push('\n');
+            mock_api_index_content.push_str(event_export);
         }
+        // ルートの index.ts で切り替えられるよう、モック側にも同じ形の api オブジェクトを生成する
+        if options.api_object && !file_names.is_empty() {
+            mock_api_index_content.push_str("\n\n");
+            mock_api_index_content.push_str(&render_api_object(file_names, "./", options)?);
+        }
+        files.push(GeneratedFile::new(
+            Path::new(&options.output_dirs.mock_api).join("index.ts"),
+            mock_api_index_content,
+        ));
     }
 
-    std::fs::write(tauri_api_dir.join("index.ts"), tauri_api_index_content)?;
-
-    if generate_mock_api {
-        let mock_api_index_content = file_names
+    if options.framework == Some(Framework::Pinia) && !options.declarations_only {
+        let pinia_api_index_content = file_names
             .iter()
-            .map(|name| format!("export * from \"./{}\";", name.to_case(Case::Pascal)))
+            .map(|name| reexport_statement(&format!("./{}", name.to_case(Case::Pascal))))
             .collect::<Vec<_>>()
             .join("\n");
-        std::fs::write(mock_api_dir.join("index.ts"), mock_api_index_content)?;
+        files.push(GeneratedFile::new(
+            "pinia-api/index.ts",
+            pinia_api_index_content,
+        ));
     }
 
     // 最上位の index.ts (切り替え可能にする)
-    let root_index_content = r#"// This file is generated by tauria-tsgen.
+    let root_index_content = format!(
+        r#"// This file is generated by tauria-tsgen.
 
 // You can switch between tauria-api and mock-api by modifying this file.
 
 
-export * from "./tauria-api";
+{}
+
+// {}
+"#,
+        reexport_statement(&root_module_path(&options.output_dirs.tauria_api)),
+        reexport_statement(&root_module_path(&options.output_dirs.mock_api))
+    );
+    files.push(GeneratedFile::new(index_file_name, root_index_content));
+
+    for file in &mut files {
+        if options.format {
+            file.content = format_typescript(&file.content);
+        }
+        file.content = add_banner(&file.content, None);
+    }
+    Ok(files)
+}
+
+/// Renders the `api` object grouping the commands by source file, e.g. `api.userCommands.getUserData()`.
+///
+/// Each group is the object returned by the `create<File>()` factory of a command file,
+/// imported from `<module_dir><File>`.
+fn render_api_object(
+    file_names: &[String],
+    module_dir: &str,
+    options: &GenerateOptions,
+) -> anyhow::Result<String> {
+    let groups = file_names
+        .iter()
+        .map(|name| {
+            let class_name = name.to_case(Case::Pascal);
+            serde_json::json!({
+                "key": name.to_case(Case::Camel),
+                "factory": format!("create{class_name}"),
+                "module_path": format!("{module_dir}{class_name}"),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut tera = Tera::default();
+    tera.add_raw_template(
+        "api_object.tera",
+        &load_template("api_object.tera", options.templates_dir.as_deref())?,
+    )?;
+    tera.autoescape_on(vec![]);
+    let mut context = Context::new();
+    context.insert("groups", &groups);
+    context.insert("output_format", &options.output_format);
+    context.insert("declarations_only", &options.declarations_only);
+    Ok(tera
+        .render("api_object.tera", &context)?
+        .trim_end()
+        .to_string())
+}
+
+/// Builds a statement re-exporting everything (including types) from `module_path`.
+///
+/// The index files use `export * from` with either `--output-format`: `Object.assign` with
+/// `require` would leave them without a top-level export, so TypeScript would treat them as
+/// scripts and lose the re-exported types. `tsc --module commonjs` compiles the statement to
+/// `require` and `exports`.
+fn reexport_statement(module_path: &str) -> String {
+    format!("export * from \"{module_path}\";")
+}
+
+/// The module path of an output directory as imported from the root `index.ts`.
+fn root_module_path(dir: &str) -> String {
+    format!("./{}", dir.replace('\\', "/").trim_matches('/'))
+}
+
+/// Builds the `CommandName` type: the union of the command names as passed to `invoke`.
+fn command_name_type(command_names: &[String], sort_order: SortOrder) -> String {
+    let mut command_names: Vec<&str> = command_names.iter().map(String::as_str).collect();
+    if sort_order == SortOrder::Alphabetical {
+        command_names.sort();
+    }
+    command_names.dedup();
+    let union = command_names
+        .iter()
+        .map(|name| format!("\"{name}\""))
+        .collect::<Vec<_>>()
+        .join(" | ");
+    format!(
+        "/** The names of all Tauri commands, as passed to `invoke`. */\nexport type CommandName = {union};"
+    )
+}
 
-// export * from "./mock-api";
-"#
-    .to_string();
-    std::fs::write(output_dir.join("index.ts"), root_index_content)?;
+/// Builds the re-export lines for the generated event handler files.
+fn event_handler_exports(
+    global_events: &[crate::generator::type_extractor::EventInfo],
+    window_events: &[crate::generator::type_extractor::WindowEventInfo],
+    sort_order: SortOrder,
+) -> Vec<String> {
+    let mut exports = Vec::new();
 
-    Ok(())
+    if !global_events.is_empty() {
+        exports.push(reexport_statement("./events/TauriGlobalEventHandlers"));
+    }
+
+    for window_name in unique_window_names(window_events, sort_order) {
+        exports.push(reexport_statement(&format!(
+            "./events/Tauri{}WindowEventHandlers",
+            window_name.to_case(Case::Pascal)
+        )));
+    }
+
+    exports
 }
 
-/// Generates an `index.ts` file for user-defined types within the `interface/types` directory.
+/// Renders the files for user-defined types within the `interface/types` directory.
 ///
 /// This function collects all extracted user-defined types (structs and enums)
-/// that are marked as serializable or deserializable and generates their TypeScript
+/// that are marked as serializable or deserializable and renders their TypeScript
 /// interfaces/enums into a single `index.ts` file. This allows for easy import
 /// of all user-defined types from a single entry point.
 ///
+/// With `options.split_types`, the types of each Rust file are rendered into their own
+/// `<PascalName>.ts` instead, importing the types they use from the other files, and
+/// `index.ts` becomes a barrel re-exporting every file.
+///
 /// # Arguments
 ///
-/// * `output_dir` - The root output directory where the `interface/types/index.ts` file will be created.
 /// * `all_extracted_types` - A slice of `ExtractedTypeInfo` containing all extracted user-defined types.
+/// * `options` - The `GenerateOptions` deciding the file extension, whether to emit declarations
+///   and whether to split the types per source file.
 ///
 /// # Returns
 ///
-/// An `anyhow::Result` indicating whether the operation was successful.
-pub fn generate_user_types_index_file(
-    output_dir: &Path,
+/// The rendered files with `index.ts` last, or no files if there are no user-defined types.
+pub fn render_user_types_files(
     all_extracted_types: &[crate::generator::type_extractor::ExtractedTypeInfo],
-) -> anyhow::Result<()> {
+    options: &GenerateOptions,
+) -> anyhow::Result<Vec<GeneratedFile>> {
     // all_extracted_types が空の場合は、types ディレクトリも types/index.ts も生成しない
     if all_extracted_types.is_empty() {
-        return Ok(());
+        return Ok(Vec::new());
     }
 
-    let types_dir = output_dir.join("interface").join("types");
-    std::fs::create_dir_all(&types_dir)?;
+    let types_dir = Path::new(&options.output_dirs.interface).join("types");
+    let mut files = Vec::new();
+    let content = if options.split_types {
+        let mut reexports = Vec::new();
+        for (file_name, types) in group_by_source_file(all_extracted_types) {
+            let content = render_user_types(&types, options)?;
+            if content.is_empty() {
+                continue;
+            }
+            let module_name = file_name.to_case(Case::Pascal);
+            let imports = type_imports(&types, all_extracted_types);
+            files.push(GeneratedFile::new(
+                types_dir.join(format!("{module_name}.{}", options.file_extension())),
+                format!("{imports}{content}"),
+            ));
+            reexports.push(reexport_statement(&format!("./{module_name}")));
+        }
+        reexports.join("\n")
+    } else {
+        render_user_types(all_extracted_types, options)?
+    };
+    files.push(GeneratedFile::new(
+        types_dir.join(format!("index.{}", options.file_extension())),
+        content,
+    ));
+
+    for file in &mut files {
+        if options.format {
+            file.content = format_typescript(&file.content);
+        }
+        file.content = add_banner(&file.content, None);
+    }
+    Ok(files)
+}
+
+/// Groups the types by the Rust file they are defined in, sorted by file name.
+fn group_by_source_file(
+    all_extracted_types: &[crate::generator::type_extractor::ExtractedTypeInfo],
+) -> Vec<(
+    &str,
+    Vec<crate::generator::type_extractor::ExtractedTypeInfo>,
+)> {
+    let mut groups: Vec<(&str, Vec<_>)> = Vec::new();
+    for info in all_extracted_types {
+        match groups
+            .iter_mut()
+            .find(|(file_name, _)| *file_name == info.original_file_name)
+        {
+            Some((_, types)) => types.push(info.clone()),
+            None => groups.push((&info.original_file_name, vec![info.clone()])),
+        }
+    }
+    groups.sort_by_key(|(file_name, _)| *file_name);
+    groups
+}
 
+/// Builds the `import type` statements for the types that `types` use from other source files.
+fn type_imports(
+    types: &[crate::generator::type_extractor::ExtractedTypeInfo],
+    all_extracted_types: &[crate::generator::type_extractor::ExtractedTypeInfo],
+) -> String {
+    let Some(file_name) = types.first().map(|info| &info.original_file_name) else {
+        return String::new();
+    };
+    let mut imports: std::collections::BTreeMap<&str, Vec<&str>> = Default::default();
+    for name in types.iter().flat_map(|info| &info.referenced_types) {
+        // 同じファイルで定義された型は import しない
+        if types.iter().any(|info| &info.name == name) {
+            continue;
+        }
+        if let Some(owner) = all_extracted_types.iter().find(|info| {
+            &info.name == name
+                && &info.original_file_name != file_name
+                && (info.is_serializable || info.is_deserializable)
+        }) {
+            let names = imports.entry(&owner.original_file_name).or_default();
+            if !names.contains(&name.as_str()) {
+                names.push(name);
+            }
+        }
+    }
+    let mut statements = String::new();
+    for (owner_file_name, mut names) in imports {
+        names.sort();
+        statements.push_str(&format!(
+            "import type {{ {} }} from \"./{}\";\n",
+            names.join(", "),
+            owner_file_name.to_case(Case::Pascal)
+        ));
+    }
+    if !statements.is_empty() {
+        statements.push('\n');
+    }
+    statements
+}
+
+/// Renders the TypeScript declarations of all exported user-defined types and constants.
+///
+/// Types that derive neither `Serialize` nor `Deserialize` are skipped, and the fields of
+/// output-only types (`Serialize` without `Deserialize`) are rendered as `readonly`.
+/// Types of other files are referenced by name, without the `T.` namespace prefix.
+/// `user_types.tera` is loaded from `options.templates_dir` if it overrides the embedded template,
+/// and with `options.source_lines` the comment above each type also names its line.
+pub fn render_user_types(
+    all_extracted_types: &[crate::generator::type_extractor::ExtractedTypeInfo],
+    options: &GenerateOptions,
+) -> anyhow::Result<String> {
     let mut tera = Tera::default();
     tera.add_raw_template(
         "user_types.tera",
-        std::str::from_utf8(
-            crate::generator::ts_file_generator::Asset::get("user_types.tera")
-                .unwrap()
-                .data
-                .as_ref(),
-        )?,
+        &load_template("user_types.tera", options.templates_dir.as_deref())?,
     )?;
     tera.autoescape_on(vec![]);
+    register_tera_filters(&mut tera);
 
     let mut all_types_content = String::new();
     for extracted_type_info in all_extracted_types {
-        // Serialize または Deserialize のどちらか一方でも derive されていればエクスポート対象
-        if !extracted_type_info.is_serializable && !extracted_type_info.is_deserializable {
+        // Serialize または Deserialize のどちらか一方でも derive されていればエクスポート対象 (const は常に対象)
+        let is_const = extracted_type_info.ts_interface["type"] == "const";
+        if !is_const
+            && !extracted_type_info.is_serializable
+            && !extracted_type_info.is_deserializable
+        {
             continue;
         }
 
+        let source_line = extracted_type_info.ts_interface["line"]
+            .as_u64()
+            .filter(|_| options.source_lines)
+            .map(|line| format!(":{line}"))
+            .unwrap_or_default();
         all_types_content.push_str(&format!(
-            "//- Generated from {}.rs\n",
+            "//- Generated from {}.rs{source_line}\n",
             extracted_type_info.original_file_name
         ));
         let mut context = Context::new();
         context.insert("ts_interface", &extracted_type_info.ts_interface);
+        context.insert("declarations_only", &options.declarations_only);
+        // フロントエンドへ出力されるだけの型は、受け取った側で変更されないよう readonly にする
+        context.insert(
+            "readonly",
+            &(extracted_type_info.is_serializable && !extracted_type_info.is_deserializable),
+        );
         let rendered = tera.render("user_types.tera", &context)?;
-        all_types_content.push_str(&rendered);
+        // 型定義のファイルは名前空間 T を import せず、他の型を名前で参照する
+        for line in rendered.split_inclusive('\n') {
+            all_types_content.push_str(&strip_namespace_prefix(line));
+        }
         all_types_content.push('\n');
         all_types_content.push('\n');
     }
 
-    std::fs::write(types_dir.join("index.ts"), all_types_content)?;
-
-    Ok(())
+    Ok(all_types_content)
 }
 
 #[cfg(test)]
@@ -189,9 +425,58 @@ mod tests {
     use std::path::PathBuf;
     use tempfile::tempdir;
 
-    use crate::generator::type_extractor::ExtractedTypeInfo;
+    use crate::generator::banner::strip_banner;
+    use crate::generator::options::{OutputFormat, TypeMapping};
+    use crate::generator::ts_file_generator::write_generated_files;
+    use crate::generator::type_extractor::{EventInfo, ExtractedTypeInfo, WindowEventInfo};
     use serde_json::json;
 
+    /// Renders and writes the index files, detecting the types index on disk like the CLI does.
+    #[allow(clippy::ptr_arg)]
+    fn generate_index_files(
+        output_dir: &Path,
+        file_names: &mut Vec<String>,
+        options: &GenerateOptions,
+        global_events: &[EventInfo],
+        window_events: &[WindowEventInfo],
+    ) -> anyhow::Result<()> {
+        std::fs::create_dir_all(output_dir)?;
+        file_names.sort();
+        let types_index_path = output_dir
+            .join("interface")
+            .join("types")
+            .join(format!("index.{}", options.file_extension()));
+        let has_user_types = types_index_path.exists()
+            && !std::fs::read_to_string(&types_index_path)?
+                .trim()
+                .is_empty();
+
+        let files = render_index_files(
+            file_names,
+            &[],
+            options,
+            global_events,
+            window_events,
+            has_user_types,
+        )?;
+        write_generated_files(output_dir, &files)
+    }
+
+    /// Renders and writes `interface/types/index.ts`.
+    fn generate_user_types_index_file(
+        output_dir: &Path,
+        all_extracted_types: &[ExtractedTypeInfo],
+        options: &GenerateOptions,
+    ) -> anyhow::Result<()> {
+        let files = render_user_types_files(all_extracted_types, options)?;
+        write_generated_files(output_dir, &files)
+    }
+
+    /// Reads a generated file without its `@generated` banner.
+    fn read_generated(path: impl AsRef<Path>) -> std::io::Result<String> {
+        fs::read_to_string(path).map(|content| strip_banner(&content).to_string())
+    }
+
     // Helper function to create a dummy file
     fn create_dummy_file(dir: &Path, file_name: &str, content: &str) -> PathBuf {
         let file_path = dir.join(file_name);
@@ -208,10 +493,16 @@ mod tests {
         create_dummy_file(&types_dir, "index.ts", "export interface MyType {};");
 
         let mut file_names = vec!["test_file".to_string()];
-        generate_index_files(output_dir.path(), &mut file_names, false, &[], &[])
-            .expect("Failed to generate index files");
+        generate_index_files(
+            output_dir.path(),
+            &mut file_names,
+            &GenerateOptions::default(),
+            &[],
+            &[],
+        )
+        .expect("Failed to generate index files");
 
-        let interface_index_content = fs::read_to_string(interface_dir.join("index.ts"))
+        let interface_index_content = read_generated(interface_dir.join("index.ts"))
             .expect("Failed to read interface/index.ts");
         assert!(interface_index_content.contains("export * from \"./types/\";"));
     }
@@ -225,10 +516,16 @@ mod tests {
         create_dummy_file(&types_dir, "index.ts", ""); // Empty content
 
         let mut file_names = vec!["test_file".to_string()];
-        generate_index_files(output_dir.path(), &mut file_names, false, &[], &[])
-            .expect("Failed to generate index files");
+        generate_index_files(
+            output_dir.path(),
+            &mut file_names,
+            &GenerateOptions::default(),
+            &[],
+            &[],
+        )
+        .expect("Failed to generate index files");
 
-        let interface_index_content = fs::read_to_string(interface_dir.join("index.ts"))
+        let interface_index_content = read_generated(interface_dir.join("index.ts"))
             .expect("Failed to read interface/index.ts");
         assert!(!interface_index_content.contains("export * from \"./types/\";"));
     }
@@ -239,11 +536,17 @@ mod tests {
         // Do not create types/index.ts
 
         let mut file_names = vec!["test_file".to_string()];
-        generate_index_files(output_dir.path(), &mut file_names, false, &[], &[])
-            .expect("Failed to generate index files");
+        generate_index_files(
+            output_dir.path(),
+            &mut file_names,
+            &GenerateOptions::default(),
+            &[],
+            &[],
+        )
+        .expect("Failed to generate index files");
 
         let interface_dir = output_dir.path().join("interface");
-        let interface_index_content = fs::read_to_string(interface_dir.join("index.ts"))
+        let interface_index_content = read_generated(interface_dir.join("index.ts"))
             .expect("Failed to read interface/index.ts");
         assert!(!interface_index_content.contains("export * from \"./types/\";"));
     }
@@ -252,19 +555,213 @@ mod tests {
     fn test_generate_index_files_no_mock_api() {
         let output_dir = tempdir().expect("Failed to create temp dir");
         let mut file_names = vec!["test_file".to_string()];
-        generate_index_files(output_dir.path(), &mut file_names, false, &[], &[])
-            .expect("Failed to generate index files");
+        generate_index_files(
+            output_dir.path(),
+            &mut file_names,
+            &GenerateOptions::default(),
+            &[],
+            &[],
+        )
+        .expect("Failed to generate index files");
 
         assert!(!output_dir.path().join("mock-api").exists());
     }
 
+    #[test]
+    fn test_generate_index_files_mock_api_exports_event_handlers() {
+        let output_dir = tempdir().expect("Failed to create temp dir");
+        let mut file_names = vec!["test_file".to_string()];
+        let global_events = vec![EventInfo {
+            event_name: "global".to_string(),
+            payload_type: "string".to_string(),
+        }];
+        let window_events = vec![WindowEventInfo {
+            window_name: "main".to_string(),
+            event_name: "window-event".to_string(),
+            payload_type: "string".to_string(),
+        }];
+        let options = GenerateOptions {
+            generate_mock_api: true,
+            ..Default::default()
+        };
+        generate_index_files(
+            output_dir.path(),
+            &mut file_names,
+            &options,
+            &global_events,
+            &window_events,
+        )
+        .expect("Failed to generate index files");
+
+        let mock_api_index_content =
+            read_generated(output_dir.path().join("mock-api").join("index.ts"))
+                .expect("Failed to read mock-api/index.ts");
+        assert_eq!(
+            mock_api_index_content,
+            "export * from \"./TestFile\";\nexport * from \"./events/TauriGlobalEventHandlers\";\nexport * from \"./events/TauriMainWindowEventHandlers\";"
+        );
+    }
+
+    #[test]
+    fn test_generate_index_files_cjs_output_format_keeps_modules() {
+        let global_events = vec![EventInfo {
+            event_name: "global".to_string(),
+            payload_type: "string".to_string(),
+        }];
+        let options = GenerateOptions {
+            output_format: OutputFormat::Cjs,
+            generate_mock_api: true,
+            ..Default::default()
+        };
+        let files = render_index_files(
+            &["test_file".to_string()],
+            &[],
+            &options,
+            &global_events,
+            &[],
+            true,
+        )
+        .unwrap();
+
+        // CommonJS でも index ファイルは export を持つモジュールのままにし、型も再エクスポートする
+        for (path, expected) in [
+            (
+                "interface/index.ts",
+                "export * from \"./commands/TestFile\";\nexport * from \"./types/\";",
+            ),
+            (
+                "tauria-api/index.ts",
+                "export * from \"./commands/TestFile\";\nexport * from \"./events/TauriGlobalEventHandlers\";",
+            ),
+            (
+                "mock-api/index.ts",
+                "export * from \"./TestFile\";\nexport * from \"./events/TauriGlobalEventHandlers\";",
+            ),
+        ] {
+            let file = files
+                .iter()
+                .find(|file| file.path == Path::new(path))
+                .unwrap();
+            assert_eq!(strip_banner(&file.content), expected, "{path}");
+        }
+        let root = files
+            .iter()
+            .find(|file| file.path == Path::new("index.ts"))
+            .unwrap();
+        assert!(root.content.contains("\nexport * from \"./tauria-api\";\n"));
+        for file in &files {
+            assert!(!file.content.contains("module.exports"), "{:?}", file.path);
+            assert!(!file.content.contains("require("), "{:?}", file.path);
+        }
+    }
+
+    #[test]
+    fn test_generate_index_files_api_object() {
+        let output_dir = tempdir().expect("Failed to create temp dir");
+        let mut file_names = vec!["user_commands".to_string(), "product_commands".to_string()];
+        let options = GenerateOptions {
+            generate_mock_api: true,
+            api_object: true,
+            ..Default::default()
+        };
+        generate_index_files(output_dir.path(), &mut file_names, &options, &[], &[])
+            .expect("Failed to generate index files");
+
+        let tauri_api_index =
+            read_generated(output_dir.path().join("tauria-api/index.ts")).unwrap();
+        assert!(tauri_api_index.ends_with(
+            r#"import { createProductCommands } from "./commands/ProductCommands";
+import { createUserCommands } from "./commands/UserCommands";
+
+/** The Tauri commands grouped by the Rust file they are defined in. */
+export const api = {
+    productCommands: createProductCommands(),
+    userCommands: createUserCommands(),
+} as const;"#
+        ));
+        let mock_api_index = read_generated(output_dir.path().join("mock-api/index.ts")).unwrap();
+        assert!(mock_api_index.contains("import { createUserCommands } from \"./UserCommands\";"));
+        assert!(mock_api_index.contains("    userCommands: createUserCommands(),"));
+
+        let declarations_dir = tempdir().expect("Failed to create temp dir");
+        let options = GenerateOptions {
+            api_object: true,
+            declarations_only: true,
+            ..Default::default()
+        };
+        generate_index_files(declarations_dir.path(), &mut file_names, &options, &[], &[])
+            .expect("Failed to generate index files");
+        let declarations_index =
+            read_generated(declarations_dir.path().join("tauria-api/index.d.ts")).unwrap();
+        assert!(declarations_index.contains(
+            "export declare const api: {\n    readonly productCommands: ReturnType<typeof createProductCommands>;"
+        ));
+
+        // api_object を指定しなければ生成しない
+        let plain_dir = tempdir().expect("Failed to create temp dir");
+        generate_index_files(
+            plain_dir.path(),
+            &mut file_names,
+            &GenerateOptions::default(),
+            &[],
+            &[],
+        )
+        .unwrap();
+        let plain_index = read_generated(plain_dir.path().join("tauria-api/index.ts")).unwrap();
+        assert!(!plain_index.contains("export const api"));
+    }
+
+    #[test]
+    fn test_generate_index_files_declarations_only() {
+        let output_dir = tempdir().expect("Failed to create temp dir");
+        let mut file_names = vec!["test_file".to_string()];
+        let options = GenerateOptions {
+            declarations_only: true,
+            output_format: OutputFormat::Cjs,
+            ..Default::default()
+        };
+        let rust_code = r#"
+            #[derive(Serialize, Deserialize)]
+            pub enum Kind { A, B }
+
+            pub const LIMIT: u32 = 10;
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let types = crate::generator::type_extractor::extract_and_convert_types(
+            &syntax.items,
+            "test_file",
+            &TypeMapping::default(),
+        );
+        generate_user_types_index_file(output_dir.path(), &types, &options).unwrap();
+        generate_index_files(output_dir.path(), &mut file_names, &options, &[], &[])
+            .expect("Failed to generate index files");
+
+        let types_content =
+            read_generated(output_dir.path().join("interface/types/index.d.ts")).unwrap();
+        assert!(types_content.contains("export type Kind =\n"));
+        assert!(types_content.contains("export declare const LIMIT: 10;"));
+        let interface_index =
+            read_generated(output_dir.path().join("interface/index.d.ts")).unwrap();
+        assert_eq!(
+            interface_index,
+            "export * from \"./commands/TestFile\";\nexport * from \"./types/\";"
+        );
+        assert!(output_dir.path().join("tauria-api/index.d.ts").exists());
+        assert!(output_dir.path().join("index.d.ts").exists());
+        assert!(!output_dir.path().join("index.ts").exists());
+    }
+
     #[test]
     fn test_generate_user_types_index_file_empty_interfaces() {
         let output_dir = tempdir().expect("Failed to create temp dir");
         let all_ts_interfaces: Vec<ExtractedTypeInfo> = Vec::new();
 
-        generate_user_types_index_file(output_dir.path(), &all_ts_interfaces)
-            .expect("Failed to generate user types index file");
+        generate_user_types_index_file(
+            output_dir.path(),
+            &all_ts_interfaces,
+            &GenerateOptions::default(),
+        )
+        .expect("Failed to generate user types index file");
 
         assert!(
             !output_dir
@@ -286,6 +783,7 @@ mod tests {
                 is_serializable: false,
                 is_deserializable: false,
                 original_file_name: "file1".to_string(),
+                referenced_types: vec![],
             },
             ExtractedTypeInfo {
                 name: "file2".to_string(),
@@ -293,13 +791,18 @@ mod tests {
                 is_serializable: true,
                 is_deserializable: true,
                 original_file_name: "file2".to_string(),
+                referenced_types: vec![],
             },
         ];
 
-        generate_user_types_index_file(output_dir.path(), &all_ts_interfaces)
-            .expect("Failed to generate user types index file");
+        generate_user_types_index_file(
+            output_dir.path(),
+            &all_ts_interfaces,
+            &GenerateOptions::default(),
+        )
+        .expect("Failed to generate user types index file");
 
-        let types_index_content = fs::read_to_string(
+        let types_index_content = read_generated(
             output_dir
                 .path()
                 .join("interface")
@@ -321,6 +824,7 @@ mod tests {
                 is_serializable: true,
                 is_deserializable: true,
                 original_file_name: "file_b".to_string(),
+                referenced_types: vec![],
             },
             ExtractedTypeInfo {
                 name: "file_a".to_string(),
@@ -328,14 +832,19 @@ mod tests {
                 is_serializable: true,
                 is_deserializable: true,
                 original_file_name: "file_a".to_string(),
+                referenced_types: vec![],
             },
         ];
         all_ts_interfaces.sort_by(|a, b| a.name.cmp(&b.name));
 
-        generate_user_types_index_file(output_dir.path(), &all_ts_interfaces)
-            .expect("Failed to generate user types index file");
+        generate_user_types_index_file(
+            output_dir.path(),
+            &all_ts_interfaces,
+            &GenerateOptions::default(),
+        )
+        .expect("Failed to generate user types index file");
 
-        let types_index_content = fs::read_to_string(
+        let types_index_content = read_generated(
             output_dir
                 .path()
                 .join("interface")
@@ -364,6 +873,74 @@ export interface TypeB {
         );
     }
 
+    #[test]
+    fn test_render_user_types_files_split_types() {
+        use crate::generator::type_extractor::extract_and_convert_types;
+
+        let mut all_ts_interfaces = Vec::new();
+        for (file_name, rust_code) in [
+            (
+                "user_profile",
+                "#[derive(Serialize)] struct User { role: Role, friends: Vec<User>, tags: Vec<Tag> }",
+            ),
+            (
+                "roles",
+                "#[derive(Serialize)] enum Role { Admin } #[derive(Serialize)] struct Tag { name: String }",
+            ),
+            ("internal", "struct Hidden { id: u32 }"),
+        ] {
+            let syntax = syn::parse_file(rust_code).unwrap();
+            all_ts_interfaces.extend(extract_and_convert_types(
+                &syntax.items,
+                file_name,
+                &TypeMapping::default(),
+            ));
+        }
+        let options = GenerateOptions {
+            split_types: true,
+            ..Default::default()
+        };
+        let files = render_user_types_files(&all_ts_interfaces, &options).unwrap();
+        let paths: Vec<&Path> = files.iter().map(|file| file.path.as_path()).collect();
+        // 出力対象の型を持たないファイル (internal.rs) からは何も生成しない
+        assert_eq!(
+            paths,
+            [
+                Path::new("interface/types/Roles.ts"),
+                Path::new("interface/types/UserProfile.ts"),
+                Path::new("interface/types/index.ts"),
+            ]
+        );
+
+        let user_profile = strip_banner(&files[1].content);
+        assert!(user_profile.starts_with(
+            "import type { Role, Tag } from \"./Roles\";\n\n//- Generated from user_profile.rs\n"
+        ));
+        assert!(user_profile.contains("export interface User {"));
+        assert!(user_profile.contains("role: Role;"));
+        assert!(user_profile.contains("tags: Tag[];"));
+        assert!(!user_profile.contains("T."));
+        let roles = strip_banner(&files[0].content);
+        assert!(!roles.contains("import"));
+        assert!(roles.contains("export type Role ="));
+        assert!(roles.contains("export interface Tag {"));
+        assert_eq!(
+            strip_banner(&files[2].content),
+            "export * from \"./Roles\";\nexport * from \"./UserProfile\";"
+        );
+
+        let options = GenerateOptions {
+            output_format: OutputFormat::Cjs,
+            ..options
+        };
+        let files = render_user_types_files(&all_ts_interfaces, &options).unwrap();
+        // CommonJS でも型を再エクスポートできるよう export * from のままにする
+        assert_eq!(
+            strip_banner(&files[2].content),
+            "export * from \"./Roles\";\nexport * from \"./UserProfile\";"
+        );
+    }
+
     #[test]
     fn test_generate_user_types_index_file_with_struct_and_enum() {
         let output_dir = tempdir().expect("Failed to create temp dir");
@@ -374,6 +951,7 @@ export interface TypeB {
                 is_serializable: true,
                 is_deserializable: true,
                 original_file_name: "my_types".to_string(),
+                referenced_types: vec![],
             },
             ExtractedTypeInfo {
                 name: "my_types".to_string(),
@@ -381,13 +959,18 @@ export interface TypeB {
                 is_serializable: true,
                 is_deserializable: true,
                 original_file_name: "my_types".to_string(),
+                referenced_types: vec![],
             },
         ];
 
-        generate_user_types_index_file(output_dir.path(), &all_ts_interfaces)
-            .expect("Failed to generate user types index file");
+        generate_user_types_index_file(
+            output_dir.path(),
+            &all_ts_interfaces,
+            &GenerateOptions::default(),
+        )
+        .expect("Failed to generate user types index file");
 
-        let types_index_content = fs::read_to_string(
+        let types_index_content = read_generated(
             output_dir
                 .path()
                 .join("interface")
@@ -397,7 +980,321 @@ export interface TypeB {
         .expect("Failed to read types/index.ts");
 
         assert!(types_index_content.contains("interface MyStruct"));
-        assert!(types_index_content.contains("enum MyEnum"));
+        assert!(types_index_content.contains("export type MyEnum = never;"));
+    }
+
+    // Generates interface/types/index.ts from test/data/<case>/src and compares it with the expected file
+    fn run_user_types_test(test_case_name: &str) {
+        let test_case_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("test/data")
+            .join(test_case_name);
+        let mut rust_files: Vec<PathBuf> = fs::read_dir(test_case_dir.join("src"))
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+            .collect();
+        rust_files.sort();
+
+        let mut all_ts_interfaces = Vec::new();
+        for rust_file in &rust_files {
+            let syntax = syn::parse_file(&read_generated(rust_file).unwrap()).unwrap();
+            let file_name = rust_file.file_stem().unwrap().to_str().unwrap();
+            all_ts_interfaces.extend(crate::generator::type_extractor::extract_and_convert_types(
+                &syntax.items,
+                file_name,
+                &TypeMapping::default(),
+            ));
+        }
+        all_ts_interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let output_dir = tempdir().expect("Failed to create temp dir");
+        generate_user_types_index_file(
+            output_dir.path(),
+            &all_ts_interfaces,
+            &GenerateOptions::default(),
+        )
+        .expect("Failed to generate user types index file");
+
+        let types_index_path = PathBuf::from("interface").join("types").join("index.ts");
+        let generated = read_generated(output_dir.path().join(&types_index_path)).unwrap();
+        let expected =
+            read_generated(test_case_dir.join("expected").join(&types_index_path)).unwrap();
+        assert_eq!(
+            generated.trim().replace("\r\n", "\n"),
+            expected.trim().replace("\r\n", "\n"),
+            "types/index.ts does not match for {test_case_name}"
+        );
+    }
+
+    #[test]
+    fn test_generate_user_types_index_file_enum_test() {
+        run_user_types_test("enum_test");
+    }
+
+    #[test]
+    fn test_generate_user_types_index_file_tagged_enum_test() {
+        run_user_types_test("tagged_enum_test");
+    }
+
+    #[test]
+    fn test_generate_user_types_index_file_struct_test() {
+        run_user_types_test("struct_test");
+    }
+
+    #[test]
+    fn test_render_user_types_member_doc_comments() {
+        let rust_code = r#"
+            #[derive(Serialize, Deserialize)]
+            struct Glob {
+                /// Matches `src/*/lib.rs`.
+                pattern: String,
+                count: u32,
+            }
+
+            #[derive(Serialize)]
+            enum Mode {
+                /// Ends with */ inside the text.
+                Strict,
+                Lenient,
+            }
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let types = crate::generator::type_extractor::extract_and_convert_types(
+            &syntax.items,
+            "globs",
+            &TypeMapping::default(),
+        );
+        let content = render_user_types(&types, &GenerateOptions::default()).unwrap();
+
+        // `*/` はエスケープされ、ドキュメントのないメンバーにはコメントを付けない
+        assert!(content.contains(
+            "    /**\n     * Matches `src/*\\/lib.rs`.\n     */\n    pattern: string;\n"
+        ));
+        assert!(content.contains("\n    count: number;"));
+        assert!(!content.contains("/**\n     * \n"));
+        assert!(content.contains(
+            "    /**\n     * Ends with *\\/ inside the text.\n     */\n    | \"Strict\"\n"
+        ));
+        assert_eq!(content.matches("/**").count(), 2);
+    }
+
+    #[test]
+    fn test_render_user_types_quotes_renamed_fields() {
+        let rust_code = r#"
+            #[derive(Serialize, Deserialize)]
+            #[serde(rename_all = "kebab-case")]
+            struct Profile {
+                user_name: String,
+                age: u32,
+            }
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let types = crate::generator::type_extractor::extract_and_convert_types(
+            &syntax.items,
+            "profiles",
+            &TypeMapping::default(),
+        );
+        let content = render_user_types(&types, &GenerateOptions::default()).unwrap();
+
+        assert!(content.contains("    \"user-name\": string;"));
+        assert!(content.contains("    age: number;"));
+    }
+
+    #[test]
+    fn test_render_user_types_generics() {
+        let rust_code = r#"
+            #[derive(Serialize)]
+            struct Page<T> { items: Vec<T>, total: u32 }
+
+            #[derive(Serialize)]
+            #[serde(untagged)]
+            enum Either<L, R> { Left(L), Right(R) }
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let types = crate::generator::type_extractor::extract_and_convert_types(
+            &syntax.items,
+            "pages",
+            &TypeMapping::default(),
+        );
+        let content = render_user_types(&types, &GenerateOptions::default()).unwrap();
+        assert!(content.contains("export interface Page<T> {"));
+        assert!(content.contains("items: T[];"));
+        assert!(content.contains("export type Either<L, R> =\n"));
+    }
+
+    #[test]
+    fn test_render_user_types_unit_enum() {
+        let rust_code = r#"
+            #[derive(Serialize, Deserialize)]
+            enum Color { Red, Green, Blue }
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let types = crate::generator::type_extractor::extract_and_convert_types(
+            &syntax.items,
+            "colors",
+            &TypeMapping::default(),
+        );
+        let content = render_user_types(&types, &GenerateOptions::default()).unwrap();
+        let members: Vec<&str> = content
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("| "))
+            .collect();
+        assert!(content.contains("export type Color =\n"));
+        assert_eq!(members, [r#""Red""#, r#""Green""#, r#""Blue""#]);
+        assert!(!content.contains("enum Color"));
+    }
+
+    #[test]
+    fn test_render_user_types_tuple_structs() {
+        let rust_code = r#"
+            #[derive(Serialize, Deserialize)]
+            struct Meters(f64);
+
+            #[derive(Serialize, Deserialize)]
+            struct Segment(Meters, Meters);
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let types = crate::generator::type_extractor::extract_and_convert_types(
+            &syntax.items,
+            "geometry",
+            &TypeMapping::default(),
+        );
+        let content = render_user_types(&types, &GenerateOptions::default()).unwrap();
+        assert!(content.contains("export type Meters = number;"));
+        assert!(content.contains("export type Segment = [Meters, Meters];"));
+    }
+
+    #[test]
+    fn test_render_user_types_readonly_fields() {
+        let rust_code = r#"
+            #[derive(Serialize)]
+            struct Report { title: String, pages: Option<u32> }
+
+            #[derive(Serialize, Deserialize)]
+            struct Draft { title: String }
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let types = crate::generator::type_extractor::extract_and_convert_types(
+            &syntax.items,
+            "reports",
+            &TypeMapping::default(),
+        );
+        let content = render_user_types(&types, &GenerateOptions::default()).unwrap();
+        let (report, draft) = content.split_once("export interface Draft").unwrap();
+        assert!(report.contains("readonly title: string;"));
+        assert!(report.contains("readonly pages?: number;"));
+        assert!(draft.contains("    title: string;"));
+        assert!(!draft.contains("readonly"));
+    }
+
+    #[test]
+    fn test_generate_user_types_index_file_with_flatten() {
+        let output_dir = tempdir().expect("Failed to create temp dir");
+        let rust_code = r#"
+            #[derive(Serialize, Deserialize)]
+            struct BaseRequest { id: u32 }
+
+            #[derive(Serialize, Deserialize)]
+            struct Request {
+                #[serde(flatten)]
+                base: BaseRequest,
+                extra_field: String,
+            }
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let mut all_ts_interfaces = crate::generator::type_extractor::extract_and_convert_types(
+            &syntax.items,
+            "requests",
+            &TypeMapping::default(),
+        );
+        crate::generator::type_extractor::resolve_flattened_fields(&mut all_ts_interfaces);
+
+        generate_user_types_index_file(
+            output_dir.path(),
+            &all_ts_interfaces,
+            &GenerateOptions::default(),
+        )
+        .expect("Failed to generate user types index file");
+
+        let types_index_content = read_generated(
+            output_dir
+                .path()
+                .join("interface")
+                .join("types")
+                .join("index.ts"),
+        )
+        .expect("Failed to read types/index.ts");
+
+        assert!(types_index_content.contains("export interface BaseRequest {"));
+        assert!(types_index_content.contains("export interface Request extends BaseRequest {"));
+        assert!(types_index_content.contains("extra_field: string;"));
+        assert!(!types_index_content.contains("base:"));
+    }
+
+    #[test]
+    fn test_generate_user_types_index_file_with_optional_field() {
+        let output_dir = tempdir().expect("Failed to create temp dir");
+        let rust_code = r#"
+            #[derive(Serialize, Deserialize)]
+            struct Profile { name: String, nickname: Option<String> }
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let all_ts_interfaces = crate::generator::type_extractor::extract_and_convert_types(
+            &syntax.items,
+            "profile",
+            &TypeMapping::default(),
+        );
+
+        generate_user_types_index_file(
+            output_dir.path(),
+            &all_ts_interfaces,
+            &GenerateOptions::default(),
+        )
+        .expect("Failed to generate user types index file");
+
+        let types_index_content = read_generated(
+            output_dir
+                .path()
+                .join("interface")
+                .join("types")
+                .join("index.ts"),
+        )
+        .expect("Failed to read types/index.ts");
+
+        assert!(types_index_content.contains("name: string;"));
+        assert!(types_index_content.contains("nickname?: string;"));
+        assert!(!types_index_content.contains("undefined"));
+    }
+
+    #[test]
+    fn test_generate_user_types_index_file_with_const() {
+        let output_dir = tempdir().expect("Failed to create temp dir");
+        let rust_code = "pub const MAX: u32 = 100;";
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let all_ts_interfaces = crate::generator::type_extractor::extract_and_convert_types(
+            &syntax.items,
+            "consts",
+            &TypeMapping::default(),
+        );
+
+        generate_user_types_index_file(
+            output_dir.path(),
+            &all_ts_interfaces,
+            &GenerateOptions::default(),
+        )
+        .expect("Failed to generate user types index file");
+
+        let types_index_content = read_generated(
+            output_dir
+                .path()
+                .join("interface")
+                .join("types")
+                .join("index.ts"),
+        )
+        .expect("Failed to read types/index.ts");
+
+        assert!(types_index_content.contains("//- Generated from consts.rs"));
+        assert!(types_index_content.contains("export const MAX = 100 as const;"));
     }
 
     #[test]
@@ -416,18 +1313,20 @@ export interface TypeB {
             "m_file".to_string(),
         ];
 
-        generate_index_files(&output_dir, &mut file_names, true, &[], &[])
+        let options = GenerateOptions {
+            generate_mock_api: true,
+            ..Default::default()
+        };
+        generate_index_files(&output_dir, &mut file_names, &options, &[], &[])
             .expect("indexファイルの生成に失敗しました");
 
-        let interface_index_content =
-            fs::read_to_string(output_dir.join("interface").join("index.ts"))
-                .expect("interface/index.tsが読み込めません");
+        let interface_index_content = read_generated(output_dir.join("interface").join("index.ts"))
+            .expect("interface/index.tsが読み込めません");
         let tauri_api_index_content =
-            fs::read_to_string(output_dir.join("tauria-api").join("index.ts"))
+            read_generated(output_dir.join("tauria-api").join("index.ts"))
                 .expect("tauria-api/index.tsが読み込めません");
-        let mock_api_index_content =
-            fs::read_to_string(output_dir.join("mock-api").join("index.ts"))
-                .expect("mock-api/index.tsが読み込めません");
+        let mock_api_index_content = read_generated(output_dir.join("mock-api").join("index.ts"))
+            .expect("mock-api/index.tsが読み込めません");
 
         // interface/index.ts のソート順を確認
         let expected_interface_content = "export * from \"./commands/AFile\";\nexport * from \"./commands/MFile\";\nexport * from \"./commands/ZFile\";";
@@ -1,3 +1,9 @@
+pub mod banner;
+pub mod formatter;
 pub mod index_file_generator;
+pub mod options;
+pub mod serde_attrs;
+pub mod single_file_generator;
 pub mod ts_file_generator;
 pub mod type_extractor;
+pub mod warnings;
@@ -0,0 +1,292 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Component, Path, PathBuf};
+
+/// Frontend frameworks for which additional bindings can be generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Framework {
+    /// Generates Pinia stores (Vue 3) under `pinia-api/`.
+    Pinia,
+}
+
+/// The module syntax used for imports and re-exports in the generated files.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// ES modules (`import` / `export * from`).
+    #[default]
+    Esm,
+    /// CommonJS (`import T = require(...)` for the `T` namespace).
+    Cjs,
+}
+
+/// The shape of the generated command wrappers in `tauria-api/` and `mock-api/`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ApiStyle {
+    /// A private class per file, created by the exported `create<FileName>()` factory function.
+    #[default]
+    Functions,
+    /// An exported `<FileName>Api` class with a public constructor (e.g. for dependency
+    /// injection), in addition to the `create<FileName>()` factory function.
+    Class,
+}
+
+/// The order in which commands, types and event handlers are emitted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    /// Sorted by name, so reordering the Rust source does not change the output.
+    #[default]
+    Alphabetical,
+    /// In the order of declaration in the Rust source (and of the input files).
+    Source,
+}
+
+/// How `Option<T>` is represented in the generated TypeScript.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum OptionRepr {
+    /// `T | null`, for values serialized as an explicit `null`.
+    Null,
+    /// `T | undefined`, including struct fields (`name: T | undefined`).
+    Undefined,
+    /// Struct fields become optional properties (`name?: T`), other types `T | undefined`.
+    #[default]
+    Optional,
+}
+
+/// The major version of Tauri the generated bindings target.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+pub enum TauriVersion {
+    /// Tauri v1: `invoke` is imported from `@tauri-apps/api/tauri`, and byte buffers are passed
+    /// as JSON number arrays.
+    #[value(name = "1")]
+    #[serde(rename = "1")]
+    V1,
+    /// Tauri v2: `invoke` is imported from `@tauri-apps/api/core`, and byte buffers of command
+    /// arguments and return values are passed as `Uint8Array`.
+    #[default]
+    #[value(name = "2")]
+    #[serde(rename = "2")]
+    V2,
+}
+
+impl TauriVersion {
+    /// The module `invoke` (and, in v2, `Channel`) is exported from.
+    pub fn invoke_module(self) -> &'static str {
+        match self {
+            TauriVersion::V1 => "@tauri-apps/api/tauri",
+            TauriVersion::V2 => "@tauri-apps/api/core",
+        }
+    }
+}
+
+/// Options controlling which TypeScript files are generated.
+///
+/// The serialized options (without `use_cache`) key the generation cache.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GenerateOptions {
+    /// Whether to generate mock API files under `mock-api/`.
+    pub generate_mock_api: bool,
+    /// Whether the `tauria-api` and `mock-api` index files export an `api` object grouping the
+    /// commands by source file.
+    pub api_object: bool,
+    /// Whether the user-defined types are split into one `interface/types/<PascalName>.ts` per
+    /// Rust file, re-exported by `interface/types/index.ts`.
+    pub split_types: bool,
+    /// Whether the command wrappers are exported as `<FileName>Api` classes.
+    pub api_style: ApiStyle,
+    /// The frontend framework to generate additional bindings for, if any.
+    pub framework: Option<Framework>,
+    /// The module syntax of the generated files.
+    pub output_format: OutputFormat,
+    /// The module the command wrappers import `invoke` and `Channel` from, e.g. a local
+    /// re-export. `None` uses the module of `type_mapping.tauri_version`.
+    pub invoke_import: Option<String>,
+    /// Whether to generate `.d.ts` declaration files without runtime code instead of `.ts` files.
+    /// Mock API and framework bindings are not generated in this mode.
+    pub declarations_only: bool,
+    /// The order in which commands, types and event handlers are emitted.
+    pub sort_order: SortOrder,
+    /// How Rust types are mapped to TypeScript types.
+    pub type_mapping: TypeMapping,
+    /// Whether to normalize the whitespace of the generated files (see `format_typescript`).
+    pub format: bool,
+    /// The directories the interface, Tauri API and mock API files are placed in.
+    pub output_dirs: OutputDirs,
+    /// A directory of Tera templates overriding the embedded templates of the same name.
+    pub templates_dir: Option<PathBuf>,
+    /// Whether each generated command and type declaration is preceded by a comment with the
+    /// Rust file and line it was generated from (e.g. `// from user.rs:42`).
+    pub source_lines: bool,
+    /// Whether the command wrappers throw before calling `invoke` when a required (non-`Option`)
+    /// argument is `undefined`.
+    pub validate_args: bool,
+    /// Whether to fail instead of falling back to `any` or `unknown`, leaving out command
+    /// arguments, or referencing types that are not exported (see `find_strict_violations`).
+    pub strict: bool,
+    /// Whether to reuse the results of unchanged input files from the cache in the output
+    /// directory (see `GenerationCache`). Off by default, so no cache file is written.
+    #[serde(skip)]
+    pub use_cache: bool,
+}
+
+impl GenerateOptions {
+    /// The extension of the generated files: `d.ts` for declarations only, `ts` otherwise.
+    pub fn file_extension(&self) -> &'static str {
+        if self.declarations_only { "d.ts" } else { "ts" }
+    }
+
+    /// The module the command wrappers import `invoke` and `Channel` from.
+    pub fn invoke_import(&self) -> &str {
+        self.invoke_import
+            .as_deref()
+            .unwrap_or(self.type_mapping.tauri_version.invoke_module())
+    }
+}
+
+/// The directories, relative to the output directory, that the generated files are placed in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OutputDirs {
+    /// The command interfaces and user-defined types (`interface` by default).
+    pub interface: String,
+    /// The Tauri API wrappers and event handlers (`tauria-api` by default).
+    pub tauria_api: String,
+    /// The mock API (`mock-api` by default).
+    pub mock_api: String,
+}
+
+impl Default for OutputDirs {
+    fn default() -> Self {
+        Self {
+            interface: "interface".to_string(),
+            tauria_api: "tauria-api".to_string(),
+            mock_api: "mock-api".to_string(),
+        }
+    }
+}
+
+impl OutputDirs {
+    /// Checks that every directory is a non-empty relative path and that they are distinct.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        let dirs = [
+            ("interface", &self.interface),
+            ("tauria_api", &self.tauria_api),
+            ("mock_api", &self.mock_api),
+        ];
+        for (name, dir) in dirs {
+            let components = path_components(dir);
+            if components.is_empty()
+                || Path::new(dir)
+                    .components()
+                    .any(|c| !matches!(c, Component::Normal(_) | Component::CurDir))
+            {
+                anyhow::bail!(
+                    "Output directory `{name}` must be a relative path inside the output directory: {dir:?}"
+                );
+            }
+        }
+        for (i, (name, dir)) in dirs.iter().enumerate() {
+            if let Some((other, _)) = dirs[i + 1..]
+                .iter()
+                .find(|(_, other)| path_components(other) == path_components(dir))
+            {
+                anyhow::bail!("Output directories `{name}` and `{other}` are the same: {dir:?}");
+            }
+        }
+        Ok(())
+    }
+
+    /// The module path of the interface directory as imported from a file in `from_dir`,
+    /// e.g. `../../interface` from `tauria-api/commands`. Both are relative to the output directory.
+    pub fn interface_import_path(&self, from_dir: &Path) -> String {
+        let from_dir = from_dir.to_string_lossy();
+        let from = path_components(&from_dir);
+        let to = path_components(&self.interface);
+        let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+        let mut parts: Vec<&str> = vec![".."; from.len() - common];
+        if parts.is_empty() {
+            parts.push(".");
+        }
+        parts.extend(to[common..].iter().copied());
+        parts.join("/")
+    }
+}
+
+/// Splits a directory of `OutputDirs` into its normal components, accepting `/` and `\` as separators.
+fn path_components(dir: &str) -> Vec<&str> {
+    dir.split(['/', '\\'])
+        .filter(|part| !part.is_empty() && *part != ".")
+        .collect()
+}
+
+/// Options controlling how Rust types are mapped to TypeScript types.
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct TypeMapping {
+    /// Whether to map `u64`, `i64`, `u128` and `i128` to `bigint` instead of `number`.
+    pub bigint_for_64: bool,
+    /// Whether to map `serde_json::Value` to `any` instead of `unknown`.
+    pub json_value_as_any: bool,
+    /// How `Option<T>` is represented.
+    pub option_repr: OptionRepr,
+    /// The Tauri version the bindings target. Byte buffers (`Vec<u8>`, `&[u8]`, `Bytes`) of
+    /// commands are `Uint8Array` in v2 and `number[]` in v1, which has no binary IPC.
+    pub tauri_version: TauriVersion,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interface_import_path() {
+        let dirs = OutputDirs::default();
+        assert_eq!(
+            dirs.interface_import_path(Path::new("tauria-api/commands")),
+            "../../interface"
+        );
+        assert_eq!(
+            dirs.interface_import_path(Path::new("mock-api")),
+            "../interface"
+        );
+
+        let dirs = OutputDirs {
+            interface: "generated/types".to_string(),
+            tauria_api: "generated/api/".to_string(),
+            mock_api: "mocks".to_string(),
+        };
+        assert_eq!(
+            dirs.interface_import_path(Path::new("generated/api/commands")),
+            "../../types"
+        );
+        assert_eq!(
+            dirs.interface_import_path(Path::new("mocks/events")),
+            "../../generated/types"
+        );
+        assert_eq!(
+            dirs.interface_import_path(Path::new("generated/types/commands")),
+            ".."
+        );
+    }
+
+    #[test]
+    fn test_validate_output_dirs() {
+        assert!(OutputDirs::default().validate().is_ok());
+
+        let same = OutputDirs {
+            mock_api: "./tauria-api/".to_string(),
+            ..Default::default()
+        };
+        assert!(same.validate().is_err());
+
+        for dir in ["", "/abs", "../outside"] {
+            let invalid = OutputDirs {
+                interface: dir.to_string(),
+                ..Default::default()
+            };
+            assert!(invalid.validate().is_err(), "{dir:?}");
+        }
+    }
+}
@@ -0,0 +1,291 @@
+use syn::{Attribute, Expr, Lit, Meta};
+
+/// Serde attributes that affect the generated TypeScript.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct SerdeAttributes {
+    /// `#[serde(flatten)]`
+    pub flatten: bool,
+    /// `#[serde(rename = "...")]`
+    pub rename: Option<String>,
+    /// `#[serde(rename_all = "...")]`
+    pub rename_all: Option<RenameRule>,
+    /// `#[serde(tag = "...")]` (internally or adjacently tagged enums)
+    pub tag: Option<String>,
+    /// `#[serde(content = "...")]` (adjacently tagged enums)
+    pub content: Option<String>,
+    /// `#[serde(untagged)]`
+    pub untagged: bool,
+    /// `#[serde(skip_serializing)]` (also set by `#[serde(skip)]`)
+    pub skip_serializing: bool,
+    /// `#[serde(skip_deserializing)]` (also set by `#[serde(skip)]`)
+    pub skip_deserializing: bool,
+    /// `#[serde(skip_serializing_if = "...")]`
+    pub skip_serializing_if: bool,
+    /// `#[serde(default)]` or `#[serde(default = "...")]`
+    pub default: bool,
+}
+
+/// The case conversion rules supported by `#[serde(rename_all = "...")]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    /// Parses a serde rename rule name such as `"camelCase"`.
+    pub fn from_name(rule: &str) -> Option<Self> {
+        match rule {
+            "lowercase" => Some(RenameRule::Lower),
+            "UPPERCASE" => Some(RenameRule::Upper),
+            "PascalCase" => Some(RenameRule::Pascal),
+            "camelCase" => Some(RenameRule::Camel),
+            "snake_case" => Some(RenameRule::Snake),
+            "SCREAMING_SNAKE_CASE" => Some(RenameRule::ScreamingSnake),
+            "kebab-case" => Some(RenameRule::Kebab),
+            "SCREAMING-KEBAB-CASE" => Some(RenameRule::ScreamingKebab),
+            _ => None,
+        }
+    }
+
+    /// Applies the rule to a field name, which is assumed to be in snake_case (as serde does).
+    pub fn apply_to_field(self, field: &str) -> String {
+        match self {
+            RenameRule::Lower | RenameRule::Snake => field.to_string(),
+            RenameRule::Upper | RenameRule::ScreamingSnake => field.to_ascii_uppercase(),
+            RenameRule::Pascal => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+                for ch in field.chars() {
+                    if ch == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(ch.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(ch);
+                    }
+                }
+                pascal
+            }
+            RenameRule::Camel => {
+                let pascal = RenameRule::Pascal.apply_to_field(field);
+                let mut chars = pascal.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => pascal,
+                }
+            }
+            RenameRule::Kebab => field.replace('_', "-"),
+            RenameRule::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+        }
+    }
+
+    /// Applies the rule to a variant name, which is assumed to be in PascalCase (as serde does).
+    pub fn apply_to_variant(self, variant: &str) -> String {
+        match self {
+            RenameRule::Pascal => variant.to_string(),
+            RenameRule::Lower => variant.to_ascii_lowercase(),
+            RenameRule::Upper => variant.to_ascii_uppercase(),
+            RenameRule::Camel => {
+                let mut chars = variant.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => String::new(),
+                }
+            }
+            RenameRule::Snake
+            | RenameRule::ScreamingSnake
+            | RenameRule::Kebab
+            | RenameRule::ScreamingKebab => {
+                // 大文字の前で区切った snake_case を経由して変換する
+                let mut snake = String::new();
+                for (i, ch) in variant.char_indices() {
+                    if i > 0 && ch.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.push(ch.to_ascii_lowercase());
+                }
+                self.apply_to_field(&snake)
+            }
+        }
+    }
+}
+
+/// Parses the `#[serde(...)]` attributes of an item, field or variant.
+///
+/// Unknown or malformed serde attributes are ignored.
+pub(crate) fn parse_serde_attributes(attrs: &[Attribute]) -> SerdeAttributes {
+    let mut serde_attrs = SerdeAttributes::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        let Ok(list) = attr
+            .parse_args_with(syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated)
+        else {
+            continue;
+        };
+        for meta in list {
+            match &meta {
+                Meta::Path(path) if path.is_ident("flatten") => serde_attrs.flatten = true,
+                Meta::Path(path) if path.is_ident("untagged") => serde_attrs.untagged = true,
+                Meta::Path(path) if path.is_ident("skip") => {
+                    serde_attrs.skip_serializing = true;
+                    serde_attrs.skip_deserializing = true;
+                }
+                Meta::Path(path) if path.is_ident("skip_serializing") => {
+                    serde_attrs.skip_serializing = true
+                }
+                Meta::Path(path) if path.is_ident("skip_deserializing") => {
+                    serde_attrs.skip_deserializing = true
+                }
+                Meta::Path(path) if path.is_ident("default") => serde_attrs.default = true,
+                Meta::NameValue(name_value) => {
+                    let Some(value) = expr_to_string(&name_value.value) else {
+                        continue;
+                    };
+                    if name_value.path.is_ident("rename") {
+                        serde_attrs.rename = Some(value);
+                    } else if name_value.path.is_ident("rename_all") {
+                        serde_attrs.rename_all = RenameRule::from_name(&value);
+                    } else if name_value.path.is_ident("tag") {
+                        serde_attrs.tag = Some(value);
+                    } else if name_value.path.is_ident("content") {
+                        serde_attrs.content = Some(value);
+                    } else if name_value.path.is_ident("skip_serializing_if") {
+                        serde_attrs.skip_serializing_if = true;
+                    } else if name_value.path.is_ident("default") {
+                        serde_attrs.default = true;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    serde_attrs
+}
+
+/// Extracts the value of a string literal expression.
+fn expr_to_string(expr: &Expr) -> Option<String> {
+    if let Expr::Lit(expr_lit) = expr
+        && let Lit::Str(lit_str) = &expr_lit.lit
+    {
+        return Some(lit_str.value());
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_serde_attributes() {
+        let field: syn::Field = syn::parse_quote! {
+            #[serde(default, rename = "x", flatten)]
+            x: Base
+        };
+        let attrs = parse_serde_attributes(&field.attrs);
+        assert!(attrs.flatten);
+        assert!(attrs.default);
+        assert_eq!(attrs.rename.as_deref(), Some("x"));
+
+        let field: syn::Field = syn::parse_quote! {
+            #[serde(default = "default_port")]
+            port: u16
+        };
+        assert!(parse_serde_attributes(&field.attrs).default);
+
+        let field: syn::Field = syn::parse_quote! {
+            #[serde(rename = "flatten")]
+            x: Base
+        };
+        assert!(!parse_serde_attributes(&field.attrs).flatten);
+
+        let item: syn::ItemStruct = syn::parse_quote! {
+            #[derive(Serialize)]
+            #[serde(rename_all = "camelCase")]
+            struct S;
+        };
+        assert_eq!(
+            parse_serde_attributes(&item.attrs).rename_all,
+            Some(RenameRule::Camel)
+        );
+
+        let item: syn::ItemEnum = syn::parse_quote! {
+            #[serde(tag = "t", content = "c")]
+            enum E { A }
+        };
+        let attrs = parse_serde_attributes(&item.attrs);
+        assert_eq!(attrs.tag.as_deref(), Some("t"));
+        assert_eq!(attrs.content.as_deref(), Some("c"));
+        assert!(!attrs.untagged);
+
+        let item: syn::ItemEnum = syn::parse_quote! {
+            #[serde(untagged)]
+            enum E { A }
+        };
+        assert!(parse_serde_attributes(&item.attrs).untagged);
+
+        let field: syn::Field = syn::parse_quote! {
+            #[serde(skip)]
+            x: Cache
+        };
+        let attrs = parse_serde_attributes(&field.attrs);
+        assert!(attrs.skip_serializing && attrs.skip_deserializing);
+
+        let field: syn::Field = syn::parse_quote! {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            x: Option<u32>
+        };
+        let attrs = parse_serde_attributes(&field.attrs);
+        assert!(attrs.skip_serializing_if && !attrs.skip_serializing);
+
+        let item: syn::ItemStruct = syn::parse_quote! {
+            #[serde(rename_all = "unknownCase")]
+            struct S;
+        };
+        assert_eq!(
+            parse_serde_attributes(&item.attrs),
+            SerdeAttributes::default()
+        );
+    }
+
+    #[test]
+    fn test_rename_rule_apply_to_field() {
+        let cases = [
+            (RenameRule::Lower, "user_id"),
+            (RenameRule::Upper, "USER_ID"),
+            (RenameRule::Pascal, "UserId"),
+            (RenameRule::Camel, "userId"),
+            (RenameRule::Snake, "user_id"),
+            (RenameRule::ScreamingSnake, "USER_ID"),
+            (RenameRule::Kebab, "user-id"),
+            (RenameRule::ScreamingKebab, "USER-ID"),
+        ];
+        for (rule, expected) in cases {
+            assert_eq!(rule.apply_to_field("user_id"), expected, "{rule:?}");
+        }
+    }
+
+    #[test]
+    fn test_rename_rule_apply_to_variant() {
+        let cases = [
+            (RenameRule::Lower, "userid"),
+            (RenameRule::Upper, "USERID"),
+            (RenameRule::Pascal, "UserId"),
+            (RenameRule::Camel, "userId"),
+            (RenameRule::Snake, "user_id"),
+            (RenameRule::ScreamingSnake, "USER_ID"),
+            (RenameRule::Kebab, "user-id"),
+            (RenameRule::ScreamingKebab, "USER-ID"),
+        ];
+        for (rule, expected) in cases {
+            assert_eq!(rule.apply_to_variant("UserId"), expected, "{rule:?}");
+        }
+    }
+}
@@ -0,0 +1,254 @@
+use crate::generator::banner::add_banner;
+use crate::generator::index_file_generator::render_user_types;
+use crate::generator::options::GenerateOptions;
+use crate::generator::ts_file_generator::GeneratedFile;
+use crate::generator::type_extractor::ExtractedTypeInfo;
+use std::path::Path;
+
+const SINGLE_FILE_HEADER: &str = "// --- --- ---
+// Note: This file was automatically generated by tauria-tsgen.
+// Manually modifying it may break the interface and lead to unintended consequences.
+// Please exercise great caution if you choose to make manual changes.
+";
+
+/// The sections of the bundled file, in output order, with the directory their files come from.
+const SECTIONS: [(&str, &str); 3] = [
+    ("commands", "interface/commands"),
+    ("tauri commands", "tauria-api/commands"),
+    ("events", "tauria-api/events"),
+];
+
+/// Bundles the generated TypeScript into a single module.
+///
+/// The user-defined types come first, followed by the command interfaces, the Tauri command
+/// wrappers and the event handlers. Imports between the generated files are dropped and the
+/// `T.` namespace prefix is removed, since every declaration lives in the same module.
+/// Package imports (e.g. `@tauri-apps/api/core`) are deduplicated and hoisted to the top.
+///
+/// # Arguments
+///
+/// * `all_extracted_types` - All extracted user-defined types, in output order.
+/// * `files` - The rendered files of all input files. Mock API and framework files are ignored.
+/// * `options` - The `GenerateOptions` of the run (templates directory, source line comments).
+///
+/// # Returns
+///
+/// The content of the bundled file, or an `anyhow::Result` error if rendering the types fails.
+pub fn generate_single_file(
+    all_extracted_types: &[ExtractedTypeInfo],
+    files: &[GeneratedFile],
+    options: &GenerateOptions,
+) -> anyhow::Result<String> {
+    let mut imports: Vec<String> = Vec::new();
+    let mut sections: Vec<(&str, String)> = Vec::new();
+
+    let types_content = render_user_types(all_extracted_types, options)?;
+    if !types_content.trim().is_empty() {
+        // 型定義には import もヘッダーも名前空間の接頭辞も含まれない
+        sections.push(("types", types_content.trim().to_string()));
+    }
+
+    for (section_name, dir) in SECTIONS {
+        let mut section_files: Vec<&GeneratedFile> = files
+            .iter()
+            .filter(|file| file.path.parent() == Some(Path::new(dir)))
+            .collect();
+        section_files.sort_by(|a, b| a.path.cmp(&b.path));
+        if section_files.is_empty() {
+            continue;
+        }
+        let content = section_files
+            .iter()
+            .map(|file| strip_module_boilerplate(&file.content, &mut imports))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        sections.push((section_name, content));
+    }
+
+    let mut bundled = SINGLE_FILE_HEADER.to_string();
+    if !imports.is_empty() {
+        bundled.push('\n');
+        bundled.push_str(&imports.join("\n"));
+        bundled.push('\n');
+    }
+    for (section_name, content) in sections {
+        bundled.push_str(&format!(
+            "\n//#region {section_name}\n\n{content}\n\n//#endregion\n"
+        ));
+    }
+    Ok(add_banner(&bundled, None))
+}
+
+/// Removes the leading comment header, imports and `T.` prefixes from a generated file.
+///
+/// Package imports are collected into `imports` (without duplicates) instead of being dropped.
+fn strip_module_boilerplate(content: &str, imports: &mut Vec<String>) -> String {
+    let mut lines = Vec::new();
+    let mut in_header = true;
+    for line in content.lines() {
+        let trimmed = line.trim();
+        // 先頭の自動生成コメントは各ファイルで重複するため取り除く
+        if in_header && (trimmed.is_empty() || trimmed.starts_with("//")) {
+            continue;
+        }
+        in_header = false;
+
+        if trimmed.starts_with("import ") {
+            if !is_relative_import(trimmed) {
+                add_import(imports, trimmed);
+            }
+            continue;
+        }
+        lines.push(strip_namespace_prefix(line));
+    }
+    lines.join("\n").trim().to_string()
+}
+
+/// Adds a package import, merging named imports from the same module into one statement.
+///
+/// Separate statements importing the same name (e.g. `Event` from `@tauri-apps/api/event`
+/// in several event handler files) would be duplicate declarations in a single module.
+fn add_import(imports: &mut Vec<String>, import: &str) {
+    let Some((names, module)) = parse_named_import(import) else {
+        if !imports.iter().any(|existing| existing == import) {
+            imports.push(import.to_string());
+        }
+        return;
+    };
+    for existing in imports.iter_mut() {
+        if let Some((mut existing_names, existing_module)) = parse_named_import(existing)
+            && existing_module.replace('\'', "\"") == module.replace('\'', "\"")
+        {
+            for name in names {
+                if !existing_names.contains(&name) {
+                    existing_names.push(name);
+                }
+            }
+            *existing = format!(
+                "import {{ {} }} from {existing_module};",
+                existing_names.join(", ")
+            );
+            return;
+        }
+    }
+    imports.push(import.to_string());
+}
+
+/// Parses `import { a, b } from "module";` into its names and the quoted module specifier.
+fn parse_named_import(import: &str) -> Option<(Vec<String>, String)> {
+    let rest = import.strip_prefix("import {")?;
+    let (names, rest) = rest.split_once('}')?;
+    let module = rest
+        .trim()
+        .strip_prefix("from")?
+        .trim()
+        .trim_end_matches(';');
+    let names = names
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect();
+    Some((names, module.to_string()))
+}
+
+/// Returns `true` if the import statement refers to another generated file (e.g. `'../../interface/'`).
+fn is_relative_import(import: &str) -> bool {
+    import.contains("from \".") || import.contains("from '.")
+}
+
+/// Removes the `T.` namespace prefix from type references (e.g. `T.User[]` -> `User[]`).
+pub(crate) fn strip_namespace_prefix(line: &str) -> String {
+    let chars: Vec<char> = line.chars().collect();
+    let mut stripped = String::with_capacity(line.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let is_prefix = chars[i] == 'T'
+            && chars.get(i + 1) == Some(&'.')
+            && chars
+                .get(i + 2)
+                .is_some_and(|c| c.is_alphabetic() || *c == '_')
+            && (i == 0 || !is_identifier_char(chars[i - 1]));
+        if is_prefix {
+            i += 2;
+            continue;
+        }
+        stripped.push(chars[i]);
+        i += 1;
+    }
+    stripped
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$' || c == '.'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_module_boilerplate() {
+        let content = r#"// Note: generated
+//
+import { invoke } from '@tauri-apps/api/core';
+import * as T from '../../interface/';
+
+class Cmd implements T.ICmd {
+    // T.User is returned
+    async getUser(ids: T.Id[]): Promise<T.User | undefined> {
+        return await invoke('get_user', { ids: ids });
+    }
+}"#;
+        let mut imports = vec!["import { invoke } from '@tauri-apps/api/core';".to_string()];
+        let stripped = strip_module_boilerplate(content, &mut imports);
+
+        assert_eq!(
+            stripped,
+            r#"class Cmd implements ICmd {
+    // User is returned
+    async getUser(ids: Id[]): Promise<User | undefined> {
+        return await invoke('get_user', { ids: ids });
+    }
+}"#
+        );
+        assert_eq!(imports.len(), 1);
+    }
+
+    #[test]
+    fn test_add_import_merges_named_imports() {
+        let mut imports = Vec::new();
+        add_import(
+            &mut imports,
+            r#"import { Event, listen, once, UnlistenFn } from "@tauri-apps/api/event";"#,
+        );
+        add_import(
+            &mut imports,
+            "import { invoke } from '@tauri-apps/api/core';",
+        );
+        add_import(
+            &mut imports,
+            r#"import { Event, listen, UnlistenFn } from "@tauri-apps/api/event";"#,
+        );
+        add_import(
+            &mut imports,
+            "import { emit } from '@tauri-apps/api/event';",
+        );
+
+        assert_eq!(
+            imports,
+            vec![
+                r#"import { Event, listen, once, UnlistenFn, emit } from "@tauri-apps/api/event";"#,
+                "import { invoke } from '@tauri-apps/api/core';",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_strip_namespace_prefix_keeps_other_identifiers() {
+        assert_eq!(
+            strip_namespace_prefix("a: AT.B, b: Record<T.K, T.V>, c: x.T.y"),
+            "a: AT.B, b: Record<K, V>, c: x.T.y"
+        );
+    }
+}
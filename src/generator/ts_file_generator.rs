@@ -1,11 +1,17 @@
+use crate::generator::banner::{add_banner, content_hash};
+use crate::generator::formatter::format_typescript;
+use crate::generator::options::{Framework, GenerateOptions, SortOrder};
 use crate::generator::type_extractor::{
-    extract_and_convert_types, extract_events, extract_tauri_commands,
+    DroppedArgument, ExtractedTypeInfo, extract_and_convert_types, extract_events,
+    extract_generate_handler_commands, extract_tauri_commands, resolve_type_aliases,
 };
+use crate::generator::warnings::{find_strict_violations, namespace_type_refs};
+use anyhow::Context as _;
 use convert_case::{Case, Casing};
 use log::info;
 use rust_embed::RustEmbed;
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 #[allow(unused_imports)]
 use syn::{Attribute, Fields, FnArg, Item, ItemEnum, ItemStruct, Lit, Meta, Pat, Type};
 use tera::{Context, Filter, Tera, from_value, to_value};
@@ -15,6 +21,40 @@ use tera::{Context, Filter, Tera, from_value, to_value};
 #[folder = "templates/"]
 pub struct Asset;
 
+/// Loads the source of the template `name`.
+///
+/// If `templates_dir` contains a file of that name, it overrides the embedded template.
+/// Templates that are not overridden fall back to the embedded ones.
+pub fn load_template(name: &str, templates_dir: Option<&Path>) -> anyhow::Result<String> {
+    if let Some(path) = templates_dir.map(|dir| dir.join(name))
+        && path.is_file()
+    {
+        log::debug!("Using template override: {}", path.display());
+        return std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read template {}", path.display()));
+    }
+    let asset = Asset::get(name).with_context(|| format!("Unknown template: {name}"))?;
+    Ok(std::str::from_utf8(asset.data.as_ref())?.to_string())
+}
+
+/// Hashes the templates of `templates_dir` that override embedded ones, so that cached results
+/// rendered with other templates can be told apart. Returns `0` if no directory is given.
+pub(crate) fn template_overrides_hash(templates_dir: Option<&Path>) -> u64 {
+    let Some(templates_dir) = templates_dir else {
+        return 0;
+    };
+    let mut overrides = String::new();
+    for name in Asset::iter() {
+        if let Ok(content) = std::fs::read_to_string(templates_dir.join(name.as_ref())) {
+            overrides.push_str(&name);
+            overrides.push('\0');
+            overrides.push_str(&content);
+            overrides.push('\0');
+        }
+    }
+    content_hash(&overrides)
+}
+
 #[derive(Debug)]
 /// Tera filter to convert a string to PascalCase.
 pub struct PascalCaseFilter;
@@ -45,130 +85,600 @@ impl Filter for CamelCaseFilter {
     }
 }
 
-fn register_tera_filters(tera: &mut Tera) {
+#[derive(Debug)]
+/// Tera filter to convert a (multi-line) doc comment into a JSDoc block.
+///
+/// The optional `indent` argument sets the number of spaces the block is indented by, the
+/// optional `is_async` argument notes that the command is an `async fn`, and the optional
+/// `throws` argument (a TypeScript type, or null) appends a `@throws` tag.
+/// Each line of the block ends with a newline, `*/` inside the doc comment is escaped as `*\/`,
+/// and an empty doc comment renders nothing.
+pub struct JsDocFilter;
+
+impl Filter for JsDocFilter {
+    fn filter(
+        &self,
+        value: &tera::Value,
+        args: &HashMap<String, tera::Value>,
+    ) -> tera::Result<tera::Value> {
+        let doc = from_value::<String>(value.clone())?;
+        let indent = match args.get("indent") {
+            Some(indent) => " ".repeat(from_value::<usize>(indent.clone())?),
+            None => String::new(),
+        };
+        let throws = match args.get("throws") {
+            Some(throws) => from_value::<Option<String>>(throws.clone())?,
+            None => None,
+        };
+        let is_async = match args.get("is_async") {
+            Some(is_async) => from_value::<bool>(is_async.clone())?,
+            None => false,
+        };
+        let mut doc = doc.trim().to_string();
+        if is_async {
+            if !doc.is_empty() {
+                doc.push('\n');
+            }
+            // 非同期コマンドはメインスレッドではなく async ランタイム上で実行される
+            doc.push_str("@remarks Runs on the async runtime of Tauri instead of the main thread.");
+        }
+        if let Some(throws) = throws {
+            if !doc.is_empty() {
+                doc.push('\n');
+            }
+            doc.push_str(&format!(
+                "@throws {{{throws}}} when the command returns an error."
+            ));
+        }
+        Ok(to_value(to_jsdoc(&doc, &indent))?)
+    }
+}
+
+fn to_jsdoc(doc: &str, indent: &str) -> String {
+    if doc.is_empty() {
+        return String::new();
+    }
+    let mut jsdoc = format!("{indent}/**\n");
+    for line in doc.lines() {
+        // ドキュメント中の `*/` でコメントが途中で閉じないようにエスケープする
+        let line = line.trim_end().replace("*/", "*\\/");
+        if line.is_empty() {
+            jsdoc.push_str(&format!("{indent} *\n"));
+        } else {
+            jsdoc.push_str(&format!("{indent} * {line}\n"));
+        }
+    }
+    jsdoc.push_str(&format!("{indent} */\n"));
+    jsdoc
+}
+
+#[derive(Debug)]
+/// Tera filter to convert a TypeScript type into a default value for mock implementations.
+///
+/// Primitives map to their zero values (`""`, `0`, `false`), arrays to `[]` and optional
+/// types to `undefined`. Structs and enums defined in the same file are constructed from
+/// their fields and first variant; other user-defined types fall back to `{} as T.Name`.
+pub struct MockValueFilter {
+    types: HashMap<String, serde_json::Value>,
+}
+
+impl MockValueFilter {
+    fn new(types: &[ExtractedTypeInfo]) -> Self {
+        Self {
+            types: types
+                .iter()
+                .map(|info| (info.name.clone(), info.ts_interface.clone()))
+                .collect(),
+        }
+    }
+}
+
+impl Filter for MockValueFilter {
+    fn filter(
+        &self,
+        value: &tera::Value,
+        _: &HashMap<String, tera::Value>,
+    ) -> tera::Result<tera::Value> {
+        let ts_type = from_value::<String>(value.clone())?;
+        Ok(to_value(mock_value(&ts_type, &self.types, 0))?)
+    }
+}
+
+/// The nesting depth up to which user-defined types are constructed, so recursive types terminate.
+const MAX_MOCK_VALUE_DEPTH: usize = 4;
+
+/// Builds a TypeScript expression holding a default value of `ts_type`.
+fn mock_value(ts_type: &str, types: &HashMap<String, serde_json::Value>, depth: usize) -> String {
+    let ts_type = ts_type.trim();
+    let union_members = split_top_level(ts_type, '|');
+    if union_members.len() > 1 {
+        // Option<T> は undefined (--option-repr null では null) を既定値とする
+        if union_members.contains(&"undefined") {
+            return "undefined".to_string();
+        }
+        if union_members.contains(&"null") {
+            return "null".to_string();
+        }
+        return mock_value(union_members[0], types, depth);
+    }
+
+    match ts_type {
+        "string" => return "\"\"".to_string(),
+        "number" => return "0".to_string(),
+        "bigint" => return "0n".to_string(),
+        "boolean" => return "false".to_string(),
+        "null" => return "null".to_string(),
+        "Uint8Array" => return "new Uint8Array()".to_string(),
+        "void" | "undefined" | "unknown" | "any" => return "undefined".to_string(),
+        _ => {}
+    }
+    if ts_type.ends_with("[]") {
+        return "[]".to_string();
+    }
+    if ts_type.starts_with("Record<") {
+        return "{}".to_string();
+    }
+    // 文字列リテラル型 (unit 列挙子など) はその値自身を返す
+    if ts_type.starts_with('"') {
+        return ts_type.to_string();
+    }
+    if let Some(inner) = ts_type.strip_prefix('(').and_then(|t| t.strip_suffix(')')) {
+        return mock_value(inner, types, depth);
+    }
+    if let Some(inner) = ts_type.strip_prefix('[').and_then(|t| t.strip_suffix(']')) {
+        let elems: Vec<String> = split_top_level(inner, ',')
+            .into_iter()
+            .filter(|elem| !elem.is_empty())
+            .map(|elem| mock_value(elem, types, depth))
+            .collect();
+        return format!("[{}]", elems.join(", "));
+    }
+    if let Some(inner) = ts_type.strip_prefix('{').and_then(|t| t.strip_suffix('}')) {
+        let properties: Vec<(String, &str)> = split_top_level(inner, ';')
+            .into_iter()
+            .filter_map(|property| property.split_once(": "))
+            .map(|(name, ty)| (name.trim().to_string(), ty))
+            .collect();
+        return mock_object(&properties, types, depth);
+    }
+
+    let name = ts_type.strip_prefix("T.").unwrap_or(ts_type);
+    let is_identifier = name
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '_' || c == '$');
+    if is_identifier && !name.is_empty() {
+        return mock_user_type(name, types, depth).unwrap_or_else(|| format!("{{}} as T.{name}"));
+    }
+    if ts_type.starts_with("T.") {
+        format!("{{}} as {ts_type}")
+    } else {
+        "undefined as any".to_string()
+    }
+}
+
+/// Builds a default value of a user-defined type from the same file, or `None` if it is unknown.
+fn mock_user_type(
+    name: &str,
+    types: &HashMap<String, serde_json::Value>,
+    depth: usize,
+) -> Option<String> {
+    if depth >= MAX_MOCK_VALUE_DEPTH {
+        return None;
+    }
+    let ts_interface = types.get(name)?;
+    match ts_interface["type"].as_str()? {
+        "interface" => {
+            let properties = interface_properties(ts_interface, types)?;
+            let properties: Vec<(String, &str)> = properties
+                .iter()
+                .map(|(name, ty)| (name.clone(), ty.as_str()))
+                .collect();
+            Some(mock_object(&properties, types, depth + 1))
+        }
+        "enum" => {
+            let first_variant = ts_interface["variants"].as_array()?.first()?;
+            Some(mock_value(
+                first_variant["ts_type"].as_str()?,
+                types,
+                depth + 1,
+            ))
+        }
+        "alias" => Some(mock_value(
+            ts_interface["ts_type"].as_str()?,
+            types,
+            depth + 1,
+        )),
+        _ => None,
+    }
+}
+
+/// Collects the required `(name, type)` pairs of an interface, including those of the
+/// interfaces it extends or flattens. Returns `None` if such an interface is unknown.
+fn interface_properties(
+    ts_interface: &serde_json::Value,
+    types: &HashMap<String, serde_json::Value>,
+) -> Option<Vec<(String, String)>> {
+    let mut properties = Vec::new();
+    for base in ts_interface["extends"].as_array().into_iter().flatten() {
+        let base = types.get(base.as_str()?.trim_start_matches("T."))?;
+        properties.extend(interface_properties(base, types)?);
+    }
+    for entry in ts_interface["flattened"].as_array().into_iter().flatten() {
+        // Option<_> の flatten はすべて省略可能なプロパティになる
+        if entry["optional"].as_bool() == Some(true) {
+            continue;
+        }
+        let base = types.get(entry["type"].as_str()?.trim_start_matches("T."))?;
+        if base["type"] != "interface" {
+            return None;
+        }
+        properties.extend(interface_properties(base, types)?);
+    }
+    for field in ts_interface["fields"].as_array().into_iter().flatten() {
+        // 省略可能なフィールドはモックでも省略する
+        if field["optional"].as_bool() == Some(true) {
+            continue;
+        }
+        // フィールド名は抽出時に引用符で囲まれている
+        properties.push((
+            field["name"].as_str()?.to_string(),
+            field["type"].as_str()?.to_string(),
+        ));
+    }
+    Some(properties)
+}
+
+/// Renders an object literal such as `{ id: 0, name: "" }` with a default value per property.
+fn mock_object(
+    properties: &[(String, &str)],
+    types: &HashMap<String, serde_json::Value>,
+    depth: usize,
+) -> String {
+    if properties.is_empty() {
+        return "{}".to_string();
+    }
+    let properties: Vec<String> = properties
+        .iter()
+        .map(|(name, ty)| format!("{name}: {}", mock_value(ty, types, depth)))
+        .collect();
+    format!("{{ {} }}", properties.join(", "))
+}
+
+/// Splits a TypeScript type at `separator`, ignoring separators nested in brackets or strings.
+fn split_top_level(ts_type: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut start = 0;
+    for (i, c) in ts_type.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '(' | '[' | '{' | '<' if !in_string => depth += 1,
+            ')' | ']' | '}' | '>' if !in_string => depth = depth.saturating_sub(1),
+            c if c == separator && depth == 0 && !in_string => {
+                parts.push(ts_type[start..i].trim());
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(ts_type[start..].trim());
+    parts
+}
+
+pub(crate) fn register_tera_filters(tera: &mut Tera) {
     tera.register_filter("pascalcase", PascalCaseFilter);
     tera.register_filter("camelcase", CamelCaseFilter);
+    tera.register_filter("jsdoc", JsDocFilter);
+}
+
+/// A rendered TypeScript file that has not been written to disk yet.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GeneratedFile {
+    /// The path of the file, relative to the output directory.
+    pub path: PathBuf,
+    /// The rendered TypeScript source.
+    pub content: String,
+}
+
+impl GeneratedFile {
+    pub(crate) fn new(path: impl Into<PathBuf>, content: String) -> Self {
+        Self {
+            path: path.into(),
+            content,
+        }
+    }
+}
+
+/// Writes the generated files below `output_dir`, creating parent directories as needed.
+///
+/// Files whose content on disk is already identical are not rewritten, so their modification
+/// times stay unchanged and file watchers of the frontend are not triggered.
+pub fn write_generated_files(output_dir: &Path, files: &[GeneratedFile]) -> anyhow::Result<()> {
+    for file in files {
+        let path = output_dir.join(&file.path);
+        if std::fs::read(&path).is_ok_and(|existing| existing == file.content.as_bytes()) {
+            log::debug!("Unchanged file: {}", file.path.display());
+            continue;
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, &file.content)?;
+        info!("Generated file: {}", file.path.display());
+    }
+    Ok(())
+}
+
+/// Returns the distinct window names of the window events, ordered by `sort_order`.
+pub(crate) fn unique_window_names(
+    window_events: &[crate::generator::type_extractor::WindowEventInfo],
+    sort_order: SortOrder,
+) -> Vec<String> {
+    let mut window_names: Vec<String> = Vec::new();
+    for event in window_events {
+        if !window_names.contains(&event.window_name) {
+            window_names.push(event.window_name.clone());
+        }
+    }
+    if sort_order == SortOrder::Alphabetical {
+        window_names.sort();
+    }
+    window_names
 }
 
 /// Generates TypeScript event handler files based on extracted global and window events.
 ///
-/// This function uses Tera templates to create TypeScript files that handle
+/// This function uses Tera templates to render TypeScript files that handle
 /// global and window-specific events, including their payloads.
+/// When mock API generation is enabled, matching mock handlers that never subscribe
+/// to Tauri are rendered for `mock-api/events/` as well.
 ///
 /// # Arguments
 ///
-/// * `output_dir` - The base directory where the generated files will be saved.
 /// * `global_events` - A slice of `EventInfo` representing global events.
 /// * `window_events` - A slice of `WindowEventInfo` representing window-specific events.
+/// * `options` - The `GenerateOptions` deciding whether mock event handlers should be generated.
 ///
 /// # Returns
 ///
-/// `Ok(())` if the files are generated successfully, otherwise an `anyhow::Result` error.
+/// The rendered event handler files, or an `anyhow::Result` error if rendering fails.
 pub fn generate_event_handler_files(
-    output_dir: &Path,
     global_events: &[crate::generator::type_extractor::EventInfo],
     window_events: &[crate::generator::type_extractor::WindowEventInfo],
-) -> anyhow::Result<()> {
+    options: &GenerateOptions,
+) -> anyhow::Result<Vec<GeneratedFile>> {
     let mut tera = Tera::default();
     register_tera_filters(&mut tera);
+    let mut files = Vec::new();
+    let extension = options.file_extension();
 
-    // all_extracted_types を取得する方法がないため、ここではペイロードタイプが "T." で始まるかどうかで簡易的に判定
+    // all_extracted_types を取得する方法がないため、ここではペイロードタイプに "T." が含まれるかどうかで簡易的に判定 (ユニオン型を含む)
     let has_user_defined_types_in_global_events = global_events
         .iter()
-        .any(|event| event.payload_type.starts_with("T."));
+        .any(|event| event.payload_type.contains("T."));
 
     if !global_events.is_empty() {
         let mut sorted_global_events = global_events.to_vec();
-        sorted_global_events.sort_by(|a, b| a.event_name.cmp(&b.event_name));
+        if options.sort_order == SortOrder::Alphabetical {
+            sorted_global_events.sort_by(|a, b| a.event_name.cmp(&b.event_name));
+        }
 
+        let events_dir = Path::new(&options.output_dirs.tauria_api).join("events");
         let mut context = Context::new();
+        context.insert("output_format", &options.output_format);
+        context.insert(
+            "interface_path",
+            &options.output_dirs.interface_import_path(&events_dir),
+        );
         context.insert("global_events", &sorted_global_events);
         context.insert(
             "has_user_defined_types_in_global_events",
             &has_user_defined_types_in_global_events,
         ); // この行を追加
-        let asset = Asset::get("tauri_global_event_handler.tera").unwrap();
-        let template = std::str::from_utf8(asset.data.as_ref())?;
-        let rendered = tera.render_str(template, &context)?;
-        let event_dir = output_dir.join("tauria-api").join("events");
-        std::fs::create_dir_all(&event_dir)?;
-        std::fs::write(event_dir.join("TauriGlobalEventHandlers.ts"), rendered)?;
+        let template = load_template(
+            if options.declarations_only {
+                "tauri_global_event_handler_declarations.tera"
+            } else {
+                "tauri_global_event_handler.tera"
+            },
+            options.templates_dir.as_deref(),
+        )?;
+        let rendered = tera.render_str(&template, &context)?;
+        files.push(GeneratedFile::new(
+            events_dir.join(format!("TauriGlobalEventHandlers.{extension}")),
+            rendered,
+        ));
+
+        if options.generate_mock_api && !options.declarations_only {
+            let mut context = Context::new();
+            context.insert("output_format", &options.output_format);
+            context.insert("class_name", "TauriGlobalEventHandlers");
+            context.insert("events", &sorted_global_events);
+            // 実装側と同じく on<イベント名> / once<イベント名> 関数もエクスポートする
+            context.insert("subscription_functions", &true);
+            context.insert(
+                "has_user_defined_types",
+                &has_user_defined_types_in_global_events,
+            );
+            files.push(render_mock_event_handler_file(
+                &mut tera,
+                "TauriGlobalEventHandlers",
+                &mut context,
+                options,
+            )?);
+        }
     }
 
     if !window_events.is_empty() {
-        let mut unique_window_names: Vec<String> = window_events
-            .iter()
-            .map(|e| e.window_name.clone())
-            .collect();
-        unique_window_names.sort();
-        unique_window_names.dedup();
+        let unique_window_names = unique_window_names(window_events, options.sort_order);
 
         for window_name in unique_window_names {
             let mut events_for_window: Vec<_> = window_events
                 .iter()
                 .filter(|e| e.window_name == window_name)
                 .collect();
-            events_for_window.sort_by(|a, b| a.event_name.cmp(&b.event_name));
+            if options.sort_order == SortOrder::Alphabetical {
+                events_for_window.sort_by(|a, b| a.event_name.cmp(&b.event_name));
+            }
+            // グローバルイベントと同様に、ユーザー定義型のペイロードがある場合のみ型をインポートする
+            let has_user_defined_types = events_for_window
+                .iter()
+                .any(|event| event.payload_type.contains("T."));
+            let events_dir = Path::new(&options.output_dirs.tauria_api).join("events");
             let mut context = Context::new();
+            context.insert("output_format", &options.output_format);
+            context.insert(
+                "interface_path",
+                &options.output_dirs.interface_import_path(&events_dir),
+            );
             context.insert("window_name", &window_name);
             context.insert("events", &events_for_window);
-            let asset = Asset::get("tauri_window_event_handler.tera").unwrap();
-            let template = std::str::from_utf8(asset.data.as_ref())?;
-            let rendered = tera.render_str(template, &context)?;
+            context.insert("has_user_defined_types", &has_user_defined_types);
+            let template = load_template(
+                if options.declarations_only {
+                    "tauri_window_event_handler_declarations.tera"
+                } else {
+                    "tauri_window_event_handler.tera"
+                },
+                options.templates_dir.as_deref(),
+            )?;
+            let rendered = tera.render_str(&template, &context)?;
             let pascal_case_window_name = window_name.to_case(Case::Pascal);
-            let event_handler_dir = output_dir.join("tauria-api").join("events");
-            std::fs::create_dir_all(&event_handler_dir)?;
-            std::fs::write(
-                event_handler_dir.join(format!(
-                    "Tauri{pascal_case_window_name}WindowEventHandlers.ts"
+            files.push(GeneratedFile::new(
+                events_dir.join(format!(
+                    "Tauri{pascal_case_window_name}WindowEventHandlers.{extension}"
                 )),
                 rendered,
-            )?;
+            ));
+
+            if options.generate_mock_api && !options.declarations_only {
+                let class_name = format!("Tauri{pascal_case_window_name}WindowEventHandlers");
+                let mut context = Context::new();
+                context.insert("output_format", &options.output_format);
+                context.insert("class_name", &class_name);
+                context.insert("events", &events_for_window);
+                context.insert("subscription_functions", &false);
+                context.insert("has_user_defined_types", &has_user_defined_types);
+                files.push(render_mock_event_handler_file(
+                    &mut tera,
+                    &class_name,
+                    &mut context,
+                    options,
+                )?);
+            }
         }
     }
 
-    Ok(())
+    // イベントハンドラは全入力ファイルのイベントから生成されるため、ソースファイル名は付けない
+    for file in &mut files {
+        if options.format {
+            file.content = format_typescript(&file.content);
+        }
+        file.content = add_banner(&file.content, None);
+    }
+    Ok(files)
+}
+
+/// Renders a mock event handler class for `mock-api/events/<class_name>.ts`.
+fn render_mock_event_handler_file(
+    tera: &mut Tera,
+    class_name: &str,
+    context: &mut Context,
+    options: &GenerateOptions,
+) -> anyhow::Result<GeneratedFile> {
+    let events_dir = Path::new(&options.output_dirs.mock_api).join("events");
+    context.insert(
+        "interface_path",
+        &options.output_dirs.interface_import_path(&events_dir),
+    );
+    let template = load_template("mock_event_handler.tera", options.templates_dir.as_deref())?;
+    let rendered = tera.render_str(&template, context)?;
+    Ok(GeneratedFile::new(
+        events_dir.join(format!("{class_name}.ts")),
+        rendered,
+    ))
 }
 
 /// The result type for `generate_ts_files`.
-pub type GenerateTsFilesResult = (
-    bool,
-    Vec<crate::generator::type_extractor::ExtractedTypeInfo>,
-    Vec<crate::generator::type_extractor::EventInfo>,
-    Vec<crate::generator::type_extractor::WindowEventInfo>,
-);
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GenerateTsFilesResult {
+    /// `true` if commands were found and files were generated, `false` otherwise.
+    pub has_command: bool,
+    /// All extracted user-defined types.
+    pub types: Vec<crate::generator::type_extractor::ExtractedTypeInfo>,
+    /// All extracted global events.
+    pub global_events: Vec<crate::generator::type_extractor::EventInfo>,
+    /// All extracted window-specific events.
+    pub window_events: Vec<crate::generator::type_extractor::WindowEventInfo>,
+    /// Identifiers of the types referenced by the command signatures.
+    pub command_type_names: Vec<String>,
+    /// The Rust names of the Tauri commands, as passed to `invoke`.
+    pub command_names: Vec<String>,
+    /// The commands registered with `tauri::generate_handler!` in the file.
+    #[serde(default)]
+    pub handler_command_names: Vec<String>,
+    /// The command arguments left out of the wrappers because their types are not Deserializable.
+    #[serde(default)]
+    pub dropped_args: Vec<DroppedArgument>,
+    /// The names referenced as `T.<Name>` by the command signatures and type definitions.
+    #[serde(default)]
+    pub type_refs: Vec<String>,
+    /// The rendered command files (interface, Tauri API wrapper, and optional mock API and framework bindings).
+    pub files: Vec<GeneratedFile>,
+}
 
 /// Generates TypeScript files (interfaces, Tauri API wrappers, and optionally mock API) from Rust code.
 ///
 /// This function parses the given Rust code, extracts Tauri commands and types,
-/// and then uses Tera templates to generate corresponding TypeScript files.
+/// and then uses Tera templates to render corresponding TypeScript files.
+/// Nothing is written to disk; see `write_generated_files`.
 ///
 /// # Arguments
 ///
 /// * `rust_code` - A string slice containing the Rust source code.
-/// * `output_dir` - The base directory where the generated files will be saved.
 /// * `file_name` - The base name of the Rust file (without extension), used for naming generated TypeScript files.
-/// * `generate_mock_api` - A boolean indicating whether to generate mock API files.
+/// * `options` - The `GenerateOptions` selecting optional outputs (mock API, framework bindings).
 ///
 /// # Returns
 ///
-/// A `GenerateTsFilesResult` tuple containing:
-/// - `bool`: `true` if commands were found and files were generated, `false` otherwise.
-/// - `Vec<ExtractedTypeInfo>`: All extracted user-defined types.
-/// - `Vec<EventInfo>`: All extracted global events.
-/// - `Vec<WindowEventInfo>`: All extracted window-specific events.
+/// A `GenerateTsFilesResult` describing whether commands were found, together with
+/// the extracted types, events, the types referenced by the command signatures and
+/// the rendered files.
 ///
-/// Returns an `anyhow::Result` error if parsing or file generation fails.
+/// Returns an `anyhow::Result` error if parsing or rendering fails.
 pub fn generate_ts_files(
     rust_code: &str,
-    output_dir: &Path,
     file_name: &str,
-    generate_mock_api: bool,
+    options: &GenerateOptions,
 ) -> anyhow::Result<GenerateTsFilesResult> {
-    let syntax = syn::parse_file(rust_code)?;
-    let all_extracted_types = extract_and_convert_types(&syntax.items, file_name);
-    let functions = extract_tauri_commands(&syntax.items, &all_extracted_types);
-    let (global_events, window_events) = extract_events(&syntax.items, &all_extracted_types);
+    let mut syntax = syn::parse_file(rust_code)?;
+    resolve_type_aliases(&mut syntax.items);
+    let all_extracted_types =
+        extract_and_convert_types(&syntax.items, file_name, &options.type_mapping);
+    let mut functions =
+        extract_tauri_commands(&syntax.items, &all_extracted_types, &options.type_mapping);
+    if options.sort_order == SortOrder::Alphabetical {
+        functions.sort_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+    }
+    let (global_events, window_events) =
+        extract_events(&syntax.items, &all_extracted_types, &options.type_mapping);
+    let handler_command_names = extract_generate_handler_commands(&syntax.items);
+
+    if options.strict {
+        let violations = find_strict_violations(
+            &functions,
+            &all_extracted_types,
+            options.type_mapping.json_value_as_any,
+        );
+        if !violations.is_empty() {
+            anyhow::bail!(
+                "{file_name}.rs contains types that could not be resolved (--strict):\n{}",
+                violations.join("\n")
+            );
+        }
+    }
+    let type_refs = namespace_type_refs(&functions, &all_extracted_types);
 
     // デバッグログの追加
     log::debug!("Extracted types: {all_extracted_types:?}");
@@ -177,17 +687,33 @@ pub fn generate_ts_files(
     log::debug!("Extracted window events: {window_events:?}");
 
     if functions.is_empty() {
-        return Ok((false, all_extracted_types, global_events, window_events));
+        return Ok(GenerateTsFilesResult {
+            has_command: false,
+            types: all_extracted_types,
+            global_events,
+            window_events,
+            command_type_names: Vec::new(),
+            command_names: Vec::new(),
+            handler_command_names,
+            dropped_args: Vec::new(),
+            type_refs,
+            files: Vec::new(),
+        });
     }
 
     let mut tera = Tera::default();
     register_tera_filters(&mut tera);
 
     let mut context = Context::new();
+    context.insert("output_format", &options.output_format);
     context.insert("file_name", &file_name.to_case(Case::Pascal));
     context.insert("functions", &functions);
     context.insert("interface_name", &file_name.to_case(Case::Pascal));
     context.insert("original_file_name", &file_name);
+    context.insert("validate_args", &options.validate_args);
+    context.insert("source_lines", &options.source_lines);
+    context.insert("api_style", &options.api_style);
+    context.insert("invoke_import", options.invoke_import());
 
     let mut has_user_defined_types_in_commands = false;
     for func in &functions {
@@ -196,16 +722,21 @@ pub fn generate_ts_files(
             for arg_str_val in args {
                 if let Some(arg_str) = arg_str_val.as_str() {
                     // "name: T.MyType" の形式から "MyType" を抽出
-                    if let Some(type_part) = arg_str.split(": ").nth(1) {
-                        if type_part.starts_with("T.") {
-                            let type_name = type_part.trim_start_matches("T.");
-                            if all_extracted_types.iter().any(|info| {
-                                info.name == type_name
-                                    && (info.is_serializable || info.is_deserializable)
-                            }) {
-                                has_user_defined_types_in_commands = true;
-                                break;
-                            }
+                    // (Channel の引数は "name: (msg: T.MyType) => void" の形式)
+                    if let Some(type_part) = arg_str.split_once(": ").map(|(_, type_part)| {
+                        type_part
+                            .strip_prefix("(msg: ")
+                            .and_then(|callback| callback.strip_suffix(") => void"))
+                            .unwrap_or(type_part)
+                    }) && type_part.starts_with("T.")
+                    {
+                        let type_name = type_part.trim_start_matches("T.");
+                        if all_extracted_types.iter().any(|info| {
+                            info.name == type_name
+                                && (info.is_serializable || info.is_deserializable)
+                        }) {
+                            has_user_defined_types_in_commands = true;
+                            break;
                         }
                     }
                 }
@@ -216,15 +747,15 @@ pub fn generate_ts_files(
         }
 
         // 戻り値にユーザー定義型が含まれているかチェック
-        if let Some(return_type_val) = func["return_type"].as_str() {
-            if return_type_val.starts_with("T.") {
-                let type_name = return_type_val.trim_start_matches("T.");
-                if all_extracted_types.iter().any(|info| {
-                    info.name == type_name && (info.is_serializable || info.is_deserializable)
-                }) {
-                    has_user_defined_types_in_commands = true;
-                    break;
-                }
+        if let Some(return_type_val) = func["return_type"].as_str()
+            && return_type_val.starts_with("T.")
+        {
+            let type_name = return_type_val.trim_start_matches("T.");
+            if all_extracted_types.iter().any(|info| {
+                info.name == type_name && (info.is_serializable || info.is_deserializable)
+            }) {
+                has_user_defined_types_in_commands = true;
+                break;
             }
         }
     }
@@ -232,59 +763,140 @@ pub fn generate_ts_files(
         "has_user_defined_types_in_commands",
         &has_user_defined_types_in_commands,
     );
+    context.insert(
+        "uses_channel",
+        &functions
+            .iter()
+            .any(|func| func["uses_channel"].as_bool() == Some(true)),
+    );
 
     log::debug!("Tera context: {context:?}");
 
-    let asset = Asset::get("command_interfaces.tera").unwrap();
-    let command_interface_template = std::str::from_utf8(asset.data.as_ref())?;
-    let rendered_interface = tera.render_str(command_interface_template, &context)?;
-    let interface_dir = output_dir.join("interface").join("commands");
-    std::fs::create_dir_all(&interface_dir)?;
-    std::fs::write(
-        interface_dir.join(format!("{}.ts", file_name.to_case(Case::Pascal))),
+    let pascal_case_file_name = file_name.to_case(Case::Pascal);
+    let mut files = Vec::new();
+
+    let extension = options.file_extension();
+    let command_interface_template =
+        load_template("command_interfaces.tera", options.templates_dir.as_deref())?;
+    let rendered_interface = tera.render_str(&command_interface_template, &context)?;
+    files.push(GeneratedFile::new(
+        Path::new(&options.output_dirs.interface)
+            .join("commands")
+            .join(format!("{pascal_case_file_name}.{extension}")),
         rendered_interface,
-    )?;
-    info!(
-        "Generated interface file: {}.ts",
-        file_name.to_case(Case::Pascal)
-    );
+    ));
 
-    let asset = Asset::get("tauria_api.tera").unwrap();
-    let tauri_api_template = std::str::from_utf8(asset.data.as_ref())?;
-    let rendered_tauri_api = tera.render_str(tauri_api_template, &context)?;
-    let tauri_api_dir = output_dir.join("tauria-api").join("commands");
-    std::fs::create_dir_all(&tauri_api_dir)?;
-    std::fs::write(
-        tauri_api_dir.join(format!("{}.ts", file_name.to_case(Case::Pascal))),
-        rendered_tauri_api,
+    // 宣言のみの場合は invoke の実装を含まない .d.ts を出力する
+    let tauri_api_template = load_template(
+        if options.declarations_only {
+            "tauria_api_declarations.tera"
+        } else {
+            "tauria_api.tera"
+        },
+        options.templates_dir.as_deref(),
     )?;
-    info!(
-        "Generated tauri-api file: {}.ts",
-        file_name.to_case(Case::Pascal)
+    let tauri_api_dir = Path::new(&options.output_dirs.tauria_api).join("commands");
+    context.insert(
+        "interface_path",
+        &options.output_dirs.interface_import_path(&tauri_api_dir),
     );
+    let rendered_tauri_api = tera.render_str(&tauri_api_template, &context)?;
+    files.push(GeneratedFile::new(
+        tauri_api_dir.join(format!("{pascal_case_file_name}.{extension}")),
+        rendered_tauri_api,
+    ));
 
-    if generate_mock_api {
-        let asset = Asset::get("mock_api.tera").unwrap();
-        let mock_api_template = std::str::from_utf8(asset.data.as_ref())?;
-        let rendered_mock_api = tera.render_str(mock_api_template, &context)?;
-        let mock_api_dir = output_dir.join("mock-api");
-        std::fs::create_dir_all(&mock_api_dir)?;
-        std::fs::write(
-            mock_api_dir.join(format!("{}.ts", file_name.to_case(Case::Pascal))),
+    if options.generate_mock_api && !options.declarations_only {
+        tera.register_filter("mock_value", MockValueFilter::new(&all_extracted_types));
+        let mock_api_dir = Path::new(&options.output_dirs.mock_api);
+        context.insert(
+            "interface_path",
+            &options.output_dirs.interface_import_path(mock_api_dir),
+        );
+        let mock_api_template = load_template("mock_api.tera", options.templates_dir.as_deref())?;
+        let rendered_mock_api = tera.render_str(&mock_api_template, &context)?;
+        files.push(GeneratedFile::new(
+            mock_api_dir.join(format!("{pascal_case_file_name}.ts")),
             rendered_mock_api,
-        )?;
-        info!(
-            "Generated mock-api file: {}.ts",
-            file_name.to_case(Case::Pascal)
+        ));
+    }
+
+    if options.framework == Some(Framework::Pinia) && !options.declarations_only {
+        // ストアの state.result は全コマンドの戻り値型のユニオンとする
+        let mut unique_result_types: Vec<&str> = Vec::new();
+        for result_type in functions
+            .iter()
+            .filter_map(|func| func["return_type"].as_str())
+        {
+            if !unique_result_types.contains(&result_type) {
+                unique_result_types.push(result_type);
+            }
+        }
+        context.insert("store_id", &file_name.to_case(Case::Snake));
+        context.insert("result_type", &unique_result_types.join(" | "));
+        context.insert(
+            "interface_path",
+            &options
+                .output_dirs
+                .interface_import_path(Path::new("pinia-api")),
         );
+
+        let pinia_api_template = load_template("pinia_api.tera", options.templates_dir.as_deref())?;
+        let rendered_pinia_api = tera.render_str(&pinia_api_template, &context)?;
+        files.push(GeneratedFile::new(
+            Path::new("pinia-api").join(format!("{pascal_case_file_name}.ts")),
+            rendered_pinia_api,
+        ));
     }
 
-    Ok((true, all_extracted_types, global_events, window_events))
+    let mut command_type_names: Vec<String> = functions
+        .iter()
+        .filter_map(|func| func["referenced_types"].as_array())
+        .flatten()
+        .filter_map(|name| name.as_str().map(str::to_string))
+        .collect();
+    command_type_names.sort();
+    command_type_names.dedup();
+    let command_names = functions
+        .iter()
+        .filter_map(|func| func["name"].as_str().map(str::to_string))
+        .collect();
+    let dropped_args = functions
+        .iter()
+        .filter_map(|func| {
+            serde_json::from_value::<Vec<DroppedArgument>>(func["dropped_args"].clone()).ok()
+        })
+        .flatten()
+        .collect();
+    let source_file_name = format!("{file_name}.rs");
+    for file in &mut files {
+        if options.format {
+            file.content = format_typescript(&file.content);
+        }
+        file.content = add_banner(&file.content, Some(&source_file_name));
+    }
+
+    Ok(GenerateTsFilesResult {
+        has_command: true,
+        types: all_extracted_types,
+        global_events,
+        window_events,
+        command_type_names,
+        command_names,
+        handler_command_names,
+        dropped_args,
+        type_refs,
+        files,
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::generator::banner::strip_banner;
+    use crate::generator::index_file_generator::render_user_types;
+    use crate::generator::options::{ApiStyle, OutputFormat, TauriVersion, TypeMapping};
+    use crate::generator::type_extractor::EventInfo;
     use std::fs;
     use std::path::PathBuf;
 
@@ -332,7 +944,7 @@ mod tests {
             test_case_name
         };
 
-        let result = generate_ts_files(&rust_code, &output_dir, file_name, false);
+        let result = generate_ts_files(&rust_code, file_name, &GenerateOptions::default());
 
         // todo!() によりテストは失敗するが、ビルドは通るようになるはず
         if result.is_err() {
@@ -341,12 +953,23 @@ mod tests {
         }
         let result = result.unwrap();
 
-        let (has_command, _all_types, global_events, window_events) = result;
+        let GenerateTsFilesResult {
+            has_command,
+            global_events,
+            window_events,
+            files,
+            ..
+        } = result;
+        write_generated_files(&output_dir, &files).unwrap();
 
         if !global_events.is_empty() || !window_events.is_empty() {
-            let event_result =
-                generate_event_handler_files(&output_dir, &global_events, &window_events);
-            assert!(event_result.is_ok());
+            let event_files = generate_event_handler_files(
+                &global_events,
+                &window_events,
+                &GenerateOptions::default(),
+            )
+            .unwrap();
+            write_generated_files(&output_dir, &event_files).unwrap();
         }
 
         // event_window テストケースでは interface/commands 関連のファイル比較をスキップ
@@ -416,6 +1039,8 @@ mod tests {
 
         let generated_content = fs::read_to_string(&generated_path)
             .unwrap_or_else(|_| panic!("生成されたファイルが読み込めません: {:?}", generated_path));
+        // バナーにはバージョンが含まれるため、期待値のファイルとは比較しない
+        let generated_content = strip_banner(&generated_content);
         let expected_content = fs::read_to_string(&expected_path)
             .unwrap_or_else(|_| panic!("期待されるファイルが読み込めません: {:?}", expected_path));
 
@@ -442,6 +1067,16 @@ mod tests {
         run_ts_wrapper_test("enum_test");
     }
 
+    #[test]
+    fn test_generate_ts_wrapper_for_tagged_enum_test_file() {
+        run_ts_wrapper_test("tagged_enum_test");
+    }
+
+    #[test]
+    fn test_generate_ts_wrapper_for_impl_command() {
+        run_ts_wrapper_test("impl_command");
+    }
+
     #[test]
     fn test_generate_ts_wrapper_for_nesting_type_test() {
         run_ts_wrapper_test("nesting_type_test");
@@ -477,6 +1112,11 @@ mod tests {
         run_ts_wrapper_test("event_global");
     }
 
+    #[test]
+    fn test_generate_ts_wrapper_for_event_emit_all() {
+        run_ts_wrapper_test("event_emit_all");
+    }
+
     #[test]
     fn test_generate_ts_wrapper_for_event_window() {
         run_ts_wrapper_test("event_window");
@@ -487,6 +1127,851 @@ mod tests {
         run_ts_wrapper_test("event_window_many");
     }
 
+    #[test]
+    fn test_generate_pinia_store_for_basic_file() {
+        let test_case_name = "basic";
+        let rust_file_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("test/data")
+            .join(test_case_name)
+            .join("src")
+            .join("basic.rs");
+        let output_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("target/generated_ts")
+            .join("basic_pinia");
+
+        if output_dir.exists() {
+            fs::remove_dir_all(&output_dir).unwrap();
+        }
+        fs::create_dir_all(&output_dir).unwrap();
+
+        let rust_code = fs::read_to_string(&rust_file_path).unwrap();
+        let options = GenerateOptions {
+            framework: Some(Framework::Pinia),
+            ..Default::default()
+        };
+        let result = generate_ts_files(&rust_code, test_case_name, &options).unwrap();
+        write_generated_files(&output_dir, &result.files).unwrap();
+
+        compare_generated_files(&output_dir, test_case_name, "pinia-api/Basic.ts");
+    }
+
+    #[test]
+    fn test_generate_class_api_style_for_basic_file() {
+        let test_case_name = "basic";
+        let rust_file_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("test/data")
+            .join(test_case_name)
+            .join("src")
+            .join("basic.rs");
+        let output_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("target/generated_ts")
+            .join("basic_class");
+
+        if output_dir.exists() {
+            fs::remove_dir_all(&output_dir).unwrap();
+        }
+        fs::create_dir_all(&output_dir).unwrap();
+
+        let rust_code = fs::read_to_string(&rust_file_path).unwrap();
+        let options = GenerateOptions {
+            api_style: ApiStyle::Class,
+            generate_mock_api: true,
+            ..Default::default()
+        };
+        let result = generate_ts_files(&rust_code, test_case_name, &options).unwrap();
+        // 期待値は expected/class-style 以下に置かれている
+        write_generated_files(&output_dir.join("class-style"), &result.files).unwrap();
+
+        compare_generated_files(
+            &output_dir,
+            test_case_name,
+            "class-style/tauria-api/commands/Basic.ts",
+        );
+        compare_generated_files(&output_dir, test_case_name, "class-style/mock-api/Basic.ts");
+
+        // 宣言ファイルでもクラスをエクスポートする
+        let options = GenerateOptions {
+            api_style: ApiStyle::Class,
+            declarations_only: true,
+            ..Default::default()
+        };
+        let result = generate_ts_files(&rust_code, test_case_name, &options).unwrap();
+        let declarations = &result
+            .files
+            .iter()
+            .find(|file| file.path.starts_with("tauria-api"))
+            .unwrap()
+            .content;
+        assert!(declarations.contains("export declare class BasicApi implements T.IBasic {"));
+        assert!(declarations.contains("    constructor();\n}"));
+        assert!(!declarations.contains("private constructor"));
+    }
+
+    #[test]
+    fn test_generate_declarations_for_basic_file() {
+        let test_case_name = "basic";
+        let rust_file_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("test/data")
+            .join(test_case_name)
+            .join("src")
+            .join("basic.rs");
+        let output_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("target/generated_ts")
+            .join("basic_declarations");
+
+        if output_dir.exists() {
+            fs::remove_dir_all(&output_dir).unwrap();
+        }
+        fs::create_dir_all(&output_dir).unwrap();
+
+        let rust_code = fs::read_to_string(&rust_file_path).unwrap();
+        let options = GenerateOptions {
+            declarations_only: true,
+            generate_mock_api: true,
+            ..Default::default()
+        };
+        let result = generate_ts_files(&rust_code, test_case_name, &options).unwrap();
+        write_generated_files(&output_dir, &result.files).unwrap();
+
+        compare_generated_files(&output_dir, test_case_name, "interface/commands/Basic.d.ts");
+        compare_generated_files(
+            &output_dir,
+            test_case_name,
+            "tauria-api/commands/Basic.d.ts",
+        );
+        // 宣言のみの場合はモック API を生成しない
+        assert_eq!(result.files.len(), 2);
+        assert!(!output_dir.join("mock-api").exists());
+    }
+
+    #[test]
+    fn test_generate_mock_api_for_struct_test() {
+        let test_case_name = "struct_test";
+        let rust_file_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("test/data")
+            .join(test_case_name)
+            .join("src")
+            .join("struct_test.rs");
+        let output_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("target/generated_ts")
+            .join("struct_test_mock");
+
+        if output_dir.exists() {
+            fs::remove_dir_all(&output_dir).unwrap();
+        }
+        fs::create_dir_all(&output_dir).unwrap();
+
+        let rust_code = fs::read_to_string(&rust_file_path).unwrap();
+        let options = GenerateOptions {
+            generate_mock_api: true,
+            ..Default::default()
+        };
+        let result = generate_ts_files(&rust_code, test_case_name, &options).unwrap();
+        write_generated_files(&output_dir, &result.files).unwrap();
+
+        compare_generated_files(&output_dir, test_case_name, "mock-api/StructTest.ts");
+
+        // 各コマンドは既定値を返す前に上書き用のレジストリを参照する
+        let content = fs::read_to_string(output_dir.join("mock-api/StructTest.ts")).unwrap();
+        assert!(content.contains("export type StructTestCommandName = keyof T.IStructTest;"));
+        assert!(content.contains("export const structTestMocks: { [K in StructTestCommandName]?: T.IStructTest[K] } = {};"));
+        for (command, args) in [("getUserData", "id"), ("getProductData", "productId")] {
+            assert!(content.contains(&format!(
+                "const mock = structTestMocks.{command};\n        if (mock) {{\n            return await mock({args});"
+            )));
+        }
+    }
+
+    #[test]
+    fn test_generate_ts_files_cjs_imports() {
+        let rust_code = r#"
+            #[derive(Serialize, Deserialize)]
+            pub struct User { pub id: u32 }
+
+            #[tauri::command]
+            fn get_user(id: u32) -> User {}
+        "#;
+        let options = GenerateOptions {
+            output_format: OutputFormat::Cjs,
+            ..Default::default()
+        };
+        let result = generate_ts_files(rust_code, "users", &options).unwrap();
+        let tauri_api = &result
+            .files
+            .iter()
+            .find(|file| file.path.starts_with("tauria-api"))
+            .unwrap()
+            .content;
+        // 実行時の import は TypeScript の import 文のままにし、tsc が require に変換する
+        assert!(tauri_api.contains("import { invoke } from '@tauri-apps/api/core';"));
+        assert!(tauri_api.contains("import T = require('../../interface/');"));
+        assert!(!tauri_api.contains("import * as T"));
+        assert!(!tauri_api.contains("const {"));
+
+        let global_events = vec![EventInfo {
+            event_name: "global".to_string(),
+            payload_type: "string".to_string(),
+        }];
+        let files = generate_event_handler_files(&global_events, &[], &options).unwrap();
+        assert!(files[0].content.contains(
+            "import { Event, listen, once, UnlistenFn } from \"@tauri-apps/api/event\";"
+        ));
+        assert!(
+            !files[0]
+                .content
+                .contains("= require(\"@tauri-apps/api/event\")")
+        );
+    }
+
+    #[test]
+    fn test_generate_ts_files_zero_argument_commands() {
+        let rust_code = r#"
+            #[tauri::command]
+            fn read_file1() -> tauri::ipc::Response {}
+
+            #[tauri::command]
+            fn get_count(state: tauri::State<'_, Counter>) -> u32 {}
+        "#;
+        let result = generate_ts_files(rust_code, "files", &GenerateOptions::default()).unwrap();
+        let tauri_api = &result
+            .files
+            .iter()
+            .find(|file| file.path.starts_with("tauria-api"))
+            .unwrap()
+            .content;
+        // 引数のないコマンド (State などフロントエンドから渡さない引数のみの場合も含む) はペイロードを渡さない
+        assert!(tauri_api.contains(
+            "    async readFile1(): Promise<unknown> {\n        return await invoke('read_file1');\n    }"
+        ));
+        assert!(tauri_api.contains(
+            "    async getCount(): Promise<number> {\n        return await invoke('get_count');\n    }"
+        ));
+        assert!(!tauri_api.contains("{  }"));
+
+        let options = GenerateOptions {
+            framework: Some(Framework::Pinia),
+            ..Default::default()
+        };
+        let result = generate_ts_files(rust_code, "files", &options).unwrap();
+        let pinia_api = &result
+            .files
+            .iter()
+            .find(|file| file.path.starts_with("pinia-api"))
+            .unwrap()
+            .content;
+        assert!(pinia_api.contains("const result = await invoke<number>('get_count');"));
+        assert!(!pinia_api.contains("{  }"));
+    }
+
+    #[test]
+    fn test_generate_ts_files_invoke_import() {
+        let rust_code = r#"
+            #[tauri::command]
+            fn greet(name: String) -> String {}
+        "#;
+        let options = GenerateOptions {
+            invoke_import: Some("@tauri-apps/api/tauri".to_string()),
+            framework: Some(Framework::Pinia),
+            ..Default::default()
+        };
+        let result = generate_ts_files(rust_code, "greeting", &options).unwrap();
+        let content_of = |dir: &str| {
+            &result
+                .files
+                .iter()
+                .find(|file| file.path.starts_with(dir))
+                .unwrap()
+                .content
+        };
+        assert!(
+            content_of("tauria-api").contains("import { invoke } from '@tauri-apps/api/tauri';")
+        );
+        assert!(
+            content_of("pinia-api").contains("import { invoke } from '@tauri-apps/api/tauri';")
+        );
+        assert!(!content_of("tauria-api").contains("@tauri-apps/api/core"));
+
+        let options = GenerateOptions {
+            invoke_import: Some("@/lib/tauri".to_string()),
+            output_format: OutputFormat::Cjs,
+            ..Default::default()
+        };
+        let result = generate_ts_files(rust_code, "greeting", &options).unwrap();
+        assert!(
+            result.files[1]
+                .content
+                .contains("import { invoke } from '@/lib/tauri';")
+        );
+    }
+
+    #[test]
+    fn test_generate_ts_files_tauri_version() {
+        let rust_code = r#"
+            #[tauri::command]
+            fn read_file(path: String) -> Vec<u8> {}
+        "#;
+        let global_events = vec![EventInfo {
+            event_name: "progress".to_string(),
+            payload_type: "number".to_string(),
+        }];
+        for (tauri_version, invoke_line, bytes_type) in [
+            (
+                TauriVersion::V1,
+                "import { invoke } from '@tauri-apps/api/tauri';",
+                "Promise<number[]>",
+            ),
+            (
+                TauriVersion::V2,
+                "import { invoke } from '@tauri-apps/api/core';",
+                "Promise<Uint8Array>",
+            ),
+        ] {
+            let options = GenerateOptions {
+                type_mapping: TypeMapping {
+                    tauri_version,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            let result = generate_ts_files(rust_code, "files", &options).unwrap();
+            let tauri_api = &result
+                .files
+                .iter()
+                .find(|file| file.path.starts_with("tauria-api"))
+                .unwrap()
+                .content;
+            assert!(tauri_api.contains(invoke_line), "{tauri_version:?}");
+            assert!(
+                tauri_api.contains(&format!("async readFile(path: string): {bytes_type}")),
+                "{tauri_version:?}"
+            );
+
+            // イベント API の import 先は v1 と v2 で共通
+            let event_files = generate_event_handler_files(&global_events, &[], &options).unwrap();
+            assert!(
+                event_files[0].content.contains(
+                    "import { Event, listen, once, UnlistenFn } from \"@tauri-apps/api/event\";"
+                ),
+                "{tauri_version:?}"
+            );
+        }
+
+        // --invoke-import はバージョンごとの既定の import 先より優先される
+        let options = GenerateOptions {
+            invoke_import: Some("@/lib/tauri".to_string()),
+            type_mapping: TypeMapping {
+                tauri_version: TauriVersion::V1,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert_eq!(options.invoke_import(), "@/lib/tauri");
+    }
+
+    #[test]
+    fn test_generate_ts_files_channel_argument() {
+        let rust_code = r#"
+            #[derive(Clone, Serialize)]
+            pub struct DownloadEvent { pub progress: u32 }
+
+            #[tauri::command]
+            fn download(url: String, on_event: tauri::ipc::Channel<DownloadEvent>) {}
+        "#;
+        let result =
+            generate_ts_files(rust_code, "downloads", &GenerateOptions::default()).unwrap();
+        let content = |prefix: &str| {
+            &result
+                .files
+                .iter()
+                .find(|file| file.path.starts_with(prefix))
+                .unwrap()
+                .content
+        };
+
+        assert!(content("interface").contains("import * as T from \"../types/index\""));
+        assert!(content("interface").contains(
+            "download(url: string, onEvent: (msg: T.DownloadEvent) => void): Promise<void>;"
+        ));
+        let tauri_api = content("tauria-api");
+        assert!(tauri_api.contains("import { Channel, invoke } from '@tauri-apps/api/core';"));
+        assert!(tauri_api.contains(
+            "return await invoke('download', { url: url, onEvent: Object.assign(new Channel<T.DownloadEvent>(), { onmessage: onEvent }) });"
+        ));
+    }
+
+    #[test]
+    fn test_generate_ts_files_template_override() {
+        let templates_dir = tempfile::tempdir().unwrap();
+        let template = load_template("tauria_api.tera", None).unwrap().replace(
+            "class {{ class_name }}",
+            "// customized\nclass {{ class_name }}",
+        );
+        fs::write(templates_dir.path().join("tauria_api.tera"), template).unwrap();
+
+        let rust_code = "#[tauri::command]\nfn greet() -> String { String::new() }";
+        let generate = |templates_dir: Option<&Path>| {
+            let options = GenerateOptions {
+                templates_dir: templates_dir.map(Path::to_path_buf),
+                ..Default::default()
+            };
+            generate_ts_files(rust_code, "basic", &options)
+                .unwrap()
+                .files
+        };
+        let default_files = generate(None);
+        let overridden_files = generate(Some(templates_dir.path()));
+
+        // 上書きしたテンプレートのみが反映され、他は埋め込みのテンプレートが使われる
+        assert!(
+            overridden_files[1]
+                .content
+                .contains("// customized\nclass Basic")
+        );
+        assert!(!default_files[1].content.contains("// customized"));
+        assert_eq!(overridden_files[0], default_files[0]);
+
+        assert_ne!(
+            template_overrides_hash(Some(templates_dir.path())),
+            template_overrides_hash(None)
+        );
+    }
+
+    #[test]
+    fn test_generate_ts_files_strict() {
+        let rust_code = r#"
+            #[derive(Serialize)]
+            pub struct Report { pub render: fn() -> String }
+
+            #[derive(Deserialize)]
+            pub struct Secret { pub value: String }
+
+            #[tauri::command]
+            fn run(callback: fn(u32) -> u32) -> Report { todo!() }
+
+            #[tauri::command]
+            fn reveal() -> Secret { todo!() }
+        "#;
+        let strict = GenerateOptions {
+            strict: true,
+            ..Default::default()
+        };
+        assert!(generate_ts_files(rust_code, "runner", &GenerateOptions::default()).is_ok());
+
+        let error = generate_ts_files(rust_code, "runner", &strict)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            error,
+            "runner.rs contains types that could not be resolved (--strict):\n\
+             Command `reveal`: the return type is `unknown` because `Secret` does not derive Serialize\n\
+             Command `run`: argument `callback` falls back to `any` (`any`)\n\
+             Type `Report` in runner.rs falls back to `any` (`any`)"
+        );
+
+        // --json-value-as-any では any は意図した出力なので報告しない
+        let rust_code = "#[tauri::command]\nfn raw() -> serde_json::Value { todo!() }";
+        let options = GenerateOptions {
+            type_mapping: TypeMapping {
+                json_value_as_any: true,
+                ..Default::default()
+            },
+            ..strict
+        };
+        assert!(generate_ts_files(rust_code, "raw", &options).is_ok());
+    }
+
+    #[test]
+    fn test_generate_ts_files_banner() {
+        let rust_code = "#[tauri::command]\nfn greet() -> String { String::new() }";
+        let options = GenerateOptions {
+            generate_mock_api: true,
+            ..Default::default()
+        };
+        let result = generate_ts_files(rust_code, "basic", &options).unwrap();
+        let banner = format!(
+            "// @generated by tauria-tsgen v{} — do not edit\n// source: basic.rs, hash: ",
+            env!("CARGO_PKG_VERSION")
+        );
+        assert_eq!(result.files.len(), 3);
+        for file in &result.files {
+            assert!(file.content.starts_with(&banner), "{:?}", file.path);
+        }
+    }
+
+    #[test]
+    fn test_generate_ts_files_validate_args() {
+        let rust_code = r#"
+            #[tauri::command]
+            fn find_user(user_id: u32, filter: Option<String>) -> String { String::new() }
+        "#;
+        let options = GenerateOptions {
+            validate_args: true,
+            ..Default::default()
+        };
+        let result = generate_ts_files(rust_code, "users", &options).unwrap();
+        let tauri_api = &result.files[1].content;
+        assert!(tauri_api.contains(
+            "        if (userId === undefined) {\n            throw new Error(\"Missing required argument `userId` of command `find_user`\");\n        }\n        return await invoke('find_user'"
+        ));
+        // Option<T> の引数は undefined を許すため検査しない
+        assert!(!tauri_api.contains("filter === undefined"));
+
+        let result = generate_ts_files(rust_code, "users", &GenerateOptions::default()).unwrap();
+        assert!(!result.files[1].content.contains("=== undefined"));
+    }
+
+    #[test]
+    fn test_generate_ts_files_tuple_and_unit_struct_args() {
+        let rust_code = r#"
+            #[derive(Serialize, Deserialize)]
+            struct Point(i32, i32);
+
+            #[derive(Serialize, Deserialize)]
+            struct UserId(u32);
+
+            #[derive(Serialize, Deserialize)]
+            struct Ack;
+
+            #[tauri::command]
+            fn move_to(data: Point, owner: UserId) -> Ack { Ack }
+        "#;
+        let options = GenerateOptions {
+            generate_mock_api: true,
+            ..Default::default()
+        };
+        let result = generate_ts_files(rust_code, "shapes", &options).unwrap();
+        let interface = &result.files[0].content;
+        assert!(
+            interface.contains("moveTo(data: T.Point, owner: T.UserId): Promise<T.Ack>;"),
+            "{interface}"
+        );
+
+        let types = render_user_types(&result.types, &options).unwrap();
+        assert!(types.contains("export type Point = [number, number];"));
+        assert!(types.contains("export type UserId = number;"));
+        assert!(types.contains("export type Ack = null;"));
+
+        // モックは unit 構造体の値として null を返す
+        let mock_api = &result.files[2].content;
+        assert!(mock_api.contains("        return null;\n"), "{mock_api}");
+    }
+
+    #[test]
+    fn test_generate_ts_files_source_lines() {
+        let rust_code = "#[derive(Serialize)]
+struct User {
+    id: u32,
+}
+
+#[tauri::command]
+fn get_user(id: u32) -> User { User { id } }
+";
+        let options = GenerateOptions {
+            source_lines: true,
+            ..Default::default()
+        };
+        let result = generate_ts_files(rust_code, "users", &options).unwrap();
+        for file in &result.files {
+            assert!(
+                file.content.contains("// from users.rs:7\n"),
+                "{}",
+                file.content
+            );
+        }
+        let types = render_user_types(&result.types, &options).unwrap();
+        assert!(types.contains("//- Generated from users.rs:2\n"));
+
+        // 既定では行番号を出力しない
+        let options = GenerateOptions::default();
+        let result = generate_ts_files(rust_code, "users", &options).unwrap();
+        assert!(
+            result
+                .files
+                .iter()
+                .all(|file| !file.content.contains("// from users.rs"))
+        );
+        let types = render_user_types(&result.types, &options).unwrap();
+        assert!(types.contains("//- Generated from users.rs\n"));
+    }
+
+    #[test]
+    fn test_generate_ts_files_command_names() {
+        let rust_code = r#"
+            #[tauri::command]
+            fn get_user_data(id: u32) -> String { String::new() }
+
+            fn helper() {}
+
+            #[tauri::command]
+            async fn delete_user(id: u32) {}
+        "#;
+        let result =
+            generate_ts_files(rust_code, "user_commands", &GenerateOptions::default()).unwrap();
+        assert!(result.has_command);
+        assert_eq!(result.command_names, ["delete_user", "get_user_data"]);
+
+        let result =
+            generate_ts_files("fn helper() {}", "helpers", &GenerateOptions::default()).unwrap();
+        assert!(!result.has_command);
+        assert!(result.command_names.is_empty());
+    }
+
+    #[test]
+    fn test_generate_ts_files_sort_order() {
+        let rust_code = r#"
+            #[tauri::command]
+            fn zoom() {}
+
+            #[tauri::command]
+            fn attach() {}
+        "#;
+        let global_events = vec![
+            EventInfo {
+                event_name: "saved".to_string(),
+                payload_type: "string".to_string(),
+            },
+            EventInfo {
+                event_name: "loaded".to_string(),
+                payload_type: "string".to_string(),
+            },
+        ];
+        let cases = [
+            (
+                SortOrder::Alphabetical,
+                ["attach", "zoom"],
+                ["loaded", "saved"],
+            ),
+            (SortOrder::Source, ["zoom", "attach"], ["saved", "loaded"]),
+        ];
+        for (sort_order, commands, events) in cases {
+            let options = GenerateOptions {
+                sort_order,
+                ..Default::default()
+            };
+            let result = generate_ts_files(rust_code, "tools", &options).unwrap();
+            assert_eq!(result.command_names, commands, "{sort_order:?}");
+            let interface = &result.files[0].content;
+            let first = interface.find(&format!("{}()", commands[0])).unwrap();
+            let second = interface.find(&format!("{}()", commands[1])).unwrap();
+            assert!(first < second, "{sort_order:?}");
+
+            let files = generate_event_handler_files(&global_events, &[], &options).unwrap();
+            let first = files[0].content.find(&format!("'{}'", events[0])).unwrap();
+            let second = files[0].content.find(&format!("'{}'", events[1])).unwrap();
+            assert!(first < second, "{sort_order:?}");
+        }
+    }
+
+    #[test]
+    fn test_mock_value() {
+        let rust_code = r#"
+            #[derive(Serialize, Deserialize)]
+            pub struct Base { pub id: u32 }
+
+            #[derive(Serialize, Deserialize)]
+            pub struct Item {
+                #[serde(flatten)]
+                pub base: Base,
+                #[serde(rename = "item-name")]
+                pub name: String,
+                pub note: Option<String>,
+                pub tags: Vec<String>,
+                pub kind: Kind,
+                pub shape: Shape,
+            }
+
+            #[derive(Serialize, Deserialize)]
+            pub enum Kind { Small, Large }
+
+            #[derive(Serialize, Deserialize)]
+            pub enum Shape { Circle(f64), Square }
+
+            #[derive(Serialize, Deserialize)]
+            pub struct Node { pub next: Box<Node> }
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let types = extract_and_convert_types(&syntax.items, "test", &Default::default());
+        let filter = MockValueFilter::new(&types);
+        let mock = |ts_type: &str| mock_value(ts_type, &filter.types, 0);
+
+        assert_eq!(mock("string"), "\"\"");
+        assert_eq!(mock("number"), "0");
+        assert_eq!(mock("bigint"), "0n");
+        assert_eq!(mock("boolean"), "false");
+        assert_eq!(mock("void"), "undefined");
+        assert_eq!(mock("T.User | undefined"), "undefined");
+        assert_eq!(mock("T.User | null"), "null");
+        assert_eq!(mock("(number | undefined)[]"), "[]");
+        assert_eq!(mock("Record<string, number>"), "{}");
+        assert_eq!(mock("[string, number]"), "[\"\", 0]");
+        assert_eq!(mock("Uint8Array"), "new Uint8Array()");
+        assert_eq!(
+            mock("T.Item"),
+            r#"{ id: 0, "item-name": "", tags: [], kind: "Small", shape: { Circle: 0 } }"#
+        );
+        // 他のファイルの型や再帰的な型は空のオブジェクトをキャストする
+        assert_eq!(mock("T.Other"), "{} as T.Other");
+        assert_eq!(
+            mock("T.Node"),
+            "{ next: { next: { next: { next: {} as T.Node } } } }"
+        );
+    }
+
+    #[test]
+    fn test_generate_mock_event_handler_files() {
+        use crate::generator::type_extractor::{EventInfo, WindowEventInfo};
+
+        let output_dir = tempfile::tempdir().unwrap();
+        let global_events = vec![EventInfo {
+            event_name: "global".to_string(),
+            payload_type: "string".to_string(),
+        }];
+        let window_events = vec![WindowEventInfo {
+            window_name: "main".to_string(),
+            event_name: "window-event".to_string(),
+            payload_type: "T.EventPayload".to_string(),
+        }];
+        let options = GenerateOptions {
+            generate_mock_api: true,
+            ..Default::default()
+        };
+        let files = generate_event_handler_files(&global_events, &window_events, &options).unwrap();
+        write_generated_files(output_dir.path(), &files).unwrap();
+
+        let mock_event_dir = output_dir.path().join("mock-api").join("events");
+        let global_content =
+            fs::read_to_string(mock_event_dir.join("TauriGlobalEventHandlers.ts")).unwrap();
+        assert!(global_content.contains("export abstract class TauriGlobalEventHandlers"));
+        assert!(global_content.contains("public EmitGlobal(payload: string)"));
+        assert!(!global_content.contains("listen<"));
+        assert!(!global_content.contains("import * as T"));
+        // ルートの index.ts を mock-api に切り替えても on / once 関数の import が壊れない
+        assert!(
+            global_content.contains("import { Event, UnlistenFn } from \"@tauri-apps/api/event\";")
+        );
+        assert!(global_content.contains(
+            "export function onGlobal(_callback: (event: Event<string>) => void): Promise<UnlistenFn> {\n    return Promise.resolve(() => {});\n}"
+        ));
+        assert!(global_content.contains(
+            "export function onceGlobal(_callback: (event: Event<string>) => void): Promise<UnlistenFn> {"
+        ));
+
+        let window_content =
+            fs::read_to_string(mock_event_dir.join("TauriMainWindowEventHandlers.ts")).unwrap();
+        assert!(window_content.contains("public EmitWindowEvent(payload: T.EventPayload)"));
+        assert!(window_content.contains("import * as T from \"../../interface/types/index\""));
+        assert!(!window_content.contains("UnlistenFn"));
+        assert!(!window_content.contains("export function"));
+
+        // モックのイベントハンドラは mock-api/index.ts から再エクスポートされる
+        let index_files = crate::generator::index_file_generator::render_index_files(
+            &["commands".to_string()],
+            &[],
+            &options,
+            &global_events,
+            &window_events,
+            false,
+        )
+        .unwrap();
+        let mock_index = index_files
+            .iter()
+            .find(|file| file.path == Path::new("mock-api/index.ts"))
+            .unwrap();
+        assert!(
+            mock_index
+                .content
+                .contains("export * from \"./events/TauriGlobalEventHandlers\";")
+        );
+        assert!(
+            mock_index
+                .content
+                .contains("export * from \"./events/TauriMainWindowEventHandlers\";")
+        );
+    }
+
+    #[test]
+    fn test_generate_window_event_handler_files_imports_payload_types() {
+        use crate::generator::type_extractor::WindowEventInfo;
+
+        let window_events = vec![
+            WindowEventInfo {
+                window_name: "main".to_string(),
+                event_name: "window-event".to_string(),
+                payload_type: "T.EventPayload".to_string(),
+            },
+            WindowEventInfo {
+                window_name: "settings".to_string(),
+                event_name: "saved".to_string(),
+                payload_type: "string".to_string(),
+            },
+        ];
+        for options in [
+            GenerateOptions::default(),
+            GenerateOptions {
+                declarations_only: true,
+                ..Default::default()
+            },
+        ] {
+            let files = generate_event_handler_files(&[], &window_events, &options).unwrap();
+            let content = |window: &str| {
+                &files
+                    .iter()
+                    .find(|file| {
+                        file.path
+                            .to_string_lossy()
+                            .contains(&format!("Tauri{window}WindowEventHandlers"))
+                    })
+                    .unwrap()
+                    .content
+            };
+            assert!(content("Main").contains("import * as T from \"../../interface/types\";"));
+            assert!(content("Main").contains("Event<T.EventPayload>"));
+            assert!(!content("Settings").contains("import * as T"));
+        }
+    }
+
+    #[test]
+    fn test_jsdoc_filter() {
+        let mut tera = Tera::default();
+        register_tera_filters(&mut tera);
+        let mut context = Context::new();
+        context.insert("doc", "Greets the user.\n\n@param name The name.");
+        context.insert("empty", "");
+        context.insert("error_type", &Some("T.MyError"));
+
+        let rendered = tera
+            .render_str("{{ doc | jsdoc(indent=2) }}", &context)
+            .unwrap();
+        assert_eq!(
+            rendered,
+            "  /**\n   * Greets the user.\n   *\n   * @param name The name.\n   */\n"
+        );
+
+        let rendered = tera
+            .render_str("{{ empty | jsdoc(indent=2) }}", &context)
+            .unwrap();
+        assert_eq!(rendered, "");
+
+        let rendered = tera
+            .render_str("{{ empty | jsdoc(throws=error_type) }}", &context)
+            .unwrap();
+        assert_eq!(
+            rendered,
+            "/**\n * @throws {T.MyError} when the command returns an error.\n */\n"
+        );
+
+        let rendered = tera
+            .render_str(
+                "{{ doc | jsdoc(is_async=true, throws=error_type) }}",
+                &context,
+            )
+            .unwrap();
+        assert_eq!(
+            rendered,
+            "/**\n * Greets the user.\n *\n * @param name The name.\n * @remarks Runs on the async runtime of Tauri instead of the main thread.\n * @throws {T.MyError} when the command returns an error.\n */\n"
+        );
+    }
+
     fn run_multi_file_test() {
         let test_case_name = "event_window_many";
         let src_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
@@ -511,14 +1996,25 @@ mod tests {
             if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some("rs") {
                 let file_name = path.file_stem().and_then(|s| s.to_str()).unwrap();
                 let rust_code = fs::read_to_string(&path).unwrap();
-                let (_, _, global_events, window_events) =
-                    generate_ts_files(&rust_code, &output_dir, file_name, false).unwrap();
+                let GenerateTsFilesResult {
+                    global_events,
+                    window_events,
+                    files,
+                    ..
+                } = generate_ts_files(&rust_code, file_name, &GenerateOptions::default()).unwrap();
+                write_generated_files(&output_dir, &files).unwrap();
                 all_global_events.extend(global_events);
                 all_window_events.extend(window_events);
             }
         }
 
-        generate_event_handler_files(&output_dir, &all_global_events, &all_window_events).unwrap();
+        let event_files = generate_event_handler_files(
+            &all_global_events,
+            &all_window_events,
+            &GenerateOptions::default(),
+        )
+        .unwrap();
+        write_generated_files(&output_dir, &event_files).unwrap();
 
         compare_generated_files(
             &output_dir,
@@ -1,27 +1,50 @@
-use log::debug;
+use crate::generator::options::{OptionRepr, TauriVersion, TypeMapping};
+use crate::generator::serde_attrs::{RenameRule, parse_serde_attributes};
+use log::{debug, warn};
 use serde_json;
 use std::collections::HashMap;
 use syn::{
-    Attribute, Expr, ExprMethodCall, Fields, FnArg, Item, ItemEnum, ItemStruct, Lit, Meta, Pat,
-    Type, UseTree,
+    Attribute, Block, Expr, ExprMethodCall, Fields, FnArg, ImplItem, Item, ItemConst, ItemEnum,
+    ItemStruct, Lit, Meta, Pat, Signature, Type, UnOp, UseTree, Visibility,
     visit::{self, Visit},
+    visit_mut::{self, VisitMut},
 };
 
+const CONST_PRIMITIVE_TYPES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize", "f32",
+    "f64", "bool", "char", "str",
+];
+
 const IGNORED_TAURI_TYPES: &[&str] = &[
     "tauri::WebviewWindow",
     "tauri::State",
     "tauri::AppHandle",
     "tauri::Window",
+    "tauri::Webview",
+    "tauri::ipc::Request",
+    "tauri::ipc::CommandScope",
+    "tauri::ipc::GlobalScope",
 ];
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 /// Represents information about a global event emitted in Tauri.
 pub struct EventInfo {
     pub event_name: String,
     pub payload_type: String,
 }
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+/// A command argument left out of the generated wrapper because its type is not Deserializable.
+pub struct DroppedArgument {
+    pub command_name: String,
+    pub arg_name: String,
+    /// The TypeScript type of the argument.
+    pub arg_type: String,
+    /// The user-defined type in the argument type that does not derive `Deserialize`.
+    pub type_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 /// Represents information about a window-specific event emitted in Tauri.
 pub struct WindowEventInfo {
     pub window_name: String,
@@ -29,91 +52,302 @@ pub struct WindowEventInfo {
     pub payload_type: String,
 }
 
+/// The Tauri types whose values can emit events (`tauri::Emitter` implementors).
+const TAURI_EMITTER_TYPES: &[&str] = &["AppHandle", "Window", "WebviewWindow", "Webview"];
+
 struct EventCallFinder<'a> {
     global_events: &'a mut Vec<EventInfo>,
     window_events: &'a mut Vec<WindowEventInfo>,
     defined_types: &'a [String],
-    fn_args: &'a HashMap<String, Type>,
+    /// The types of the function arguments and of the `let` bindings seen so far.
+    locals: HashMap<String, Type>,
+    /// The named field types of the structs in the file, to infer e.g. `self.app` or `state.window`.
+    struct_fields: &'a HashMap<String, HashMap<String, Type>>,
+    /// The `Self` type when visiting a method of an `impl` block.
+    self_ty: Option<&'a Type>,
+    aliases: &'a HashMap<String, String>,
+    mapping: &'a TypeMapping,
+}
+
+impl EventCallFinder<'_> {
+    /// Checks whether the receiver of an `emit` call is an app handle or window.
+    ///
+    /// Receivers literally named `app` or `window` are accepted even if their type is unknown.
+    fn is_emitter(&self, receiver: &Expr) -> bool {
+        if let Some(ty) = self.infer_expr_type(receiver) {
+            return is_tauri_emitter_type(&ty, self.aliases);
+        }
+        matches!(receiver, Expr::Path(expr_path)
+            if expr_path.path.is_ident("app") || expr_path.path.is_ident("window"))
+    }
+
+    /// Infers the type of simple expressions: variables, field accesses and the handle
+    /// accessors and conversions commonly used on Tauri handles (`clone`, `app_handle`, `unwrap`, ...).
+    fn infer_expr_type(&self, expr: &Expr) -> Option<Type> {
+        match expr {
+            Expr::Path(expr_path) => {
+                if expr_path.path.is_ident("self") {
+                    return self.self_ty.cloned();
+                }
+                let ident = expr_path.path.get_ident()?;
+                self.locals.get(&ident.to_string()).cloned()
+            }
+            Expr::Reference(expr_ref) => self.infer_expr_type(&expr_ref.expr),
+            Expr::Paren(expr_paren) => self.infer_expr_type(&expr_paren.expr),
+            Expr::Group(expr_group) => self.infer_expr_type(&expr_group.expr),
+            Expr::Try(expr_try) => unwrapped_type(&self.infer_expr_type(&expr_try.expr)?),
+            Expr::Field(expr_field) => {
+                let syn::Member::Named(field) = &expr_field.member else {
+                    return None;
+                };
+                let base_ty = self.infer_expr_type(&expr_field.base)?;
+                self.struct_fields
+                    .get(&innermost_type_name(&base_ty)?)?
+                    .get(&field.to_string())
+                    .cloned()
+            }
+            Expr::MethodCall(method_call) => match method_call.method.to_string().as_str() {
+                "clone" | "to_owned" | "as_ref" => self.infer_expr_type(&method_call.receiver),
+                "unwrap" | "expect" => {
+                    unwrapped_type(&self.infer_expr_type(&method_call.receiver)?)
+                }
+                "app_handle" => Some(syn::parse_quote!(tauri::AppHandle)),
+                "get_window" => Some(syn::parse_quote!(Option<tauri::Window>)),
+                "get_webview_window" => Some(syn::parse_quote!(Option<tauri::WebviewWindow>)),
+                "get_webview" => Some(syn::parse_quote!(Option<tauri::Webview>)),
+                _ => None,
+            },
+            Expr::Struct(expr_struct) => Some(Type::Path(syn::TypePath {
+                qself: None,
+                path: expr_struct.path.clone(),
+            })),
+            Expr::Call(expr_call) => constructor_type(expr_call),
+            // `let count = 1;` のような束縛をペイロードとして渡す場合のため、リテラルの型も推論する
+            Expr::Lit(expr_lit) => match &expr_lit.lit {
+                Lit::Str(_) => Some(syn::parse_quote!(&str)),
+                Lit::Int(lit_int) if !lit_int.suffix().is_empty() => {
+                    syn::parse_str(lit_int.suffix()).ok()
+                }
+                Lit::Int(_) => Some(syn::parse_quote!(i32)),
+                Lit::Float(lit_float) if !lit_float.suffix().is_empty() => {
+                    syn::parse_str(lit_float.suffix()).ok()
+                }
+                Lit::Float(_) => Some(syn::parse_quote!(f64)),
+                Lit::Bool(_) => Some(syn::parse_quote!(bool)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Returns `T` for `Option<T>` and `Result<T, E>`.
+fn unwrapped_type(ty: &Type) -> Option<Type> {
+    if let Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+        && (segment.ident == "Option" || segment.ident == "Result")
+        && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(syn::GenericArgument::Type(inner)) = args.args.first()
+    {
+        return Some(inner.clone());
+    }
+    None
+}
+
+/// Returns the name of a type, looking through references and `State`/`Arc`/`Rc`/`Box` wrappers.
+fn innermost_type_name(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Reference(type_ref) => innermost_type_name(&type_ref.elem),
+        Type::Path(type_path) => {
+            let segment = type_path.path.segments.last()?;
+            if matches!(
+                segment.ident.to_string().as_str(),
+                "State" | "Arc" | "Rc" | "Box"
+            ) && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+                && let Some(inner) = args.args.iter().find_map(|arg| match arg {
+                    syn::GenericArgument::Type(inner) => Some(inner),
+                    _ => None,
+                })
+            {
+                return innermost_type_name(inner);
+            }
+            Some(segment.ident.to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Checks whether the type is a Tauri handle that can emit events (e.g. `tauri::AppHandle`).
+fn is_tauri_emitter_type(ty: &Type, aliases: &HashMap<String, String>) -> bool {
+    match ty {
+        Type::Reference(type_ref) => is_tauri_emitter_type(&type_ref.elem, aliases),
+        Type::Path(type_path) => {
+            let segments: Vec<String> = type_path
+                .path
+                .segments
+                .iter()
+                .map(|s| s.ident.to_string())
+                .collect();
+            let path_str = segments.join("::");
+            // エイリアスの場合は元のパスに解決する (例: `use tauri::AppHandle as Handle;`)
+            let final_path = match aliases.get(&path_str) {
+                Some(resolved) if segments.len() == 1 => resolved.as_str(),
+                _ => path_str.as_str(),
+            };
+            let (module, name) = final_path
+                .rsplit_once("::")
+                .unwrap_or(("tauri", final_path));
+            module == "tauri" && TAURI_EMITTER_TYPES.contains(&name)
+        }
+        _ => false,
+    }
 }
 
 impl<'ast, 'a> Visit<'ast> for EventCallFinder<'a> {
+    fn visit_local(&mut self, node: &'ast syn::Local) {
+        visit::visit_local(self, node);
+
+        let (pat, annotated_ty) = match &node.pat {
+            Pat::Type(pat_type) => (&*pat_type.pat, Some((*pat_type.ty).clone())),
+            pat => (pat, None),
+        };
+        if let Pat::Ident(pat_ident) = pat {
+            let name = pat_ident.ident.to_string();
+            let ty = annotated_ty.or_else(|| {
+                node.init
+                    .as_ref()
+                    .and_then(|init| self.infer_expr_type(&init.expr))
+            });
+            match ty {
+                Some(ty) => self.locals.insert(name, ty),
+                // 型が分からない束縛は同名の変数を隠すため削除する
+                None => self.locals.remove(&name),
+            };
+        }
+    }
+
     fn visit_expr_method_call(&mut self, node: &'ast ExprMethodCall) {
         let method_name = node.method.to_string();
 
-        if method_name == "emit" {
-            if let Expr::Path(expr_path) = &*node.receiver {
-                if let Some(ident) = expr_path.path.get_ident() {
-                    if ident == "app" || ident == "window" {
-                        if let Some(Expr::Lit(event_lit)) = node.args.get(0) {
-                            if let Lit::Str(event_str) = &event_lit.lit {
-                                let event_name = event_str.value();
-                                let payload_type = if let Some(arg) = node.args.get(1) {
-                                    payload_type_from_expr(arg, self.defined_types, self.fn_args)
-                                } else {
-                                    "void".to_string()
-                                };
-                                self.global_events.push(EventInfo {
-                                    event_name,
-                                    payload_type,
-                                });
-                            }
-                        }
-                    }
-                }
-            }
-        } else if method_name == "emit_to" {
-            if let (Some(Expr::Lit(win_lit)), Some(Expr::Lit(event_lit))) =
-                (node.args.get(0), node.args.get(1))
+        // emit_all (Tauri v1) と emit_filter (送信先はフィルタで決まる) もグローバルイベントとして扱う
+        if matches!(method_name.as_str(), "emit" | "emit_all" | "emit_filter") {
+            if self.is_emitter(&node.receiver)
+                && let Some(Expr::Lit(event_lit)) = node.args.get(0)
+                && let Lit::Str(event_str) = &event_lit.lit
             {
-                if let (Lit::Str(win_str), Lit::Str(event_str)) = (&win_lit.lit, &event_lit.lit) {
-                    let window_name = win_str.value();
-                    let event_name = event_str.value();
-                    let payload_type = if let Some(payload_expr) = node.args.get(2) {
-                        payload_type_from_expr(payload_expr, self.defined_types, self.fn_args)
-                    } else {
-                        "void".to_string()
-                    };
-                    self.window_events.push(WindowEventInfo {
-                        window_name,
-                        event_name,
-                        payload_type,
-                    });
-                }
+                let event_name = event_str.value();
+                let payload_type = if let Some(arg) = node.args.get(1) {
+                    payload_type_from_expr(arg, self.defined_types, &self.locals, self.mapping)
+                } else {
+                    "void".to_string()
+                };
+                self.global_events.push(EventInfo {
+                    event_name,
+                    payload_type,
+                });
             }
+        } else if method_name == "emit_to"
+            && let (Some(Expr::Lit(win_lit)), Some(Expr::Lit(event_lit))) =
+                (node.args.get(0), node.args.get(1))
+            && let (Lit::Str(win_str), Lit::Str(event_str)) = (&win_lit.lit, &event_lit.lit)
+        {
+            let window_name = win_str.value();
+            let event_name = event_str.value();
+            let payload_type = if let Some(payload_expr) = node.args.get(2) {
+                payload_type_from_expr(payload_expr, self.defined_types, &self.locals, self.mapping)
+            } else {
+                "void".to_string()
+            };
+            self.window_events.push(WindowEventInfo {
+                window_name,
+                event_name,
+                payload_type,
+            });
         }
 
         visit::visit_expr_method_call(self, node);
     }
 }
 
+/// Converts the type of an event payload expression to TypeScript.
+///
+/// Variables are looked up in `locals`, the types of the function arguments and of the `let`
+/// bindings seen before the `emit` call. Payloads of unknown type become `any`.
 fn payload_type_from_expr(
     expr: &Expr,
     defined_types: &[String],
-    fn_args: &HashMap<String, Type>,
+    locals: &HashMap<String, Type>,
+    mapping: &TypeMapping,
 ) -> String {
     match expr {
         Expr::Path(expr_path) => {
-            if let Some(ident) = expr_path.path.get_ident() {
-                if let Some(ty) = fn_args.get(&ident.to_string()) {
-                    return type_to_ts(ty, defined_types, true);
-                }
+            if let Some(ident) = expr_path.path.get_ident()
+                && let Some(ty) = locals.get(&ident.to_string())
+            {
+                return type_to_ts(ty, defined_types, true, mapping);
             }
             "any".to_string()
         }
-        Expr::Struct(expr_struct) => {
-            let type_name = expr_struct.path.segments.last().unwrap().ident.to_string();
-            type_to_ts(&syn::parse_str(&type_name).unwrap(), defined_types, true)
-        }
+        Expr::Struct(expr_struct) => match expr_struct.path.segments.last() {
+            Some(segment) => {
+                // `Wrapper::<u32> { .. }` のターボフィッシュは型のジェネリクス引数として扱う
+                let mut segment = segment.clone();
+                if let syn::PathArguments::AngleBracketed(args) = &mut segment.arguments {
+                    args.colon2_token = None;
+                }
+                let ty = Type::Path(syn::TypePath {
+                    qself: None,
+                    path: segment.into(),
+                });
+                type_to_ts(&ty, defined_types, true, mapping)
+            }
+            None => "any".to_string(),
+        },
         Expr::Lit(expr_lit) => match &expr_lit.lit {
             Lit::Str(_) => "string".to_string(),
             Lit::Int(_) | Lit::Float(_) => "number".to_string(),
             Lit::Bool(_) => "boolean".to_string(),
             _ => "any".to_string(),
         },
+        // `&payload` や `(payload)` は中の式の型とする
+        Expr::Reference(expr_ref) => {
+            payload_type_from_expr(&expr_ref.expr, defined_types, locals, mapping)
+        }
+        Expr::Paren(expr_paren) => {
+            payload_type_from_expr(&expr_paren.expr, defined_types, locals, mapping)
+        }
+        Expr::MethodCall(method_call)
+            if matches!(
+                method_call.method.to_string().as_str(),
+                "clone" | "to_owned"
+            ) =>
+        {
+            payload_type_from_expr(&method_call.receiver, defined_types, locals, mapping)
+        }
+        Expr::Call(expr_call) => match constructor_type(expr_call) {
+            Some(ty) => type_to_ts(&ty, defined_types, true, mapping),
+            None => "any".to_string(),
+        },
         _ => "any".to_string(),
     }
 }
 
+/// Returns `Type` for a call of the constructor `Type::new(...)`.
+fn constructor_type(expr_call: &syn::ExprCall) -> Option<Type> {
+    let Expr::Path(expr_path) = &*expr_call.func else {
+        return None;
+    };
+    let segments = &expr_path.path.segments;
+    if segments.len() < 2 || segments.last()?.ident != "new" {
+        return None;
+    }
+    let type_segment = &segments[segments.len() - 2];
+    Some(Type::Path(syn::TypePath {
+        qself: None,
+        path: type_segment.ident.clone().into(),
+    }))
+}
+
 /// Extracts global and window-specific events from the given Rust items.
 ///
 /// This function traverses the AST to find `emit` and `emit_to` calls,
@@ -130,6 +364,7 @@ fn payload_type_from_expr(
 pub fn extract_events(
     items: &[Item],
     all_extracted_types: &[ExtractedTypeInfo],
+    mapping: &TypeMapping,
 ) -> (Vec<EventInfo>, Vec<WindowEventInfo>) {
     let mut global_events = Vec::new();
     let mut window_events = Vec::new();
@@ -138,31 +373,228 @@ pub fn extract_events(
         .map(|info| info.name.clone())
         .collect();
 
+    let aliases = extract_use_aliases(items);
+    let struct_fields = collect_struct_fields(items);
+
+    for func in collect_fn_items(items) {
+        let mut fn_args = HashMap::new();
+        for input in &func.sig.inputs {
+            if let FnArg::Typed(pat_type) = input
+                && let Pat::Ident(pat_ident) = &*pat_type.pat
+            {
+                fn_args.insert(pat_ident.ident.to_string(), (*pat_type.ty).clone());
+            }
+        }
+
+        let mut finder = EventCallFinder {
+            global_events: &mut global_events,
+            window_events: &mut window_events,
+            defined_types: &defined_types_names,
+            locals: fn_args,
+            struct_fields: &struct_fields,
+            self_ty: func.self_ty,
+            aliases: &aliases,
+            mapping,
+        };
+        finder.visit_block(func.block);
+    }
+
+    (global_events, window_events)
+}
+
+/// A function that may be a Tauri command or emit events: either a free function or a method
+/// inside an `impl` block.
+struct FnItem<'a> {
+    attrs: &'a [Attribute],
+    sig: &'a Signature,
+    block: &'a Block,
+    /// The type of the `impl` block for methods.
+    self_ty: Option<&'a Type>,
+}
+
+/// Replaces references to the (non-generic) `type` aliases of the file with their target types.
+///
+/// Aliases are resolved transitively, so with `type A = B; type B = String;` a command taking
+/// `A` is typed as `string`. Aliases of user-defined types resolve to those types.
+pub fn resolve_type_aliases(items: &mut [Item]) {
+    let mut aliases: HashMap<String, Type> = flatten_module_items(items)
+        .into_iter()
+        .filter_map(|item| match item {
+            Item::Type(alias) if alias.generics.params.is_empty() => {
+                Some((alias.ident.to_string(), (*alias.ty).clone()))
+            }
+            _ => None,
+        })
+        .collect();
+    if aliases.is_empty() {
+        return;
+    }
+
+    // 別名の参照先に含まれる別名を先に解決する (循環に備えて回数を制限する)
+    for _ in 0..aliases.len() {
+        let snapshot = aliases.clone();
+        let mut resolver = AliasResolver {
+            aliases: &snapshot,
+            changed: false,
+        };
+        for ty in aliases.values_mut() {
+            resolver.visit_type_mut(ty);
+        }
+        if !resolver.changed {
+            break;
+        }
+    }
+
+    let mut resolver = AliasResolver {
+        aliases: &aliases,
+        changed: false,
+    };
+    for item in items.iter_mut() {
+        resolver.visit_item_mut(item);
+    }
+}
+
+/// Replaces single-identifier type paths that name a type alias with the aliased type.
+struct AliasResolver<'a> {
+    aliases: &'a HashMap<String, Type>,
+    changed: bool,
+}
+
+impl VisitMut for AliasResolver<'_> {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        if let Type::Path(type_path) = ty
+            && type_path.qself.is_none()
+            && let Some(ident) = type_path.path.get_ident()
+            && let Some(target) = self.aliases.get(&ident.to_string())
+        {
+            *ty = target.clone();
+            self.changed = true;
+            return;
+        }
+        visit_mut::visit_type_mut(self, ty);
+    }
+}
+
+/// Collects the commands registered with `tauri::generate_handler![...]` in the file.
+///
+/// Invocations anywhere in the file are found, typically in the `invoke_handler` call of the
+/// builder. Commands given by path (e.g. `commands::greet`) are reported by their last segment.
+pub fn extract_generate_handler_commands(items: &[Item]) -> Vec<String> {
+    let mut collector = HandlerCollector {
+        command_names: Vec::new(),
+    };
     for item in items {
-        if let Item::Fn(func) = item {
-            let mut fn_args = HashMap::new();
-            for input in &func.sig.inputs {
-                if let FnArg::Typed(pat_type) = input {
-                    if let Pat::Ident(pat_ident) = &*pat_type.pat {
-                        fn_args.insert(pat_ident.ident.to_string(), (*pat_type.ty).clone());
-                    }
-                }
+        collector.visit_item(item);
+    }
+    collector.command_names
+}
+
+/// Collects the command names listed in `generate_handler!` macro invocations.
+struct HandlerCollector {
+    command_names: Vec<String>,
+}
+
+impl<'ast> Visit<'ast> for HandlerCollector {
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        if mac
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "generate_handler")
+        {
+            match mac.parse_body_with(
+                syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+            ) {
+                Ok(paths) => self.command_names.extend(
+                    paths
+                        .iter()
+                        .filter_map(|path| path.segments.last())
+                        .map(|segment| segment.ident.to_string()),
+                ),
+                Err(e) => warn!("Could not parse generate_handler! invocation: {e}"),
             }
+        }
+        visit::visit_macro(self, mac);
+    }
+}
 
-            let mut finder = EventCallFinder {
-                global_events: &mut global_events,
-                window_events: &mut window_events,
-                defined_types: &defined_types_names,
-                fn_args: &fn_args,
-            };
-            finder.visit_block(&func.block);
+/// Maps the name of each struct with named fields to its field types.
+fn collect_struct_fields(items: &[Item]) -> HashMap<String, HashMap<String, Type>> {
+    let mut struct_fields = HashMap::new();
+    for item in flatten_module_items(items) {
+        if let Item::Struct(item_struct) = item
+            && let Fields::Named(fields) = &item_struct.fields
+        {
+            let fields = fields
+                .named
+                .iter()
+                .filter_map(|field| Some((field.ident.as_ref()?.to_string(), field.ty.clone())))
+                .collect();
+            struct_fields.insert(item_struct.ident.to_string(), fields);
         }
     }
+    struct_fields
+}
 
-    (global_events, window_events)
+/// Returns the given items together with the items of all (arbitrarily nested) inline modules.
+///
+/// Modules declared without a body (`mod foo;`) live in separate files and are not descended into,
+/// and neither are `#[cfg(test)]` modules.
+fn flatten_module_items(items: &[Item]) -> Vec<&Item> {
+    let mut flattened = Vec::new();
+    for item in items {
+        flattened.push(item);
+        if let Item::Mod(item_mod) = item
+            && let Some((_, content)) = &item_mod.content
+            && !is_cfg_test(&item_mod.attrs)
+        {
+            flattened.extend(flatten_module_items(content));
+        }
+    }
+    flattened
+}
+
+/// Checks whether the attributes contain `#[cfg(test)]`.
+fn is_cfg_test(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("cfg")
+            && attr
+                .parse_args::<syn::Path>()
+                .is_ok_and(|path| path.is_ident("test"))
+    })
+}
+
+/// Collects the free functions and the methods of `impl` blocks in the given items,
+/// including those inside inline modules.
+fn collect_fn_items(items: &[Item]) -> Vec<FnItem<'_>> {
+    let mut fn_items = Vec::new();
+    for item in flatten_module_items(items) {
+        match item {
+            Item::Fn(func) => fn_items.push(FnItem {
+                attrs: &func.attrs,
+                sig: &func.sig,
+                block: &func.block,
+                self_ty: None,
+            }),
+            Item::Impl(item_impl) => {
+                for impl_item in &item_impl.items {
+                    if let ImplItem::Fn(method) = impl_item {
+                        fn_items.push(FnItem {
+                            attrs: &method.attrs,
+                            sig: &method.sig,
+                            block: &method.block,
+                            self_ty: Some(&item_impl.self_ty),
+                        });
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    fn_items
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 /// Represents information about an extracted Rust type (struct or enum) for TypeScript generation.
 pub struct ExtractedTypeInfo {
     pub name: String,
@@ -170,11 +602,13 @@ pub struct ExtractedTypeInfo {
     pub is_serializable: bool,
     pub is_deserializable: bool,
     pub original_file_name: String,
+    /// Identifiers of all types referenced by the fields or variants of this type.
+    pub referenced_types: Vec<String>,
 }
 
 /// Extracts and converts Rust structs and enums to TypeScript interfaces.
 ///
-/// This function iterates through the given Rust items and converts any structs or enums
+/// This function iterates through the given Rust items (including those in inline modules) and converts any structs or enums
 /// that derive `Serialize` and `Deserialize` into a `serde_json::Value` representation
 /// for TypeScript generation.
 ///
@@ -188,9 +622,19 @@ pub struct ExtractedTypeInfo {
 pub fn extract_and_convert_types(
     items: &[Item],
     original_file_name: &str,
+    mapping: &TypeMapping,
 ) -> Vec<ExtractedTypeInfo> {
     let mut extracted_types = Vec::new();
-    let mut defined_types_names = Vec::new(); // Keep track of defined type names for type_to_ts
+    let items = flatten_module_items(items);
+    // 定義順に関係なく参照できるよう、ファイル内のすべての型名を先に集める
+    let defined_types_names: Vec<String> = items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Struct(s) => Some(s.ident.to_string()),
+            Item::Enum(e) => Some(e.ident.to_string()),
+            _ => None,
+        })
+        .collect();
 
     for item in items {
         match item {
@@ -200,15 +644,19 @@ pub fn extract_and_convert_types(
                 let struct_name = s.ident.to_string();
 
                 // Always convert to TS interface if it's a user-defined type, regardless of Serde derives
-                let ts_interface = convert_struct_to_ts_interface(s, &defined_types_names);
+                let ts_interface = with_source_line(
+                    convert_struct_to_ts_interface(s, &defined_types_names, mapping),
+                    &s.ident,
+                );
+                let referenced_types = collect_type_idents(s.fields.iter().map(|field| &field.ty));
                 extracted_types.push(ExtractedTypeInfo {
                     name: struct_name.clone(),
                     ts_interface,
                     is_serializable,
                     is_deserializable,
                     original_file_name: original_file_name.to_string(),
+                    referenced_types,
                 });
-                defined_types_names.push(struct_name);
             }
             Item::Enum(e) => {
                 let is_serializable = has_derive_macro(&e.attrs, "Serialize");
@@ -216,15 +664,35 @@ pub fn extract_and_convert_types(
                 let enum_name = e.ident.to_string();
 
                 // Always convert to TS enum if it's a user-defined type, regardless of Serde derives
-                let ts_interface = convert_enum_to_ts_enum(e, &defined_types_names);
+                let ts_interface = with_source_line(
+                    convert_enum_to_ts_enum(e, &defined_types_names, mapping),
+                    &e.ident,
+                );
+                let referenced_types = collect_type_idents(
+                    e.variants
+                        .iter()
+                        .flat_map(|variant| variant.fields.iter().map(|field| &field.ty)),
+                );
                 extracted_types.push(ExtractedTypeInfo {
                     name: enum_name.clone(),
                     ts_interface,
                     is_serializable,
                     is_deserializable,
                     original_file_name: original_file_name.to_string(),
+                    referenced_types,
                 });
-                defined_types_names.push(enum_name);
+            }
+            Item::Const(c) => {
+                if let Some(ts_interface) = convert_const_to_ts_const(c) {
+                    extracted_types.push(ExtractedTypeInfo {
+                        name: c.ident.to_string(),
+                        ts_interface: with_source_line(ts_interface, &c.ident),
+                        is_serializable: false,
+                        is_deserializable: false,
+                        original_file_name: original_file_name.to_string(),
+                        referenced_types: Vec::new(),
+                    });
+                }
             }
             _ => {}
         }
@@ -232,64 +700,365 @@ pub fn extract_and_convert_types(
     extracted_types
 }
 
+/// Records the line of `ident` in the Rust source as `line` of a converted type (see `--source-lines`).
+fn with_source_line(mut ts_interface: serde_json::Value, ident: &syn::Ident) -> serde_json::Value {
+    if let Some(object) = ts_interface.as_object_mut() {
+        object.insert("line".to_string(), ident.span().start().line.into());
+    }
+    ts_interface
+}
+
+/// Collects the identifiers of every path segment appearing in the given types.
+///
+/// For example `Vec<Append>` yields `["Append", "Vec"]`. The result is sorted and deduplicated.
+pub(crate) fn collect_type_idents<'a>(types: impl IntoIterator<Item = &'a Type>) -> Vec<String> {
+    struct TypeIdentCollector(Vec<String>);
+
+    impl<'ast> Visit<'ast> for TypeIdentCollector {
+        fn visit_path_segment(&mut self, node: &'ast syn::PathSegment) {
+            self.0.push(node.ident.to_string());
+            visit::visit_path_segment(self, node);
+        }
+    }
+
+    let mut collector = TypeIdentCollector(Vec::new());
+    for ty in types {
+        collector.visit_type(ty);
+    }
+    collector.0.sort();
+    collector.0.dedup();
+    collector.0
+}
+
 /// Checks if a given attribute list contains a specific derive macro.
 pub(crate) fn has_derive_macro(attrs: &[Attribute], macro_name: &str) -> bool {
-    println!("Checking for derive macro: {macro_name}");
     attrs.iter().any(|attr| {
-        if attr.path().is_ident("derive") {
-            println!("Found derive attribute: {attr:?}");
-            if let Ok(list) = attr.parse_args_with(
+        if attr.path().is_ident("derive")
+            && let Ok(list) = attr.parse_args_with(
                 syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
-            ) {
-                let found = list
-                    .iter()
-                    .any(|path| path.segments.last().is_some_and(|s| s.ident == macro_name));
-                println!("Macro {macro_name} found in derive list: {found}");
-                return found;
-            }
+            )
+        {
+            let found = list
+                .iter()
+                .any(|path| path.segments.last().is_some_and(|s| s.ident == macro_name));
+            debug!("Macro {macro_name} found in derive list: {found}");
+            return found;
         }
         false
     })
 }
 
 /// Converts a Rust `ItemStruct` into a `serde_json::Value` representation for TypeScript interface generation.
+///
+/// `Option<T>` fields are marked `optional` and typed as `T`, so they render as `name?: T`,
+/// unless `mapping.option_repr` asks for `name: T | null` or `name: T | undefined` instead.
+/// Fields skipped by serde in every direction the struct is (de)serialized in are omitted,
+/// and fields skipped in only one of them, or by `skip_serializing_if`, are marked `optional`.
+/// Fields with `#[serde(default)]` (on the field or the struct) are `optional` too if the struct
+/// is deserialized, but stay required in output-only (`Serialize`-only) types.
+///
+/// Tuple structs become a type `alias`: newtypes (`struct Meters(f64);`) serialize as their
+/// inner value and are aliased to its type, other tuple structs to a TypeScript tuple.
+/// Unit structs (`struct Marker;`) serialize as `null` and are aliased to it.
+/// Field names follow `#[serde(rename = "...")]` and the container's `#[serde(rename_all = "...")]`,
+/// and are quoted if they are not valid TypeScript identifiers (e.g. `"user-name"`).
+/// Fields marked `#[serde(flatten)]` are collected in `flattened`, to be turned into `extends`
+/// clauses or intersections by `resolve_flattened_fields` once the types of all files are known.
 pub(crate) fn convert_struct_to_ts_interface(
     s: &ItemStruct,
     defined_types: &[String],
+    mapping: &TypeMapping,
 ) -> serde_json::Value {
     let struct_name = s.ident.to_string();
     let doc_comment = extract_doc_comments(&s.attrs);
+    let type_params = type_param_names(&s.generics);
+    let defined_types = &with_type_params(defined_types, &type_params);
+
+    if let Fields::Unnamed(fields) = &s.fields {
+        let field_types: Vec<String> = fields
+            .unnamed
+            .iter()
+            .filter(|field| !parse_serde_attributes(&field.attrs).skip_serializing)
+            .map(|field| type_to_ts(&field.ty, defined_types, false, mapping))
+            .collect();
+        // 1 要素の newtype は内部の値そのものとしてシリアライズされる
+        let ts_type = match field_types.as_slice() {
+            [inner] => inner.clone(),
+            _ => format!("[{}]", field_types.join(", ")),
+        };
+        return serde_json::json!({
+            "type": "alias",
+            "name": struct_name,
+            "type_params": type_params,
+            "doc_comment": doc_comment,
+            "ts_type": ts_type,
+        });
+    }
+    if let Fields::Unit = &s.fields {
+        // unit 構造体は null としてシリアライズされる
+        return serde_json::json!({
+            "type": "alias",
+            "name": struct_name,
+            "type_params": type_params,
+            "doc_comment": doc_comment,
+            "ts_type": "null",
+        });
+    }
+
+    let container_serde_attrs = parse_serde_attributes(&s.attrs);
+    let rename_all = container_serde_attrs.rename_all;
     let mut fields_ts = Vec::new();
+    let mut flattened_list = Vec::new();
+    // derive がない場合は、両方向で使われるものとして扱う
+    let is_serializable = has_derive_macro(&s.attrs, "Serialize");
+    let is_deserializable = has_derive_macro(&s.attrs, "Deserialize");
+    let (is_serializable, is_deserializable) = if is_serializable || is_deserializable {
+        (is_serializable, is_deserializable)
+    } else {
+        (true, true)
+    };
 
     if let Fields::Named(fields) = &s.fields {
         for field in &fields.named {
-            let field_name = field.ident.as_ref().unwrap().to_string();
-            let field_type = type_to_ts(&field.ty, defined_types, false);
+            let field_serde_attrs = parse_serde_attributes(&field.attrs);
+            let serialized = is_serializable && !field_serde_attrs.skip_serializing;
+            let deserialized = is_deserializable && !field_serde_attrs.skip_deserializing;
+            // JSON に現れないフィールドはインターフェースに含めない
+            if !serialized && !deserialized {
+                continue;
+            }
+            // 片方向でのみ省略されるフィールドは、もう一方では存在するため省略可能とする
+            let skipped_one_way =
+                (is_serializable && !serialized) || (is_deserializable && !deserialized);
+            // #[serde(default)] のフィールドはデシリアライズ時に省略できる (出力専用の型では常に存在する)
+            let defaulted =
+                deserialized && (field_serde_attrs.default || container_serde_attrs.default);
+            let ident = field.ident.as_ref().unwrap().to_string();
+            // フィールド単位の rename はコンテナの rename_all より優先される
+            let field_name = match (field_serde_attrs.rename, rename_all) {
+                (Some(rename), _) => rename,
+                (None, Some(rule)) => rule.apply_to_field(&ident),
+                (None, None) => ident,
+            };
+            // Option<T> のフィールドは既定で省略可能なプロパティ (`name?: T`) として出力する
+            let optional_inner_type = option_inner_type(&field.ty)
+                .filter(|_| mapping.option_repr == OptionRepr::Optional);
+            let field_type = type_to_ts(
+                optional_inner_type.unwrap_or(&field.ty),
+                defined_types,
+                false,
+                mapping,
+            );
+
             let field_doc_comment = extract_doc_comments(&field.attrs);
-            fields_ts.push(serde_json::json!({
-                "name": field_name,
+            let field_ts = serde_json::json!({
+                // rename 後の名前 (例: "user-name") は識別子でない場合に引用符で囲む
+                "name": ts_property_key(&field_name),
                 "type": field_type,
+                "optional": optional_inner_type.is_some()
+                    || skipped_one_way
+                    || defaulted
+                    || field_serde_attrs.skip_serializing_if,
                 "doc_comment": field_doc_comment,
-            }));
+            });
+
+            if field_serde_attrs.flatten {
+                // 型の種類は全ファイルの型が揃ってから resolve_flattened_fields で解決する
+                let flattened_inner_type = option_inner_type(&field.ty);
+                let flattened_type = type_to_ts(
+                    flattened_inner_type.unwrap_or(&field.ty),
+                    defined_types,
+                    false,
+                    mapping,
+                );
+                flattened_list.push(serde_json::json!({
+                    "type": flattened_type,
+                    "optional": flattened_inner_type.is_some(),
+                    "field": field_ts,
+                }));
+                continue;
+            }
+
+            fields_ts.push(field_ts);
         }
     }
 
     serde_json::json!({
         "type": "interface",
         "name": struct_name,
+        "type_params": type_params,
         "doc_comment": doc_comment,
+        "extends": [],
+        "flattened": flattened_list,
         "fields": fields_ts,
     })
 }
 
+/// How a `#[serde(flatten)]` field is rendered once the flattened type is known.
+enum FlattenedAs {
+    /// An `extends` clause of the interface.
+    Extends(String),
+    /// A member of an intersection, e.g. `Kind` or `Partial<Meta>`.
+    Intersection(String),
+    /// A regular field, because the flattened type is not a known user-defined type.
+    Field,
+}
+
+/// Resolves the `flattened` fields of the interfaces against the types of all input files.
+///
+/// Flattened structs with named fields become `extends` clauses. Enums, aliases and `Option<_>`
+/// fields (as `Partial<_>`) cannot be extended and are intersected instead, which renders the
+/// interface as a type alias. Fields of unknown types are kept as regular fields.
+///
+/// # Returns
+///
+/// One message per flattened field kept as a regular field (an error with `--strict`).
+pub fn resolve_flattened_fields(types: &mut [ExtractedTypeInfo]) -> Vec<String> {
+    let exported: HashMap<String, serde_json::Value> = types
+        .iter()
+        .filter(|info| info.is_serializable || info.is_deserializable)
+        .map(|info| (info.name.clone(), info.ts_interface.clone()))
+        .collect();
+    let mut unresolved = Vec::new();
+    for info in types.iter_mut() {
+        let Some(flattened) = info.ts_interface["flattened"].as_array().cloned() else {
+            continue;
+        };
+        let mut extends = Vec::new();
+        let mut intersections = Vec::new();
+        for entry in flattened {
+            match flattened_as(&entry, &exported, 0) {
+                FlattenedAs::Extends(base) => extends.push(base),
+                FlattenedAs::Intersection(member) => intersections.push(member),
+                FlattenedAs::Field => {
+                    unresolved.push(format!(
+                        "Field {}.{} is flattened but its type {} is not a known user-defined type; emitting it as a regular field.",
+                        info.name,
+                        entry["field"]["name"].as_str().unwrap_or_default(),
+                        entry["type"].as_str().unwrap_or_default()
+                    ));
+                    if let Some(fields) = info.ts_interface["fields"].as_array_mut() {
+                        fields.push(entry["field"].clone());
+                    }
+                }
+            }
+        }
+        // 交差型が必要な場合は interface の extends を使えないため、すべてを交差型にまとめる
+        if !intersections.is_empty() {
+            intersections.splice(0..0, extends.drain(..));
+        }
+        info.ts_interface["extends"] = extends.into();
+        info.ts_interface["intersections"] = intersections.into();
+        info.ts_interface["flattened"] = serde_json::json!([]);
+    }
+    unresolved
+}
+
+/// Decides how a `flattened` entry of an interface is rendered (see `resolve_flattened_fields`).
+fn flattened_as(
+    entry: &serde_json::Value,
+    exported: &HashMap<String, serde_json::Value>,
+    depth: usize,
+) -> FlattenedAs {
+    let ts_type = entry["type"].as_str().unwrap_or_default();
+    // 他のファイルで定義された型 (`T.Meta`) も同じ types/index.ts に出力される
+    let ts_type = ts_type.strip_prefix("T.").unwrap_or(ts_type);
+    let name = ts_type.split('<').next().unwrap_or_default();
+    let Some(ts_interface) = exported.get(name) else {
+        return FlattenedAs::Field;
+    };
+    if entry["optional"].as_bool() == Some(true) {
+        return FlattenedAs::Intersection(format!("Partial<{ts_type}>"));
+    }
+    match ts_interface["type"].as_str() {
+        Some("interface") if is_extendable(ts_interface, exported, depth) => {
+            FlattenedAs::Extends(ts_type.to_string())
+        }
+        Some("interface" | "enum" | "alias") => FlattenedAs::Intersection(ts_type.to_string()),
+        _ => FlattenedAs::Field,
+    }
+}
+
+/// Returns `true` if the interface is rendered as an `interface` that others can extend,
+/// i.e. none of its own flattened fields becomes an intersection.
+fn is_extendable(
+    ts_interface: &serde_json::Value,
+    exported: &HashMap<String, serde_json::Value>,
+    depth: usize,
+) -> bool {
+    // 循環した flatten で無限に再帰しないよう深さを制限する
+    depth < 16
+        && ts_interface["flattened"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .all(|entry| {
+                !matches!(
+                    flattened_as(entry, exported, depth + 1),
+                    FlattenedAs::Intersection(_)
+                )
+            })
+}
+
+/// Returns the names of the type parameters of a struct or enum (lifetimes and const generics are skipped).
+fn type_param_names(generics: &syn::Generics) -> Vec<String> {
+    generics
+        .type_params()
+        .map(|param| param.ident.to_string())
+        .collect()
+}
+
+/// Adds the type parameters to the defined type names, so `type_to_ts` emits them as-is instead of `T.<name>`.
+fn with_type_params(defined_types: &[String], type_params: &[String]) -> Vec<String> {
+    defined_types.iter().chain(type_params).cloned().collect()
+}
+
+/// Returns the inner type `T` if the given type is `Option<T>`, or `Option<Option<T>>` and so on.
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    let mut inner = None;
+    let mut ty = ty;
+    // Option<Option<T>> のような入れ子は最も内側の T まで剥がす
+    while let Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+        && segment.ident == "Option"
+        && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(syn::GenericArgument::Type(inner_type)) = args.args.first()
+    {
+        inner = Some(inner_type);
+        ty = inner_type;
+    }
+    inner
+}
+
 /// Converts a Rust `ItemEnum` into a `serde_json::Value` representation for TypeScript enum generation.
-pub(crate) fn convert_enum_to_ts_enum(e: &ItemEnum, defined_types: &[String]) -> serde_json::Value {
+///
+/// Enums with data-carrying variants, as well as internally (`#[serde(tag = "...")]`) and adjacently
+/// (`#[serde(tag = "...", content = "...")]`) tagged and `#[serde(untagged)]` enums, get a `ts_type` per variant matching
+/// serde's JSON representation so they can be rendered as a union type. Externally tagged enums
+/// with only unit variants are rendered as a TypeScript `enum`.
+pub(crate) fn convert_enum_to_ts_enum(
+    e: &ItemEnum,
+    defined_types: &[String],
+    mapping: &TypeMapping,
+) -> serde_json::Value {
     let enum_name = e.ident.to_string();
     let doc_comment = extract_doc_comments(&e.attrs);
+    let type_params = type_param_names(&e.generics);
+    let defined_types = &with_type_params(defined_types, &type_params);
+    let representation = EnumRepresentation::from_attrs(&e.attrs);
+    let rename_all = parse_serde_attributes(&e.attrs).rename_all;
+    // 外部タグ付けのユニット列挙子のみの enum は文字列リテラルのユニオン型 ("Red" | "Green") になる
     let mut variants_ts = Vec::new();
 
     for variant in &e.variants {
-        let variant_name = variant.ident.to_string();
+        let variant_serde_attrs = parse_serde_attributes(&variant.attrs);
+        let ident = variant.ident.to_string();
+        // 列挙子単位の rename はコンテナの rename_all より優先される
+        let variant_name = match (variant_serde_attrs.rename, rename_all) {
+            (Some(rename), _) => rename,
+            (None, Some(rule)) => rule.apply_to_variant(&ident),
+            (None, None) => ident,
+        };
         let variant_doc_comment = extract_doc_comments(&variant.attrs);
         let mut variant_info = serde_json::Map::new();
         variant_info.insert(
@@ -313,7 +1082,7 @@ pub(crate) fn convert_enum_to_ts_enum(e: &ItemEnum, defined_types: &[String]) ->
                 let types: Vec<String> = fields
                     .unnamed
                     .iter()
-                    .map(|f| type_to_ts(&f.ty, defined_types, false))
+                    .map(|f| type_to_ts(&f.ty, defined_types, false, mapping))
                     .collect();
                 variant_info.insert(
                     "type".to_string(),
@@ -332,8 +1101,17 @@ pub(crate) fn convert_enum_to_ts_enum(e: &ItemEnum, defined_types: &[String]) ->
                     .named
                     .iter()
                     .map(|f| {
-                        let field_name = f.ident.as_ref().unwrap().to_string();
-                        let field_type = type_to_ts(&f.ty, defined_types, false);
+                        let ident = f.ident.as_ref().unwrap().to_string();
+                        // 構造体の列挙子のフィールドには列挙子の rename_all が適用される
+                        let field_name = match (
+                            parse_serde_attributes(&f.attrs).rename,
+                            variant_serde_attrs.rename_all,
+                        ) {
+                            (Some(rename), _) => rename,
+                            (None, Some(rule)) => rule.apply_to_field(&ident),
+                            (None, None) => ident,
+                        };
+                        let field_type = type_to_ts(&f.ty, defined_types, false, mapping);
                         let field_doc_comment = extract_doc_comments(&f.attrs);
                         serde_json::json!({
                             "name": field_name,
@@ -349,97 +1127,456 @@ pub(crate) fn convert_enum_to_ts_enum(e: &ItemEnum, defined_types: &[String]) ->
                 variant_info.insert("members".to_string(), serde_json::Value::Array(fields_str));
             }
         }
+        let ts_type = enum_variant_to_ts_type(&variant_info, &representation);
+        variant_info.insert("ts_type".to_string(), serde_json::Value::String(ts_type));
         variants_ts.push(serde_json::Value::Object(variant_info));
     }
 
     serde_json::json!({
         "type": "enum",
         "name": enum_name,
+        "type_params": type_params,
         "doc_comment": doc_comment,
+        "representation": representation.name(),
         "variants": variants_ts,
     })
 }
 
-/// Converts a Rust `syn::Type` into its corresponding TypeScript type string.
-pub(crate) fn type_to_ts(
-    ty: &Type,
-    defined_types: &[String],
-    is_tauri_command_type: bool,
+/// How serde represents an enum in JSON.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum EnumRepresentation {
+    /// The serde default: `{ "Variant": payload }`.
+    External,
+    /// `#[serde(tag = "...")]`: `{ "tag": "Variant", ...fields }`.
+    Internal { tag: String },
+    /// `#[serde(tag = "...", content = "...")]`: `{ "tag": "Variant", "content": payload }`.
+    Adjacent { tag: String, content: String },
+    /// `#[serde(untagged)]`: just the payload.
+    Untagged,
+}
+
+impl EnumRepresentation {
+    fn from_attrs(attrs: &[Attribute]) -> Self {
+        let serde_attrs = parse_serde_attributes(attrs);
+        if serde_attrs.untagged {
+            return EnumRepresentation::Untagged;
+        }
+        match (serde_attrs.tag, serde_attrs.content) {
+            (Some(tag), Some(content)) => EnumRepresentation::Adjacent { tag, content },
+            (Some(tag), None) => EnumRepresentation::Internal { tag },
+            _ => EnumRepresentation::External,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            EnumRepresentation::External => "external",
+            EnumRepresentation::Internal { .. } => "internal",
+            EnumRepresentation::Adjacent { .. } => "adjacent",
+            EnumRepresentation::Untagged => "untagged",
+        }
+    }
+}
+
+/// Builds the TypeScript type of a single member of an enum's union type.
+fn enum_variant_to_ts_type(
+    variant_info: &serde_json::Map<String, serde_json::Value>,
+    representation: &EnumRepresentation,
 ) -> String {
-    match ty {
-        Type::Path(type_path) => {
-            if let Some(segment) = type_path.path.segments.last() {
+    let variant_name = variant_info["name"].as_str().unwrap_or_default();
+    let variant_literal = serde_json::Value::String(variant_name.to_string()).to_string();
+    let payload = enum_variant_payload_ts(variant_info);
+
+    match representation {
+        EnumRepresentation::External => match payload {
+            Some(payload) => ts_object_literal(&[(variant_name.to_string(), payload)]),
+            None => variant_literal,
+        },
+        EnumRepresentation::Internal { tag } => {
+            let mut properties = vec![(tag.clone(), variant_literal)];
+            match (variant_info["type"].as_str(), payload) {
+                (Some("struct"), _) => properties.extend(struct_variant_properties(variant_info)),
+                (_, Some(payload)) => {
+                    return format!("{} & {payload}", ts_object_literal(&properties));
+                }
+                _ => {}
+            }
+            ts_object_literal(&properties)
+        }
+        EnumRepresentation::Adjacent { tag, content } => {
+            let mut properties = vec![(tag.clone(), variant_literal)];
+            if let Some(payload) = payload {
+                properties.push((content.clone(), payload));
+            }
+            ts_object_literal(&properties)
+        }
+        // untagged のユニット列挙子は null としてシリアライズされる
+        EnumRepresentation::Untagged => payload.unwrap_or_else(|| "null".to_string()),
+    }
+}
+
+/// Builds the TypeScript type of an enum variant's payload, or `None` for unit variants.
+///
+/// Newtype variants use the inner type, tuple variants a tuple and struct variants an object literal.
+fn enum_variant_payload_ts(
+    variant_info: &serde_json::Map<String, serde_json::Value>,
+) -> Option<String> {
+    match variant_info["type"].as_str() {
+        Some("tuple") => {
+            let members: Vec<&str> = variant_info["members"]
+                .as_array()?
+                .iter()
+                .filter_map(|member| member.as_str())
+                .collect();
+            match members.as_slice() {
+                [single] => Some(single.to_string()),
+                _ => Some(format!("[{}]", members.join(", "))),
+            }
+        }
+        Some("struct") => Some(ts_object_literal(&struct_variant_properties(variant_info))),
+        _ => None,
+    }
+}
+
+/// Collects the `(name, type)` pairs of a struct variant's fields.
+fn struct_variant_properties(
+    variant_info: &serde_json::Map<String, serde_json::Value>,
+) -> Vec<(String, String)> {
+    variant_info["members"]
+        .as_array()
+        .map(|members| {
+            members
+                .iter()
+                .map(|member| {
+                    (
+                        member["name"].as_str().unwrap_or_default().to_string(),
+                        member["type"].as_str().unwrap_or_default().to_string(),
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Renders an inline TypeScript object type such as `{ x: number; y: number }`.
+fn ts_object_literal(properties: &[(String, String)]) -> String {
+    if properties.is_empty() {
+        return "{}".to_string();
+    }
+    let properties: Vec<String> = properties
+        .iter()
+        .map(|(name, ts_type)| format!("{}: {ts_type}", ts_property_key(name)))
+        .collect();
+    format!("{{ {} }}", properties.join("; "))
+}
+
+/// Quotes a property name unless it is a valid TypeScript identifier.
+pub(crate) fn ts_property_key(name: &str) -> String {
+    let mut chars = name.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if is_identifier {
+        name.to_string()
+    } else {
+        serde_json::Value::String(name.to_string()).to_string()
+    }
+}
+
+/// Converts a public Rust `ItemConst` of a primitive type into a `serde_json::Value` representation
+/// for TypeScript const generation.
+///
+/// Only literal values (numbers, strings, chars and bools) are supported. Returns `None` for
+/// non-public consts, non-primitive types and complex const expressions.
+pub(crate) fn convert_const_to_ts_const(c: &ItemConst) -> Option<serde_json::Value> {
+    if !matches!(c.vis, Visibility::Public(_)) {
+        return None;
+    }
+
+    let const_name = c.ident.to_string();
+    let type_ident = match &*c.ty {
+        Type::Path(type_path) => type_path.path.get_ident().map(|ident| ident.to_string()),
+        Type::Reference(type_ref) => match &*type_ref.elem {
+            Type::Path(type_path) => type_path.path.get_ident().map(|ident| ident.to_string()),
+            _ => None,
+        },
+        _ => None,
+    };
+    if !type_ident.is_some_and(|ident| CONST_PRIMITIVE_TYPES.contains(&ident.as_str())) {
+        debug!("Skipping const {const_name} because its type is not a primitive type.");
+        return None;
+    }
+
+    let Some(value) = const_literal_to_ts(&c.expr) else {
+        warn!(
+            "Skipping const {const_name} because only literal values can be converted to TypeScript."
+        );
+        return None;
+    };
+
+    Some(serde_json::json!({
+        "type": "const",
+        "name": const_name,
+        "doc_comment": extract_doc_comments(&c.attrs),
+        "value": value,
+    }))
+}
+
+/// Converts a literal const expression (optionally negated) into its TypeScript representation.
+fn const_literal_to_ts(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(expr_lit) => match &expr_lit.lit {
+            Lit::Int(lit) => Some(lit.base10_digits().to_string()),
+            Lit::Float(lit) => Some(lit.base10_digits().to_string()),
+            Lit::Bool(lit) => Some(lit.value.to_string()),
+            Lit::Str(lit) => serde_json::to_string(&lit.value()).ok(),
+            Lit::Char(lit) => serde_json::to_string(&lit.value().to_string()).ok(),
+            _ => None,
+        },
+        Expr::Unary(expr_unary) if matches!(expr_unary.op, UnOp::Neg(_)) => {
+            match &*expr_unary.expr {
+                Expr::Lit(expr_lit) if matches!(expr_lit.lit, Lit::Int(_) | Lit::Float(_)) => {
+                    const_literal_to_ts(&expr_unary.expr).map(|value| format!("-{value}"))
+                }
+                _ => None,
+            }
+        }
+        Expr::Group(expr_group) => const_literal_to_ts(&expr_group.expr),
+        _ => None,
+    }
+}
+
+/// Types from common crates and the TypeScript types their default serde representation maps to.
+///
+/// Matched by the last path segment (e.g. `chrono::DateTime<Utc>` matches `DateTime`), unless a
+/// user-defined type of the same name exists. Add entries here to support more crates.
+const WELL_KNOWN_TYPES: &[(&str, &str)] = &[
+    // chrono
+    ("DateTime", "string"),
+    ("NaiveDate", "string"),
+    ("NaiveTime", "string"),
+    ("NaiveDateTime", "string"),
+    // time
+    ("OffsetDateTime", "string"),
+    ("PrimitiveDateTime", "string"),
+    // uuid
+    ("Uuid", "string"),
+    // std::path (serde では文字列としてシリアライズされる)
+    ("PathBuf", "string"),
+    ("Path", "string"),
+    // std::time (serde の既定の表現)
+    ("Duration", "{ secs: number; nanos: number }"),
+    (
+        "SystemTime",
+        "{ secs_since_epoch: number; nanos_since_epoch: number }",
+    ),
+];
+
+/// Returns whether byte buffers of a command are passed as `Uint8Array` rather than a JSON array.
+///
+/// Only Tauri v2 passes them through its binary IPC; Tauri v1 serializes them as `number[]`.
+fn is_binary_ipc(is_tauri_command_type: bool, mapping: &TypeMapping) -> bool {
+    is_tauri_command_type && mapping.tauri_version == TauriVersion::V2
+}
+
+/// The largest fixed-size array (`[T; N]`) that is rendered as a TypeScript tuple.
+/// Larger arrays, and arrays whose length is a constant or const generic, become `T[]`.
+const MAX_TUPLE_ARRAY_LEN: usize = 16;
+
+/// Converts a Rust `syn::Type` into its corresponding TypeScript type string.
+pub(crate) fn type_to_ts(
+    ty: &Type,
+    defined_types: &[String],
+    is_tauri_command_type: bool,
+    mapping: &TypeMapping,
+) -> String {
+    match ty {
+        Type::Path(type_path) => {
+            if let Some(segment) = type_path.path.segments.last() {
                 let ident_str = segment.ident.to_string();
                 match ident_str.as_str() {
-                    "String" => "string".to_string(),
+                    "String" | "str" => "string".to_string(),
                     "bool" => "boolean".to_string(),
-                    "u8" | "u16" | "u32" | "u64" | "u128" | "i8" | "i16" | "i32" | "i64"
-                    | "i128" | "usize" | "isize" | "f32" | "f64" => "number".to_string(),
+                    "char" => "string".to_string(),
+                    "u8" | "u16" | "u32" | "i8" | "i16" | "i32" | "usize" | "isize" | "f32"
+                    | "f64" => "number".to_string(),
+                    // 64 ビット以上の整数は number の安全な整数範囲を超え得るため、指定時は bigint にする
+                    "u64" | "u128" | "i64" | "i128" => {
+                        if mapping.bigint_for_64 {
+                            "bigint".to_string()
+                        } else {
+                            "number".to_string()
+                        }
+                    }
+                    "Box" | "Rc" | "Arc" | "Cow" if !defined_types.contains(&ident_str) => {
+                        // スマートポインタは serde では内部の値としてシリアライズされる
+                        // Cow<'a, T> のようにライフタイム引数が先にある場合も最初の型引数を使う
+                        if let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+                            && let Some(inner_type) = args.args.iter().find_map(|arg| match arg {
+                                syn::GenericArgument::Type(inner_type) => Some(inner_type),
+                                _ => None,
+                            })
+                        {
+                            return type_to_ts(
+                                inner_type,
+                                defined_types,
+                                is_tauri_command_type,
+                                mapping,
+                            );
+                        }
+                        "any".to_string() // 内部型が特定できない場合のフォールバック
+                    }
                     "Option" => {
-                        // Option<T> を T | undefined に変換
-                        if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
-                            if let Some(syn::GenericArgument::Type(inner_type)) = args.args.first()
-                            {
-                                let inner_ts_type =
-                                    type_to_ts(inner_type, defined_types, is_tauri_command_type);
-                                return format!("{inner_ts_type} | undefined");
+                        // Option<T> を T | undefined (--option-repr null では T | null) に変換
+                        if let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+                            && let Some(syn::GenericArgument::Type(inner_type)) = args.args.first()
+                        {
+                            let inner_ts_type = type_to_ts(
+                                inner_type,
+                                defined_types,
+                                is_tauri_command_type,
+                                mapping,
+                            );
+                            let absent = match mapping.option_repr {
+                                OptionRepr::Null => "null",
+                                OptionRepr::Undefined | OptionRepr::Optional => "undefined",
+                            };
+                            // Option<Option<T>> のように入れ子になっていても undefined は一つにまとめる
+                            if inner_ts_type.ends_with(&format!(" | {absent}")) {
+                                return inner_ts_type;
                             }
+                            return format!("{inner_ts_type} | {absent}");
                         }
                         "any".to_string() // 内部型が特定できない場合のフォールバック
                     }
-                    "Vec" => {
-                        if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
-                            if let Some(syn::GenericArgument::Type(inner_type)) = args.args.first()
+                    "Vec" | "HashSet" | "BTreeSet" | "VecDeque" | "LinkedList" => {
+                        // シーケンス・集合型はいずれも JSON 配列としてシリアライズされる
+                        if let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+                            && let Some(syn::GenericArgument::Type(inner_type)) = args.args.first()
+                        {
+                            // コマンドの引数・戻り値の Vec<u8> はバイナリとして受け渡される (Tauri v2 のみ)
+                            if is_binary_ipc(is_tauri_command_type, mapping)
+                                && ident_str == "Vec"
+                                && is_u8(inner_type)
                             {
-                                let inner_ts_type =
-                                    type_to_ts(inner_type, defined_types, is_tauri_command_type);
-                                // If the inner type is a union, wrap it in parentheses
-                                if inner_ts_type.contains(" | ") {
-                                    return format!("({inner_ts_type})[]");
-                                } else {
-                                    return format!("{inner_ts_type}[]");
-                                }
+                                return "Uint8Array".to_string();
+                            }
+                            let inner_ts_type = type_to_ts(
+                                inner_type,
+                                defined_types,
+                                is_tauri_command_type,
+                                mapping,
+                            );
+                            // If the inner type is a union, wrap it in parentheses
+                            if inner_ts_type.contains(" | ") {
+                                return format!("({inner_ts_type})[]");
+                            } else {
+                                return format!("{inner_ts_type}[]");
                             }
                         }
                         "any[]".to_string() // 内部型が特定できない場合のフォールバック
                     }
-                    "HashMap" => {
+                    "Value"
+                        if !defined_types.contains(&ident_str)
+                            && is_serde_json_path(&type_path.path) =>
+                    {
+                        // serde_json::Value は任意の JSON 値を表す
+                        if mapping.json_value_as_any {
+                            "any".to_string()
+                        } else {
+                            "unknown".to_string()
+                        }
+                    }
+                    "HashMap" | "BTreeMap" | "Map"
+                        if ident_str != "Map"
+                            || (!defined_types.contains(&ident_str)
+                                && is_serde_json_path(&type_path.path)) =>
+                    {
                         if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
                             let types: Vec<&syn::GenericArgument> = args.args.iter().collect();
-                            if types.len() == 2 {
-                                if let (
+                            if types.len() == 2
+                                && let (
                                     syn::GenericArgument::Type(key_type),
                                     syn::GenericArgument::Type(value_type),
                                 ) = (types[0], types[1])
-                                {
-                                    let key_ts_type =
-                                        type_to_ts(key_type, defined_types, is_tauri_command_type);
-                                    let value_ts_type = type_to_ts(
-                                        value_type,
-                                        defined_types,
-                                        is_tauri_command_type,
-                                    );
-                                    return format!("Record<{key_ts_type}, {value_ts_type}>");
-                                }
+                            {
+                                // JSON のキーは文字列のため bigint にはならず、数値のキーは number のままにする
+                                let key_mapping = TypeMapping {
+                                    bigint_for_64: false,
+                                    ..*mapping
+                                };
+                                let key_ts_type = type_to_ts(
+                                    key_type,
+                                    defined_types,
+                                    is_tauri_command_type,
+                                    &key_mapping,
+                                );
+                                let value_ts_type = type_to_ts(
+                                    value_type,
+                                    defined_types,
+                                    is_tauri_command_type,
+                                    mapping,
+                                );
+                                return format!("Record<{key_ts_type}, {value_ts_type}>");
                             }
                         }
                         "Record<any, any>".to_string() // 内部型が特定できない場合のフォールバック
                     }
                     "Result" => {
-                        if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
-                            if let Some(syn::GenericArgument::Type(ok_type)) = args.args.first() {
-                                let ok_ts_type =
-                                    type_to_ts(ok_type, defined_types, is_tauri_command_type);
-                                return ok_ts_type;
-                            }
+                        if let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+                            && let Some(syn::GenericArgument::Type(ok_type)) = args.args.first()
+                        {
+                            let ok_ts_type =
+                                type_to_ts(ok_type, defined_types, is_tauri_command_type, mapping);
+                            return ok_ts_type;
                         }
                         "any".to_string() // 内部型が特定できない場合のフォールバック
                     }
+                    "Bytes" if !defined_types.contains(&ident_str) => {
+                        // bytes::Bytes は serde では数値配列としてシリアライズされる
+                        if is_binary_ipc(is_tauri_command_type, mapping) {
+                            "Uint8Array".to_string()
+                        } else {
+                            "number[]".to_string()
+                        }
+                    }
                     _ => {
+                        if !defined_types.contains(&ident_str)
+                            && let Some((_, ts_type)) = WELL_KNOWN_TYPES
+                                .iter()
+                                .find(|(rust_type, _)| *rust_type == ident_str)
+                        {
+                            return ts_type.to_string();
+                        }
+                        // ジェネリック型の型引数 (`Wrapper<User>`) も変換して付与する
+                        let type_args = match &segment.arguments {
+                            syn::PathArguments::AngleBracketed(args) => {
+                                let type_args: Vec<String> = args
+                                    .args
+                                    .iter()
+                                    .filter_map(|arg| match arg {
+                                        syn::GenericArgument::Type(arg_type) => Some(type_to_ts(
+                                            arg_type,
+                                            defined_types,
+                                            is_tauri_command_type,
+                                            mapping,
+                                        )),
+                                        _ => None,
+                                    })
+                                    .collect();
+                                if type_args.is_empty() {
+                                    String::new()
+                                } else {
+                                    format!("<{}>", type_args.join(", "))
+                                }
+                            }
+                            _ => String::new(),
+                        };
                         if is_tauri_command_type || !defined_types.contains(&ident_str) {
-                            format!("T.{ident_str}")
+                            format!("T.{ident_str}{type_args}")
                         } else {
-                            ident_str
+                            format!("{ident_str}{type_args}")
                         }
                     }
                 }
@@ -449,14 +1586,18 @@ pub(crate) fn type_to_ts(
         }
         Type::Reference(type_ref) => {
             // &str を string に変換
-            if let Type::Path(path) = &*type_ref.elem {
-                if let Some(segment) = path.path.segments.last() {
-                    if segment.ident == "str" {
-                        return "string".to_string();
-                    }
-                }
+            if let Type::Path(path) = &*type_ref.elem
+                && let Some(segment) = path.path.segments.last()
+                && segment.ident == "str"
+            {
+                return "string".to_string();
             }
-            type_to_ts(&type_ref.elem, defined_types, is_tauri_command_type) // 参照されている型を再帰的に変換
+            type_to_ts(
+                &type_ref.elem,
+                defined_types,
+                is_tauri_command_type,
+                mapping,
+            ) // 参照されている型を再帰的に変換
         }
         Type::Tuple(type_tuple) => {
             if type_tuple.elems.is_empty() {
@@ -465,19 +1606,82 @@ pub(crate) fn type_to_ts(
                 let elems_ts: Vec<String> = type_tuple
                     .elems
                     .iter()
-                    .map(|elem| type_to_ts(elem, defined_types, is_tauri_command_type))
+                    .map(|elem| type_to_ts(elem, defined_types, is_tauri_command_type, mapping))
                     .collect();
                 format!("[{}]", elems_ts.join(", "))
             }
         }
+        Type::Slice(type_slice) => {
+            // コマンドの引数・戻り値の &[u8] はバイナリとして受け渡される (Tauri v2 のみ)
+            if is_binary_ipc(is_tauri_command_type, mapping) && is_u8(&type_slice.elem) {
+                return "Uint8Array".to_string();
+            }
+            let elem_ts = type_to_ts(
+                &type_slice.elem,
+                defined_types,
+                is_tauri_command_type,
+                mapping,
+            );
+            if elem_ts.contains(" | ") {
+                format!("({elem_ts})[]")
+            } else {
+                format!("{elem_ts}[]")
+            }
+        }
+        Type::Array(type_array) => {
+            let elem_ts = type_to_ts(
+                &type_array.elem,
+                defined_types,
+                is_tauri_command_type,
+                mapping,
+            );
+            // 要素数が小さいリテラルの場合は固定長のタプルとして出力する
+            if let Expr::Lit(expr_lit) = &type_array.len
+                && let Lit::Int(lit_int) = &expr_lit.lit
+                && let Ok(len) = lit_int.base10_parse::<usize>()
+                && len <= MAX_TUPLE_ARRAY_LEN
+            {
+                return format!("[{}]", vec![elem_ts; len].join(", "));
+            }
+            if elem_ts.contains(" | ") {
+                format!("({elem_ts})[]")
+            } else {
+                format!("{elem_ts}[]")
+            }
+        }
+        // impl Trait や dyn Trait は具体的な型が分からないため、形を表せない unknown にする
+        Type::ImplTrait(_) => {
+            debug!("Mapping an `impl Trait` type to unknown because its concrete type is unknown");
+            "unknown".to_string()
+        }
+        Type::TraitObject(_) => {
+            debug!("Mapping a `dyn Trait` type to unknown because its concrete type is unknown");
+            "unknown".to_string()
+        }
         _ => "any".to_string(), // その他の複雑な型に対するフォールバック
     }
 }
 
+/// Returns `true` if the path is a bare name (e.g. `Value` imported with `use`) or
+/// qualified with `serde_json` (e.g. `serde_json::Value`).
+fn is_serde_json_path(path: &syn::Path) -> bool {
+    let segments: Vec<&syn::PathSegment> = path.segments.iter().collect();
+    match segments.as_slice() {
+        [_] => true,
+        [.., module, _] => module.ident == "serde_json",
+        [] => false,
+    }
+}
+
+/// Returns `true` if the type is the `u8` primitive.
+fn is_u8(ty: &Type) -> bool {
+    matches!(ty, Type::Path(type_path) if type_path.path.is_ident("u8"))
+}
+
 /// `use`文を解析してエイリアスのマップを作成する
 fn extract_use_aliases(items: &[Item]) -> HashMap<String, String> {
     let mut aliases = HashMap::new();
-    for item in items {
+    for item in flatten_module_items(items) {
         if let Item::Use(use_item) = item {
             parse_use_tree(&mut aliases, &use_item.tree, Vec::new());
         }
@@ -550,6 +1754,7 @@ fn parse_use_tree(
 pub fn extract_tauri_commands(
     items: &[Item],
     all_extracted_types: &[ExtractedTypeInfo],
+    mapping: &TypeMapping,
 ) -> Vec<serde_json::Value> {
     let mut functions = Vec::new();
     let aliases = extract_use_aliases(items);
@@ -560,122 +1765,229 @@ pub fn extract_tauri_commands(
         .map(|info| info.name.clone())
         .collect();
 
-    for item in items {
-        if let Item::Fn(func) = item {
-            if has_tauri_command(&func.attrs) {
-                let fn_name = func.sig.ident.to_string();
-                let doc_comment = extract_doc_comments(&func.attrs);
-                let mut args_ts = Vec::new();
-                let mut invoke_obj = Vec::new();
-
-                for input in &func.sig.inputs {
-                    if let FnArg::Typed(pat_type) = input {
-                        if is_ignored_tauri_type(&pat_type.ty, &aliases) {
-                            continue; // 無視対象のTauri型はスキップ
-                        }
+    for func in collect_fn_items(items) {
+        if has_tauri_command(func.attrs) {
+            let fn_name = func.sig.ident.to_string();
+            let mut doc_comment = extract_doc_comments(func.attrs);
+            let command_args = parse_tauri_command_args(func.attrs);
+            let rename_rule = command_args.rename_all;
+            let mut args_ts = Vec::new();
+            let mut arg_names = Vec::new();
+            // Option<T> 以外の引数 (ラッパーで undefined を検査できるようにする)
+            let mut required_args = Vec::new();
+            let mut invoke_obj = Vec::new();
+            let mut signature_types: Vec<&Type> = Vec::new();
+            let mut uses_channel = false;
+            let mut dropped_args = Vec::new();
+            // unknown に置き換えた箇所 (--strict で報告する)
+            let mut fallbacks: Vec<String> = Vec::new();
 
-                        let name = match &*pat_type.pat {
-                            Pat::Ident(ident) => ident.ident.to_string(),
-                            _ => "arg".to_string(),
+            for input in &func.sig.inputs {
+                if let FnArg::Typed(pat_type) = input {
+                    if is_ignored_tauri_type(&pat_type.ty, &aliases) {
+                        continue; // 無視対象のTauri型はスキップ
+                    }
+
+                    let name = match &*pat_type.pat {
+                        Pat::Ident(ident) => ident.ident.to_string(),
+                        _ => "arg".to_string(),
+                    };
+
+                    // Channel<T> はフロントエンドからメッセージを受け取るコールバックとして公開する
+                    if let Some(payload_ty) = tauri_channel_payload(&pat_type.ty, &aliases) {
+                        let payload_ts = if is_serializable_type(
+                            payload_ty,
+                            &defined_types_names,
+                            all_extracted_types,
+                        ) {
+                            type_to_ts(payload_ty, &defined_types_names, true, mapping)
+                        } else {
+                            debug!(
+                                "Changing channel payload of argument {name} to unknown because it is not Serializable.",
+                            );
+                            "unknown".to_string()
                         };
-                        let ty_str = type_to_ts(&pat_type.ty, &defined_types_names, true);
-
-                        // 引数の型がユーザー定義型の場合、Deserializeが必須
-                        let user_defined_types_in_arg =
-                            get_user_defined_type_names(&pat_type.ty, &defined_types_names);
-                        let mut all_args_deserializable = true;
-                        for user_type_name in &user_defined_types_in_arg {
-                            if let Some(type_info) = all_extracted_types
-                                .iter()
-                                .find(|info| &info.name == user_type_name)
-                            {
-                                if !type_info.is_deserializable {
-                                    debug!(
-                                        "Skipping argument {name} because its nested type {user_type_name} is not Deserializable.",
-                                    );
-                                    all_args_deserializable = false;
-                                    break;
-                                }
-                            }
-                        }
+                        let ts_name = RenameRule::Camel.apply_to_field(&name);
+                        let wire_name = rename_rule.apply_to_field(&name);
+                        doc_comment = rename_doc_param(&doc_comment, &name, &ts_name);
+                        args_ts.push(format!("{ts_name}: (msg: {payload_ts}) => void"));
+                        arg_names.push(ts_name.clone());
+                        required_args.push(ts_name.clone());
+                        invoke_obj.push(format!(
+                            "{wire_name}: Object.assign(new Channel<{payload_ts}>(), {{ onmessage: {ts_name} }})"
+                        ));
+                        signature_types.push(payload_ty);
+                        uses_channel = true;
+                        continue;
+                    }
+                    let ty_str = type_to_ts(&pat_type.ty, &defined_types_names, true, mapping);
 
-                        if !all_args_deserializable {
-                            continue; // Deserializable でない型を含む場合はスキップ
-                        }
+                    // 引数の型がユーザー定義型の場合、Deserializeが必須
+                    let user_defined_types_in_arg =
+                        get_user_defined_type_names(&pat_type.ty, &defined_types_names);
+                    let non_deserializable_type = user_defined_types_in_arg.iter().find(|name| {
+                        all_extracted_types
+                            .iter()
+                            .any(|info| &info.name == *name && !info.is_deserializable)
+                    });
+                    if let Some(type_name) = non_deserializable_type {
+                        // Deserializable でない型を含む引数は省略し、呼び出し側で警告できるよう記録する
+                        dropped_args.push(DroppedArgument {
+                            command_name: fn_name.clone(),
+                            arg_name: name,
+                            arg_type: ty_str,
+                            type_name: type_name.clone(),
+                        });
+                        continue;
+                    }
 
-                        args_ts.push(format!("{name}: {ty_str}"));
-                        invoke_obj.push(format!("{name}: {name}"));
+                    // TypeScript の引数名は camelCase、invoke のキーは Tauri の rename_all に従う
+                    let ts_name = RenameRule::Camel.apply_to_field(&name);
+                    let wire_name = rename_rule.apply_to_field(&name);
+                    doc_comment = rename_doc_param(&doc_comment, &name, &ts_name);
+                    args_ts.push(format!("{ts_name}: {ty_str}"));
+                    arg_names.push(ts_name.clone());
+                    if option_inner_type(&pat_type.ty).is_none() {
+                        required_args.push(ts_name.clone());
                     }
+                    invoke_obj.push(format!("{wire_name}: {ts_name}"));
+                    signature_types.push(&pat_type.ty);
                 }
+            }
 
-                let ret_ty = match &func.sig.output {
-                    syn::ReturnType::Type(_, ty) => {
-                        // Result<(), E> を void に変換する処理
-                        let mut is_result_unit = false;
-                        if let Type::Path(type_path) = &**ty {
-                            if let Some(segment) = type_path.path.segments.last() {
-                                if segment.ident == "Result" {
-                                    if let syn::PathArguments::AngleBracketed(args) =
-                                        &segment.arguments
-                                    {
-                                        if let Some(syn::GenericArgument::Type(Type::Tuple(
-                                            tuple,
-                                        ))) = args.args.first()
-                                        {
-                                            if tuple.elems.is_empty() {
-                                                is_result_unit = true;
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
+            let ret_ty = match &func.sig.output {
+                syn::ReturnType::Type(_, ty) => {
+                    signature_types.push(ty);
+                    // 入れ子の Result<Result<T, E1>, E2> も含め、最も内側の T を戻り値とする
+                    let ok_ty = innermost_ok_type(ty);
+                    // Result<(), E> を void に変換する処理
+                    let is_result_unit =
+                        matches!(ok_ty, Type::Tuple(tuple) if tuple.elems.is_empty());
 
-                        let mut final_ret_ty = if is_result_unit {
-                            "void".to_string()
-                        } else if is_tauri_ipc_response(ty, &aliases) {
-                            "unknown".to_string()
-                        } else {
-                            type_to_ts(ty, &defined_types_names, true)
-                        };
+                    let mut final_ret_ty = if is_result_unit {
+                        "void".to_string()
+                    } else if is_tauri_ipc_response(ty, &aliases) {
+                        "unknown".to_string()
+                    } else {
+                        type_to_ts(ty, &defined_types_names, true, mapping)
+                    };
 
-                        // 戻り値の型がユーザー定義型の場合、Serializeが必須
-                        let user_defined_types_in_ret =
-                            get_user_defined_type_names(ty, &defined_types_names);
-                        for user_type_name in &user_defined_types_in_ret {
-                            if let Some(type_info) = all_extracted_types
-                                .iter()
-                                .find(|info| &info.name == user_type_name)
-                            {
-                                if !type_info.is_serializable {
-                                    debug!(
-                                        "Changing return type of function {fn_name} to unknown because its nested type {user_type_name} is not Serializable.",
-                                    );
-                                    final_ret_ty = "unknown".to_string(); // Serializable でない場合は unknown に変更
-                                    break;
-                                }
-                            }
+                    // 戻り値の型がユーザー定義型の場合、Serializeが必須
+                    // (Result のエラー型は error_type として別に判定する)
+                    let user_defined_types_in_ret =
+                        get_user_defined_type_names(ok_ty, &defined_types_names);
+                    for user_type_name in &user_defined_types_in_ret {
+                        if let Some(type_info) = all_extracted_types
+                            .iter()
+                            .find(|info| &info.name == user_type_name)
+                            && !type_info.is_serializable
+                        {
+                            debug!(
+                                "Changing return type of function {fn_name} to unknown because its nested type {user_type_name} is not Serializable.",
+                            );
+                            fallbacks.push(format!(
+                                "the return type is `unknown` because `{user_type_name}` does not derive Serialize"
+                            ));
+                            final_ret_ty = "unknown".to_string(); // Serializable でない場合は unknown に変更
+                            break;
                         }
-                        final_ret_ty
                     }
-                    _ => "void".to_string(),
-                };
+                    final_ret_ty
+                }
+                _ => "void".to_string(),
+            };
 
-                let func_json = serde_json::json!({
-                    "name": fn_name,
-                    "doc_comment": doc_comment,
-                    "args": args_ts,
-                    "invoke_args": invoke_obj,
-                    "return_type": ret_ty,
-                });
-                debug!("DEBUG: func_json = {func_json:?}");
-                functions.push(func_json);
-            }
+            // Result<T, E> の E をエラー型として取得する (Serializable でない場合は unknown)
+            let error_ty = result_error_type(&func.sig.output).map(|err_ty| {
+                if is_serializable_type(err_ty, &defined_types_names, all_extracted_types) {
+                    type_to_ts(err_ty, &defined_types_names, true, mapping)
+                } else {
+                    debug!(
+                        "Changing error type of function {fn_name} to unknown because it is not Serializable.",
+                    );
+                    fallbacks.push(
+                        "the error type is `unknown` because it does not derive Serialize"
+                            .to_string(),
+                    );
+                    "unknown".to_string()
+                }
+            });
+
+            let func_json = serde_json::json!({
+                "name": fn_name,
+                "doc_comment": doc_comment,
+                "args": args_ts,
+                "arg_names": arg_names,
+                "required_args": required_args,
+                "invoke_args": invoke_obj,
+                "return_type": ret_ty,
+                "error_type": error_ty,
+                "referenced_types": collect_type_idents(signature_types),
+                "uses_channel": uses_channel,
+                "is_async": func.sig.asyncness.is_some() || command_args.is_async,
+                "line": func.sig.ident.span().start().line,
+                "dropped_args": dropped_args,
+                "fallbacks": fallbacks,
+            });
+            debug!("DEBUG: func_json = {func_json:?}");
+            functions.push(func_json);
         }
     }
     functions
 }
 
+/// Checks that every user-defined type referenced by `ty` is `Serialize`.
+fn is_serializable_type(
+    ty: &Type,
+    defined_types_names: &[String],
+    all_extracted_types: &[ExtractedTypeInfo],
+) -> bool {
+    get_user_defined_type_names(ty, defined_types_names)
+        .iter()
+        .all(|user_type_name| {
+            all_extracted_types
+                .iter()
+                .find(|info| &info.name == user_type_name)
+                .is_none_or(|type_info| type_info.is_serializable)
+        })
+}
+
+/// Returns the success type `T` if `ty` is `Result<T, E>`.
+fn result_ok_type(ty: &Type) -> Option<&Type> {
+    if let Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+        && segment.ident == "Result"
+        && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(syn::GenericArgument::Type(ok_ty)) = args.args.first()
+    {
+        return Some(ok_ty);
+    }
+    None
+}
+
+/// Peels `Result<T, E>`, including nested ones such as `Result<Result<T, E1>, E2>`, down to
+/// the innermost `T`. Other types are returned as they are.
+fn innermost_ok_type(mut ty: &Type) -> &Type {
+    while let Some(ok_ty) = result_ok_type(ty) {
+        ty = ok_ty;
+    }
+    ty
+}
+
+/// Returns the error type `E` if the function returns `Result<T, E>`.
+fn result_error_type(output: &syn::ReturnType) -> Option<&Type> {
+    if let syn::ReturnType::Type(_, ty) = output
+        && let Type::Path(type_path) = &**ty
+        && let Some(segment) = type_path.path.segments.last()
+        && segment.ident == "Result"
+        && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(syn::GenericArgument::Type(err_ty)) = args.args.iter().nth(1)
+    {
+        return Some(err_ty);
+    }
+    None
+}
+
 // Helper to get user-defined type names from a syn::Type, searching recursively.
 fn get_user_defined_type_names(ty: &Type, defined_types_names: &[String]) -> Vec<String> {
     let mut user_defined_types = Vec::new();
@@ -711,6 +2023,18 @@ fn get_user_defined_type_names(ty: &Type, defined_types_names: &[String]) -> Vec
                     .extend(get_user_defined_type_names(elem_ty, defined_types_names));
             }
         }
+        Type::Slice(type_slice) => {
+            user_defined_types.extend(get_user_defined_type_names(
+                &type_slice.elem,
+                defined_types_names,
+            ));
+        }
+        Type::Array(type_array) => {
+            user_defined_types.extend(get_user_defined_type_names(
+                &type_array.elem,
+                defined_types_names,
+            ));
+        }
         _ => {}
     }
 
@@ -726,6 +2050,7 @@ fn is_ignored_tauri_type(ty: &Type, aliases: &HashMap<String, String>) -> bool {
                 .iter()
                 .map(|s| s.ident.to_string())
                 .collect();
+            // ジェネリクスやライフタイムの引数 (例: `State<'_, T>`) はパスに含めずに判定する
             let path_str = segments.join("::");
 
             let final_path = if segments.len() == 1 {
@@ -739,11 +2064,6 @@ fn is_ignored_tauri_type(ty: &Type, aliases: &HashMap<String, String>) -> bool {
                 &path_str
             };
 
-            // `State` はジェネリクスを持つため、前方一致で判定
-            if final_path.starts_with("tauri::State") {
-                return true;
-            }
-
             IGNORED_TAURI_TYPES.contains(&final_path)
         }
         Type::Reference(type_ref) => {
@@ -781,23 +2101,105 @@ fn is_tauri_ipc_response(ty: &Type, aliases: &HashMap<String, String>) -> bool {
     }
 }
 
+/// Returns the message type `T` if `ty` is `tauri::ipc::Channel<T>`, given by its full path or
+/// through a `use` alias.
+fn tauri_channel_payload<'a>(ty: &'a Type, aliases: &HashMap<String, String>) -> Option<&'a Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segments: Vec<_> = type_path
+        .path
+        .segments
+        .iter()
+        .map(|s| s.ident.to_string())
+        .collect();
+    let path_str = segments.join("::");
+
+    let final_path = if segments.len() == 1 {
+        aliases
+            .get(&path_str)
+            .map(|s| s.as_str())
+            .unwrap_or(&path_str)
+    } else {
+        &path_str
+    };
+    if final_path != "tauri::ipc::Channel" {
+        return None;
+    }
+
+    match &type_path.path.segments.last()?.arguments {
+        syn::PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+            syn::GenericArgument::Type(payload_ty) => Some(payload_ty),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
 /// Checks if a given attribute list contains a `#[tauri::command]` or `#[command]` attribute.
 pub(crate) fn has_tauri_command(attrs: &[Attribute]) -> bool {
-    attrs.iter().any(|attr| {
-        let path = attr.path();
-        // #[command] の形式
-        if path.is_ident("command") {
-            return true;
-        }
-        // #[tauri::command] の形式
-        if path.segments.len() == 2
-            && path.segments[0].ident == "tauri"
-            && path.segments[1].ident == "command"
-        {
-            return true;
+    attrs.iter().any(is_tauri_command_attr)
+}
+
+/// Checks if an attribute is `#[command]` or `#[tauri::command]` (with or without arguments).
+fn is_tauri_command_attr(attr: &Attribute) -> bool {
+    let path = attr.path();
+    // #[command] の形式
+    if path.is_ident("command") {
+        return true;
+    }
+    // #[tauri::command] の形式
+    path.segments.len() == 2
+        && path.segments[0].ident == "tauri"
+        && path.segments[1].ident == "command"
+}
+
+/// The arguments of a `#[tauri::command(...)]` attribute that affect the generated TypeScript.
+struct TauriCommandArgs {
+    /// The rule Tauri uses to derive the invoke payload keys from argument names.
+    ///
+    /// This is `rename_all = "..."`, or camelCase (Tauri's default).
+    rename_all: RenameRule,
+    /// `async`: the command runs on the async runtime even if it is not an `async fn`.
+    is_async: bool,
+}
+
+/// Parses the arguments of the `#[command(...)]` / `#[tauri::command(...)]` attributes.
+///
+/// Every argument is read, and unknown ones (e.g. `root = "crate"`) are skipped, so they neither
+/// hide the command nor the arguments that follow them. The command keeps its function name.
+fn parse_tauri_command_args(attrs: &[Attribute]) -> TauriCommandArgs {
+    let mut args = TauriCommandArgs {
+        rename_all: RenameRule::Camel,
+        is_async: false,
+    };
+    for attr in attrs.iter().filter(|attr| is_tauri_command_attr(attr)) {
+        // 引数のない #[tauri::command] は既定値のまま
+        if !matches!(attr.meta, Meta::List(_)) {
+            continue;
         }
-        false
-    })
+        let result = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename_all") {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                if let Some(rule) = RenameRule::from_name(&value.value()) {
+                    args.rename_all = rule;
+                }
+            } else if meta.path.is_ident("async") {
+                args.is_async = true;
+            } else if meta.input.peek(syn::Token![=]) {
+                // 未知の `key = value` は値ごと読み飛ばす
+                meta.value()?.parse::<Expr>()?;
+            } else if !meta.input.is_empty() && !meta.input.peek(syn::Token![,]) {
+                // 未知の `key(...)` は括弧ごと読み飛ばす
+                meta.input.parse::<proc_macro2::TokenTree>()?;
+            }
+            Ok(())
+        });
+        if let Err(err) = result {
+            warn!("Failed to parse the arguments of the command attribute: {err}");
+        }
+    }
+    args
 }
 
 /// Extracts documentation comments from the given attributes.
@@ -816,14 +2218,12 @@ pub fn extract_doc_comments(attrs: &[Attribute]) -> String {
     attrs
         .iter()
         .filter_map(|attr| {
-            if attr.path().is_ident("doc") {
-                if let Meta::NameValue(meta_name_value) = &attr.meta {
-                    if let syn::Expr::Lit(expr_lit) = &meta_name_value.value {
-                        if let Lit::Str(lit_str) = &expr_lit.lit {
-                            return Some(lit_str.value().trim().to_string());
-                        }
-                    }
-                }
+            if attr.path().is_ident("doc")
+                && let Meta::NameValue(meta_name_value) = &attr.meta
+                && let syn::Expr::Lit(expr_lit) = &meta_name_value.value
+                && let Lit::Str(lit_str) = &expr_lit.lit
+            {
+                return Some(normalize_doc_lines(&lit_str.value()));
             }
             None
         })
@@ -831,79 +2231,1606 @@ pub fn extract_doc_comments(attrs: &[Attribute]) -> String {
         .join("\n")
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Renames the parameter of `@param <rust_name>` tags in a doc comment to its TypeScript name.
+fn rename_doc_param(doc_comment: &str, rust_name: &str, ts_name: &str) -> String {
+    if rust_name == ts_name {
+        return doc_comment.to_string();
+    }
+    doc_comment
+        .lines()
+        .map(|line| {
+            let rest = line.strip_prefix("@param ");
+            match rest.and_then(|rest| rest.strip_prefix(rust_name)) {
+                Some(description) if description.is_empty() || description.starts_with(' ') => {
+                    format!("@param {ts_name}{description}")
+                }
+                _ => line.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Trims a doc attribute value and strips the leading `*` of each line of a `/** ... */` block.
+/// Doxygen-style tags are mapped to their JSDoc equivalents.
+fn normalize_doc_lines(doc: &str) -> String {
+    let doc = doc.trim();
+    if !doc.contains('\n') {
+        return to_jsdoc_tag(doc);
+    }
+    // ブロックコメント (/** ... */) の各行の先頭にある " * " を取り除く
+    doc.lines()
+        .map(|line| {
+            let line = line.trim();
+            let line = match line.strip_prefix('*') {
+                Some(rest) => rest.strip_prefix(' ').unwrap_or(rest),
+                None => line,
+            };
+            to_jsdoc_tag(line)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+/// Maps a Doxygen-style tag at the start of a doc line to JSDoc.
+///
+/// JSDoc has no `@brief`, so the tag is dropped and only its description is kept.
+/// `@return` is renamed to `@returns`.
+fn to_jsdoc_tag(line: &str) -> String {
+    if let Some(rest) = line.strip_prefix("@brief")
+        && (rest.is_empty() || rest.starts_with(char::is_whitespace))
+    {
+        return rest.trim_start().to_string();
+    }
+    if let Some(rest) = line.strip_prefix("@return")
+        && (rest.is_empty() || rest.starts_with(char::is_whitespace))
+    {
+        return format!("@returns{rest}");
+    }
+    line.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_and_convert_types() {
+        let rust_code = r#"
+            #[derive(Serialize, Deserialize)]
+            struct MyStruct {
+                field1: String,
+            }
+
+            #[derive(Serialize, Deserialize)]
+            enum MyEnum {
+                VariantA,
+            }
+
+            struct NotConvertedStruct;
+
+            #[derive(Serialize)]
+            struct OnlySerializable;
+
+            #[derive(Deserialize)]
+            struct OnlyDeserializable;
+
+            struct NoDerive;
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let extracted_types =
+            extract_and_convert_types(&syntax.items, "test_file", &TypeMapping::default());
+
+        assert_eq!(extracted_types.len(), 6);
+
+        let my_struct = extracted_types
+            .iter()
+            .find(|info| info.name == "MyStruct")
+            .unwrap();
+        assert!(my_struct.is_serializable);
+        assert!(my_struct.is_deserializable);
+
+        let my_enum = extracted_types
+            .iter()
+            .find(|info| info.name == "MyEnum")
+            .unwrap();
+        assert!(my_enum.is_serializable);
+        assert!(my_enum.is_deserializable);
+
+        let not_converted_struct = extracted_types
+            .iter()
+            .find(|info| info.name == "NotConvertedStruct")
+            .unwrap();
+        assert!(!not_converted_struct.is_serializable);
+        assert!(!not_converted_struct.is_deserializable);
+
+        let only_serializable = extracted_types
+            .iter()
+            .find(|info| info.name == "OnlySerializable")
+            .unwrap();
+        assert!(only_serializable.is_serializable);
+        assert!(!only_serializable.is_deserializable);
+
+        let only_deserializable = extracted_types
+            .iter()
+            .find(|info| info.name == "OnlyDeserializable")
+            .unwrap();
+        assert!(!only_deserializable.is_serializable);
+        assert!(only_deserializable.is_deserializable);
+
+        let no_derive = extracted_types
+            .iter()
+            .find(|info| info.name == "NoDerive")
+            .unwrap();
+        assert!(!no_derive.is_serializable);
+        assert!(!no_derive.is_deserializable);
+    }
+
+    #[test]
+    fn test_extract_and_convert_types_flatten_two_levels() {
+        let rust_code = r#"
+            #[derive(Serialize, Deserialize)]
+            struct BaseRequest {
+                id: u32,
+            }
+
+            #[derive(Serialize, Deserialize)]
+            struct Request {
+                #[serde(flatten)]
+                base: BaseRequest,
+                extra_field: String,
+            }
+
+            #[derive(Serialize, Deserialize)]
+            struct AdminRequest {
+                #[serde(rename = "req", flatten)]
+                request: Request,
+                #[serde(flatten)]
+                extra: HashMap<String, String>,
+                reason: String,
+            }
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let mut extracted_types =
+            extract_and_convert_types(&syntax.items, "test_file", &TypeMapping::default());
+        let unresolved = resolve_flattened_fields(&mut extracted_types);
+        assert_eq!(
+            unresolved,
+            vec![
+                "Field AdminRequest.extra is flattened but its type Record<string, string> is not a known user-defined type; emitting it as a regular field."
+            ]
+        );
+
+        let base = &extracted_types[0].ts_interface;
+        assert_eq!(base["extends"], serde_json::json!([]));
+
+        let request = &extracted_types[1].ts_interface;
+        assert_eq!(request["extends"], serde_json::json!(["BaseRequest"]));
+        assert_eq!(request["fields"].as_array().unwrap().len(), 1);
+        assert_eq!(request["fields"][0]["name"], "extra_field");
+
+        // 未知の型 (HashMap) の flatten は通常のフィールドとして残す
+        let admin = &extracted_types[2].ts_interface;
+        assert_eq!(admin["extends"], serde_json::json!(["Request"]));
+        let field_names: Vec<&str> = admin["fields"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|field| field["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(field_names, vec!["reason", "extra"]);
+    }
+
+    #[test]
+    fn test_resolve_flattened_enum_and_option_as_intersections() {
+        let syntax = syn::parse_file(
+            r#"
+            #[derive(Serialize)]
+            struct Meta { id: u32 }
+
+            #[derive(Serialize)]
+            enum Kind { A, B }
+
+            #[derive(Serialize)]
+            struct Request {
+                #[serde(flatten)]
+                kind: Kind,
+                #[serde(flatten)]
+                meta: Option<Meta>,
+                name: String,
+            }
+
+            #[derive(Serialize)]
+            struct Admin {
+                #[serde(flatten)]
+                request: Request,
+                #[serde(flatten)]
+                meta: Meta,
+            }
+            "#,
+        )
+        .unwrap();
+        let mut extracted =
+            extract_and_convert_types(&syntax.items, "requests", &TypeMapping::default());
+        assert!(resolve_flattened_fields(&mut extracted).is_empty());
+
+        let request = &extracted[2].ts_interface;
+        assert_eq!(request["extends"], serde_json::json!([]));
+        assert_eq!(
+            request["intersections"],
+            serde_json::json!(["Kind", "Partial<Meta>"])
+        );
+        assert_eq!(request["fields"].as_array().unwrap().len(), 1);
+
+        // 交差型になる型は継承できないため、継承する側も交差型にまとめる
+        let admin = &extracted[3].ts_interface;
+        assert_eq!(admin["extends"], serde_json::json!([]));
+        assert_eq!(
+            admin["intersections"],
+            serde_json::json!(["Meta", "Request"])
+        );
+    }
+
+    #[test]
+    fn test_resolve_flattened_unknown_type_as_regular_field() {
+        let syntax = syn::parse_file(
+            r#"
+            #[derive(Serialize)]
+            struct Request {
+                #[serde(flatten)]
+                meta: external::Meta,
+                name: String,
+            }
+            "#,
+        )
+        .unwrap();
+        let mut extracted =
+            extract_and_convert_types(&syntax.items, "requests", &TypeMapping::default());
+        let unresolved = resolve_flattened_fields(&mut extracted);
+        assert_eq!(unresolved.len(), 1);
+        assert!(unresolved[0].contains("Request.meta"));
+
+        let request = &extracted[0].ts_interface;
+        assert_eq!(request["extends"], serde_json::json!([]));
+        assert_eq!(request["intersections"], serde_json::json!([]));
+        let field_names: Vec<&str> = request["fields"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|field| field["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(field_names, vec!["name", "meta"]);
+        assert_eq!(request["fields"][1]["type"], "T.Meta");
+    }
+
+    #[test]
+    fn test_convert_struct_to_ts_interface_rename_all() {
+        let item: ItemStruct = syn::parse_quote! {
+            #[derive(Serialize, Deserialize)]
+            #[serde(rename_all = "camelCase")]
+            struct UserProfile {
+                user_name: String,
+                created_at_ms: u64,
+                #[serde(rename = "ID")]
+                user_id: u32,
+            }
+        };
+        let ts_interface = convert_struct_to_ts_interface(&item, &[], &TypeMapping::default());
+        let field_names: Vec<&str> = ts_interface["fields"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|field| field["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(field_names, vec!["userName", "createdAtMs", "ID"]);
+    }
+
+    #[test]
+    fn test_convert_struct_to_ts_interface_time_fields() {
+        let item: ItemStruct = syn::parse_quote! {
+            #[derive(Serialize, Deserialize)]
+            struct Job {
+                timeout: std::time::Duration,
+                retry_delay: Option<Duration>,
+                started_at: SystemTime,
+            }
+        };
+        let ts_interface = convert_struct_to_ts_interface(&item, &[], &TypeMapping::default());
+        assert_eq!(
+            ts_interface["fields"][0]["type"],
+            "{ secs: number; nanos: number }"
+        );
+        assert_eq!(
+            ts_interface["fields"][1]["type"],
+            "{ secs: number; nanos: number }"
+        );
+        assert_eq!(ts_interface["fields"][1]["optional"], true);
+        assert_eq!(
+            ts_interface["fields"][2]["type"],
+            "{ secs_since_epoch: number; nanos_since_epoch: number }"
+        );
+
+        // 同名のユーザー定義型があればそちらを優先する
+        let ts_interface = convert_struct_to_ts_interface(
+            &item,
+            &["Duration".to_string()],
+            &TypeMapping::default(),
+        );
+        assert_eq!(ts_interface["fields"][0]["type"], "Duration");
+    }
+
+    #[test]
+    fn test_convert_struct_to_ts_interface_skipped_fields() {
+        let fields_of = |item: ItemStruct| -> Vec<(String, bool)> {
+            convert_struct_to_ts_interface(&item, &[], &TypeMapping::default())["fields"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|field| {
+                    (
+                        field["name"].as_str().unwrap().to_string(),
+                        field["optional"].as_bool().unwrap(),
+                    )
+                })
+                .collect()
+        };
+
+        let item: ItemStruct = syn::parse_quote! {
+            #[derive(Serialize, Deserialize)]
+            struct Session {
+                id: u32,
+                #[serde(skip)]
+                cache: Vec<u8>,
+                #[serde(skip_serializing)]
+                password: String,
+                #[serde(skip_deserializing)]
+                created_at: String,
+                #[serde(skip_serializing_if = "Vec::is_empty")]
+                tags: Vec<String>,
+            }
+        };
+        assert_eq!(
+            fields_of(item),
+            vec![
+                ("id".to_string(), false),
+                ("password".to_string(), true),
+                ("created_at".to_string(), true),
+                ("tags".to_string(), true),
+            ]
+        );
+
+        // 片方向のみの型では、その方向で省略されるフィールドは出力しない
+        let item: ItemStruct = syn::parse_quote! {
+            #[derive(Serialize)]
+            struct Response {
+                id: u32,
+                #[serde(skip_serializing)]
+                internal: String,
+                #[serde(skip_deserializing)]
+                computed: String,
+            }
+        };
+        assert_eq!(
+            fields_of(item),
+            vec![("id".to_string(), false), ("computed".to_string(), false)]
+        );
+    }
+
+    #[test]
+    fn test_convert_struct_to_ts_interface_default_fields() {
+        let fields_of = |item: ItemStruct| -> Vec<(String, String, bool)> {
+            convert_struct_to_ts_interface(&item, &[], &TypeMapping::default())["fields"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|field| {
+                    (
+                        field["name"].as_str().unwrap().to_string(),
+                        field["type"].as_str().unwrap().to_string(),
+                        field["optional"].as_bool().unwrap(),
+                    )
+                })
+                .collect()
+        };
+
+        // 入力として受け取る型では、既定値のあるフィールドを省略できる
+        let item: ItemStruct = syn::parse_quote! {
+            #[derive(Serialize, Deserialize)]
+            struct Settings {
+                name: String,
+                #[serde(default)]
+                retries: u32,
+                #[serde(default = "default_port")]
+                port: u16,
+            }
+        };
+        assert_eq!(
+            fields_of(item),
+            vec![
+                ("name".to_string(), "string".to_string(), false),
+                ("retries".to_string(), "number".to_string(), true),
+                ("port".to_string(), "number".to_string(), true),
+            ]
+        );
+
+        // コンテナの #[serde(default)] はすべてのフィールドに適用される
+        let item: ItemStruct = syn::parse_quote! {
+            #[derive(Deserialize)]
+            #[serde(default)]
+            struct Filter { query: String, limit: u32 }
+        };
+        assert!(fields_of(item).iter().all(|(_, _, optional)| *optional));
+
+        // 出力専用の型では既定値のあるフィールドも常に存在する
+        let item: ItemStruct = syn::parse_quote! {
+            #[derive(Serialize)]
+            struct Report {
+                #[serde(default)]
+                count: u32,
+            }
+        };
+        assert_eq!(
+            fields_of(item),
+            vec![("count".to_string(), "number".to_string(), false)]
+        );
+    }
+
+    #[test]
+    fn test_extract_flattened_types_defined_later_or_elsewhere() {
+        let syntax = syn::parse_file(
+            r#"
+            #[derive(Serialize)]
+            struct Request {
+                #[serde(flatten)]
+                meta: Meta,
+                #[serde(flatten)]
+                audit: common::Audit,
+                owner: Owner,
+            }
+
+            #[derive(Serialize)]
+            struct Meta { id: u32 }
+
+            #[derive(Serialize)]
+            struct Owner { name: String }
+            "#,
+        )
+        .unwrap();
+        let audit_syntax =
+            syn::parse_file("#[derive(Serialize)] struct Audit { by: String }").unwrap();
+        let mut extracted =
+            extract_and_convert_types(&syntax.items, "requests", &TypeMapping::default());
+        extracted.extend(extract_and_convert_types(
+            &audit_syntax.items,
+            "common",
+            &TypeMapping::default(),
+        ));
+        assert!(resolve_flattened_fields(&mut extracted).is_empty());
+        let request = &extracted[0].ts_interface;
+        assert_eq!(request["extends"], serde_json::json!(["Meta", "Audit"]));
+        assert_eq!(request["fields"].as_array().unwrap().len(), 1);
+        assert_eq!(request["fields"][0]["type"], "Owner");
+    }
+
+    #[test]
+    fn test_convert_generic_struct_and_enum() {
+        let item: ItemStruct = syn::parse_quote! {
+            #[derive(Serialize)]
+            struct Wrapper<'a, T: Clone, const N: usize> {
+                value: T,
+                items: Vec<T>,
+                label: &'a str,
+            }
+        };
+        let ts_interface = convert_struct_to_ts_interface(&item, &[], &TypeMapping::default());
+        assert_eq!(ts_interface["type_params"], serde_json::json!(["T"]));
+        assert_eq!(ts_interface["fields"][0]["type"], "T");
+        assert_eq!(ts_interface["fields"][1]["type"], "T[]");
+        assert_eq!(ts_interface["fields"][2]["type"], "string");
+
+        let item: ItemEnum = syn::parse_quote! {
+            #[derive(Serialize)]
+            enum Outcome<V, E> {
+                Success(V),
+                Failure { error: E },
+            }
+        };
+        let ts_enum = convert_enum_to_ts_enum(&item, &[], &TypeMapping::default());
+        assert_eq!(ts_enum["type_params"], serde_json::json!(["V", "E"]));
+        assert_eq!(ts_enum["variants"][0]["ts_type"], "{ Success: V }");
+        assert_eq!(
+            ts_enum["variants"][1]["ts_type"],
+            "{ Failure: { error: E } }"
+        );
+
+        // 使用側では型引数も変換される
+        let ty: Type = syn::parse_quote!(Outcome<Vec<User>, String>);
+        let defined = vec!["Outcome".to_string(), "User".to_string()];
+        assert_eq!(
+            type_to_ts(&ty, &defined, true, &TypeMapping::default()),
+            "T.Outcome<T.User[], string>"
+        );
+        assert_eq!(
+            type_to_ts(&ty, &defined, false, &TypeMapping::default()),
+            "Outcome<User[], string>"
+        );
+    }
+
+    #[test]
+    fn test_resolve_type_aliases() {
+        let mut syntax = syn::parse_file(
+            r#"
+            type A = B;
+            type B = String;
+            type Ids = Vec<UserId>;
+            type UserId = u32;
+            type Current = User;
+
+            #[derive(Serialize, Deserialize)]
+            struct User { id: UserId }
+
+            #[tauri::command]
+            fn rename(name: A, ids: Ids) -> Current { todo!() }
+            "#,
+        )
+        .unwrap();
+        resolve_type_aliases(&mut syntax.items);
+
+        let mapping = TypeMapping::default();
+        let types = extract_and_convert_types(&syntax.items, "users", &mapping);
+        assert_eq!(types[0].ts_interface["fields"][0]["type"], "number");
+
+        let commands = extract_tauri_commands(&syntax.items, &types, &mapping);
+        assert_eq!(commands[0]["args"][0], "name: string");
+        assert_eq!(commands[0]["args"][1], "ids: number[]");
+        assert_eq!(commands[0]["return_type"], "T.User");
+    }
+
+    #[test]
+    fn test_convert_tuple_structs_to_aliases() {
+        let item: ItemStruct = syn::parse_quote! {
+            #[derive(Serialize, Deserialize)]
+            struct Meters(f64);
+        };
+        let ts_alias = convert_struct_to_ts_interface(&item, &[], &TypeMapping::default());
+        assert_eq!(ts_alias["type"], "alias");
+        assert_eq!(ts_alias["name"], "Meters");
+        assert_eq!(ts_alias["ts_type"], "number");
+
+        let item: ItemStruct = syn::parse_quote! {
+            #[derive(Serialize, Deserialize)]
+            struct Point(f64, f64, Option<String>);
+        };
+        let ts_alias =
+            convert_struct_to_ts_interface(&item, &["Meters".to_string()], &TypeMapping::default());
+        assert_eq!(ts_alias["ts_type"], "[number, number, string | undefined]");
+
+        let item: ItemStruct = syn::parse_quote! {
+            #[derive(Serialize, Deserialize)]
+            struct Labeled<T>(T, Meters);
+        };
+        let ts_alias =
+            convert_struct_to_ts_interface(&item, &["Meters".to_string()], &TypeMapping::default());
+        assert_eq!(ts_alias["type_params"], serde_json::json!(["T"]));
+        assert_eq!(ts_alias["ts_type"], "[T, Meters]");
+    }
+
+    #[test]
+    fn test_serde_field_rename() {
+        let item: ItemStruct = syn::parse_quote! {
+            #[derive(Serialize, Deserialize)]
+            #[allow(non_snake_case)]
+            pub struct User {
+                #[serde(rename = "user_id")]
+                pub userId: u32,
+                pub name: String,
+            }
+        };
+        let ts_interface = convert_struct_to_ts_interface(&item, &[], &TypeMapping::default());
+        assert_eq!(ts_interface["fields"][0]["name"], "user_id");
+        assert_eq!(ts_interface["fields"][0]["type"], "number");
+        assert_eq!(ts_interface["fields"][1]["name"], "name");
+
+        let item: ItemEnum = syn::parse_quote! {
+            #[derive(Serialize, Deserialize)]
+            #[allow(non_snake_case)]
+            pub enum Event {
+                Login {
+                    #[serde(rename = "user_id")]
+                    userId: u32,
+                },
+            }
+        };
+        let ts_enum = convert_enum_to_ts_enum(&item, &[], &TypeMapping::default());
+        assert_eq!(ts_enum["variants"][0]["members"][0]["name"], "user_id");
+    }
+
+    #[test]
+    fn test_serde_field_rename_quotes_non_identifiers() {
+        let item: ItemStruct = syn::parse_quote! {
+            #[derive(Serialize, Deserialize)]
+            pub struct Point {
+                #[serde(rename = "x y")]
+                pub xy: u32,
+            }
+        };
+        let ts_interface = convert_struct_to_ts_interface(&item, &[], &TypeMapping::default());
+        assert_eq!(ts_interface["fields"][0]["name"], "\"x y\"");
+
+        let item: ItemEnum = syn::parse_quote! {
+            #[derive(Serialize, Deserialize)]
+            pub enum Shape {
+                Point {
+                    #[serde(rename = "x y")]
+                    xy: u32,
+                },
+            }
+        };
+        let ts_enum = convert_enum_to_ts_enum(&item, &[], &TypeMapping::default());
+        assert_eq!(
+            ts_enum["variants"][0]["ts_type"],
+            "{ Point: { \"x y\": number } }"
+        );
+    }
+
+    #[test]
+    fn test_convert_struct_to_ts_interface_optional_fields() {
+        let item: ItemStruct = syn::parse_quote! {
+            #[derive(Serialize, Deserialize)]
+            struct Profile {
+                name: String,
+                nickname: Option<String>,
+                tags: Option<Vec<u32>>,
+                bio: Option<Option<String>>,
+            }
+        };
+        let ts_interface = convert_struct_to_ts_interface(&item, &[], &TypeMapping::default());
+        let fields: Vec<(&str, &str, bool)> = ts_interface["fields"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|field| {
+                (
+                    field["name"].as_str().unwrap(),
+                    field["type"].as_str().unwrap(),
+                    field["optional"].as_bool().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                ("name", "string", false),
+                ("nickname", "string", true),
+                ("tags", "number[]", true),
+                ("bio", "string", true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_option_repr() {
+        let item: ItemStruct = syn::parse_quote! {
+            #[derive(Serialize, Deserialize)]
+            struct Profile {
+                nickname: Option<String>,
+                bio: Option<Option<String>>,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                avatar: Option<String>,
+            }
+        };
+        let rust_code = r#"
+            #[tauri::command]
+            fn search(query: Option<String>) -> Option<Vec<Option<u32>>> {
+                None
+            }
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let cases = [
+            (
+                OptionRepr::Null,
+                [
+                    ("nickname", "string | null", false),
+                    ("bio", "string | null", false),
+                    ("avatar", "string | null", true),
+                ],
+                "query: string | null",
+                "(number | null)[] | null",
+            ),
+            (
+                OptionRepr::Undefined,
+                [
+                    ("nickname", "string | undefined", false),
+                    ("bio", "string | undefined", false),
+                    ("avatar", "string | undefined", true),
+                ],
+                "query: string | undefined",
+                "(number | undefined)[] | undefined",
+            ),
+            (
+                OptionRepr::Optional,
+                [
+                    ("nickname", "string", true),
+                    ("bio", "string", true),
+                    ("avatar", "string", true),
+                ],
+                "query: string | undefined",
+                "(number | undefined)[] | undefined",
+            ),
+        ];
+        for (option_repr, expected_fields, expected_arg, expected_return_type) in cases {
+            let mapping = TypeMapping {
+                option_repr,
+                ..Default::default()
+            };
+            let ts_interface = convert_struct_to_ts_interface(&item, &[], &mapping);
+            let fields: Vec<(&str, &str, bool)> = ts_interface["fields"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|field| {
+                    (
+                        field["name"].as_str().unwrap(),
+                        field["type"].as_str().unwrap(),
+                        field["optional"].as_bool().unwrap(),
+                    )
+                })
+                .collect();
+            assert_eq!(fields, expected_fields, "{option_repr:?}");
+
+            let commands = extract_tauri_commands(&syntax.items, &[], &mapping);
+            assert_eq!(commands[0]["args"][0], expected_arg, "{option_repr:?}");
+            assert_eq!(
+                commands[0]["return_type"], expected_return_type,
+                "{option_repr:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_extract_tauri_commands_option_args_keep_undefined() {
+        let rust_code = r#"
+            #[tauri::command]
+            fn search(query: Option<String>) -> Option<u32> {
+                None
+            }
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let commands = extract_tauri_commands(&syntax.items, &[], &TypeMapping::default());
+        assert_eq!(commands[0]["args"][0], "query: string | undefined");
+        assert_eq!(commands[0]["return_type"], "number | undefined");
+    }
+
+    #[test]
+    fn test_convert_enum_to_ts_enum_tagged() {
+        let item: ItemEnum = syn::parse_quote! {
+            #[serde(tag = "type")]
+            enum Shape {
+                Empty,
+                Circle { radius: f64 },
+                Dot(Point),
+            }
+        };
+        let ts_enum =
+            convert_enum_to_ts_enum(&item, &["Point".to_string()], &TypeMapping::default());
+        assert_eq!(ts_enum["representation"], "internal");
+        let ts_types: Vec<&str> = ts_enum["variants"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|variant| variant["ts_type"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            ts_types,
+            vec![
+                r#"{ type: "Empty" }"#,
+                r#"{ type: "Circle"; radius: number }"#,
+                r#"{ type: "Dot" } & Point"#,
+            ]
+        );
+
+        let item: ItemEnum = syn::parse_quote! {
+            #[serde(tag = "kind", content = "data-value")]
+            enum Request {
+                Ping,
+                Echo(String),
+                Resize(u32, u32),
+            }
+        };
+        let ts_enum = convert_enum_to_ts_enum(&item, &[], &TypeMapping::default());
+        assert_eq!(ts_enum["representation"], "adjacent");
+        let ts_types: Vec<&str> = ts_enum["variants"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|variant| variant["ts_type"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            ts_types,
+            vec![
+                r#"{ kind: "Ping" }"#,
+                r#"{ kind: "Echo"; "data-value": string }"#,
+                r#"{ kind: "Resize"; "data-value": [number, number] }"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_convert_enum_to_ts_enum_externally_tagged() {
+        let item: ItemEnum = syn::parse_quote! {
+            enum Message {
+                Quit,
+                Move { x: i32, y: i32 },
+                Write(String),
+                ChangeColor(i32, i32, i32),
+            }
+        };
+        let ts_enum = convert_enum_to_ts_enum(&item, &[], &TypeMapping::default());
+        let ts_types: Vec<&str> = ts_enum["variants"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|variant| variant["ts_type"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            ts_types,
+            vec![
+                r#""Quit""#,
+                "{ Move: { x: number; y: number } }",
+                "{ Write: string }",
+                "{ ChangeColor: [number, number, number] }",
+            ]
+        );
+
+        let item: ItemEnum = syn::parse_quote! {
+            enum Color { Red, Green }
+        };
+        let ts_enum = convert_enum_to_ts_enum(&item, &[], &TypeMapping::default());
+        assert_eq!(ts_enum["variants"][0]["ts_type"], r#""Red""#);
+        assert_eq!(ts_enum["variants"][1]["ts_type"], r#""Green""#);
+    }
+
+    #[test]
+    fn test_convert_enum_to_ts_enum_rename_all() {
+        let item: ItemEnum = syn::parse_quote! {
+            #[serde(rename_all = "kebab-case")]
+            enum Status {
+                InProgress,
+                #[serde(rename = "done!")]
+                Done,
+                OnHold { resume_at: u32 },
+                #[serde(rename_all = "camelCase")]
+                Blocked { blocked_by: String },
+            }
+        };
+        let ts_enum = convert_enum_to_ts_enum(&item, &[], &TypeMapping::default());
+        let ts_types: Vec<&str> = ts_enum["variants"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|variant| variant["ts_type"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            ts_types,
+            vec![
+                r#""in-progress""#,
+                r#""done!""#,
+                r#"{ "on-hold": { resume_at: number } }"#,
+                r#"{ blocked: { blockedBy: string } }"#,
+            ]
+        );
+
+        let item: ItemEnum = syn::parse_quote! {
+            #[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+            enum Event { UserCreated { id: u32 } }
+        };
+        let ts_enum = convert_enum_to_ts_enum(&item, &[], &TypeMapping::default());
+        assert_eq!(
+            ts_enum["variants"][0]["ts_type"],
+            r#"{ type: "USER_CREATED"; id: number }"#
+        );
+    }
+
+    #[test]
+    fn test_convert_enum_to_ts_enum_untagged() {
+        let item: ItemEnum = syn::parse_quote! {
+            #[serde(untagged)]
+            enum Value {
+                Nothing,
+                Text(String),
+                Pair(u32, String),
+                Position { x: f64, y: f64 },
+                Nested(Point),
+            }
+        };
+        let ts_enum =
+            convert_enum_to_ts_enum(&item, &["Point".to_string()], &TypeMapping::default());
+        assert_eq!(ts_enum["representation"], "untagged");
+        let ts_types: Vec<&str> = ts_enum["variants"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|variant| variant["ts_type"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            ts_types,
+            vec![
+                "null",
+                "string",
+                "[number, string]",
+                "{ x: number; y: number }",
+                "Point",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_tauri_commands_is_async() {
+        let rust_code = r#"
+            #[tauri::command]
+            async fn webview_window_command(window: tauri::WebviewWindow) -> Result<String, String> {
+                todo!()
+            }
+
+            #[tauri::command]
+            fn greet(name: &str) -> String { todo!() }
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let commands = extract_tauri_commands(&syntax.items, &[], &TypeMapping::default());
+
+        assert_eq!(commands[0]["name"], "webview_window_command");
+        assert_eq!(commands[0]["is_async"], true);
+        assert_eq!(commands[1]["name"], "greet");
+        assert_eq!(commands[1]["is_async"], false);
+    }
+
+    #[test]
+    fn test_extract_tauri_commands_dropped_args() {
+        let rust_code = r#"
+            #[derive(Serialize)]
+            struct Secret { value: String }
+
+            #[tauri::command]
+            fn store_secrets(name: String, secrets: Vec<Secret>) {}
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let extracted_types =
+            extract_and_convert_types(&syntax.items, "test_file", &TypeMapping::default());
+        let commands =
+            extract_tauri_commands(&syntax.items, &extracted_types, &TypeMapping::default());
+
+        assert_eq!(commands[0]["args"], serde_json::json!(["name: string"]));
+        let dropped_args: Vec<DroppedArgument> =
+            serde_json::from_value(commands[0]["dropped_args"].clone()).unwrap();
+        assert_eq!(
+            dropped_args,
+            vec![DroppedArgument {
+                command_name: "store_secrets".to_string(),
+                arg_name: "secrets".to_string(),
+                arg_type: "T.Secret[]".to_string(),
+                type_name: "Secret".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_extract_tauri_commands_result_with_nested_ok_type() {
+        let rust_code = r#"
+            #[derive(Serialize, Deserialize)]
+            struct User { name: String }
+
+            #[derive(Serialize)]
+            struct Product { id: u32 }
+
+            #[derive(Serialize)]
+            struct MyError { message: String }
+
+            #[derive(Deserialize)]
+            struct Draft { body: String }
+
+            #[tauri::command]
+            fn list_users() -> Result<Vec<User>, MyError> { todo!() }
+
+            #[tauri::command]
+            fn find_product(id: u32) -> Result<Option<Product>, String> { todo!() }
+
+            #[tauri::command]
+            fn users_by_name() -> Result<HashMap<String, User>, MyError> { todo!() }
+
+            #[tauri::command]
+            fn list_drafts() -> Result<Vec<Draft>, String> { todo!() }
+
+            #[tauri::command]
+            fn find_draft() -> Result<Option<Draft>, String> { todo!() }
+
+            #[tauri::command]
+            fn drafts_by_name() -> Result<HashMap<String, Draft>, String> { todo!() }
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let extracted_types =
+            extract_and_convert_types(&syntax.items, "test_file", &TypeMapping::default());
+        let commands =
+            extract_tauri_commands(&syntax.items, &extracted_types, &TypeMapping::default());
+        let signature = |name: &str| {
+            let command = commands.iter().find(|c| c["name"] == name).unwrap();
+            (
+                command["return_type"].as_str().unwrap().to_string(),
+                command["error_type"].as_str().unwrap().to_string(),
+            )
+        };
+
+        assert_eq!(
+            signature("list_users"),
+            ("T.User[]".to_string(), "T.MyError".to_string())
+        );
+        assert_eq!(
+            signature("find_product"),
+            ("T.Product | undefined".to_string(), "string".to_string())
+        );
+        assert_eq!(
+            signature("users_by_name"),
+            (
+                "Record<string, T.User>".to_string(),
+                "T.MyError".to_string()
+            )
+        );
+
+        // Ok 側にネストされた型が Serializable でない場合は unknown に置き換える
+        for name in ["list_drafts", "find_draft", "drafts_by_name"] {
+            assert_eq!(
+                signature(name),
+                ("unknown".to_string(), "string".to_string()),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_extract_tauri_commands_nested_result() {
+        let rust_code = r#"
+            #[derive(Serialize)]
+            struct User { name: String }
+
+            #[derive(Deserialize)]
+            struct InnerErr { code: u32 }
+
+            #[derive(Serialize)]
+            struct OuterErr { message: String }
+
+            #[tauri::command]
+            fn load_name() -> Result<Result<String, InnerErr>, OuterErr> { todo!() }
+
+            #[tauri::command]
+            fn load_user() -> Result<Result<User, InnerErr>, OuterErr> { todo!() }
+
+            #[tauri::command]
+            fn reset() -> Result<Result<(), InnerErr>, OuterErr> { todo!() }
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let extracted_types =
+            extract_and_convert_types(&syntax.items, "test_file", &TypeMapping::default());
+        let commands =
+            extract_tauri_commands(&syntax.items, &extracted_types, &TypeMapping::default());
+
+        // 内側のエラー型が Serializable でなくても、最も内側の Ok 型で判定する
+        assert_eq!(commands[0]["return_type"], "string");
+        assert_eq!(commands[1]["return_type"], "T.User");
+        assert_eq!(commands[2]["return_type"], "void");
+        for command in &commands {
+            assert_eq!(command["error_type"], "T.OuterErr");
+        }
+    }
+
+    #[test]
+    fn test_extract_tauri_commands_reference_args() {
+        let rust_code = r#"
+            #[tauri::command]
+            fn rename<'a>(name: &mut String, label: &'a str, ids: &[u32], nested: &&str) {}
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let commands = extract_tauri_commands(&syntax.items, &[], &TypeMapping::default());
+
+        assert_eq!(
+            commands[0]["args"],
+            serde_json::json!([
+                "name: string",
+                "label: string",
+                "ids: number[]",
+                "nested: string"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_extract_tauri_commands_trait_return_types() {
+        let rust_code = r#"
+            #[tauri::command]
+            fn summary() -> impl Serialize { todo!() }
+
+            #[tauri::command]
+            fn shape() -> Box<dyn Shape> { todo!() }
+
+            #[tauri::command]
+            fn shapes() -> Result<Vec<Box<dyn Shape + Send>>, String> { todo!() }
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let commands = extract_tauri_commands(&syntax.items, &[], &TypeMapping::default());
+
+        // 具体的な型が分からないため any ではなく unknown にする
+        assert_eq!(commands[0]["return_type"], "unknown");
+        assert_eq!(commands[1]["return_type"], "unknown");
+        assert_eq!(commands[2]["return_type"], "unknown[]");
+    }
+
+    #[test]
+    fn test_extract_tauri_commands_error_type() {
+        let rust_code = r#"
+            #[derive(Serialize, Deserialize)]
+            struct User { name: String }
+
+            #[derive(Serialize)]
+            struct MyError { message: String }
+
+            #[derive(Deserialize)]
+            struct InternalError { code: u32 }
+
+            #[tauri::command]
+            fn get_user() -> Result<User, MyError> { todo!() }
+
+            #[tauri::command]
+            fn delete_user() -> Result<(), String> { todo!() }
+
+            #[tauri::command]
+            fn reset_user() -> Result<User, InternalError> { todo!() }
+
+            #[tauri::command]
+            fn count_users() -> u32 { 0 }
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let extracted_types =
+            extract_and_convert_types(&syntax.items, "test_file", &TypeMapping::default());
+        let commands =
+            extract_tauri_commands(&syntax.items, &extracted_types, &TypeMapping::default());
+
+        assert_eq!(commands[0]["return_type"], "T.User");
+        assert_eq!(commands[0]["error_type"], "T.MyError");
+        assert_eq!(commands[1]["error_type"], "string");
+        assert_eq!(commands[2]["error_type"], "unknown"); // InternalError is not Serializable
+        // エラー型が Serializable でなくても、戻り値の型は Ok 側だけで判定する
+        assert_eq!(commands[2]["return_type"], "T.User");
+        assert!(commands[3]["error_type"].is_null());
+    }
+
+    #[test]
+    fn test_extract_commands_and_events_in_impl_blocks() {
+        let rust_code = r#"
+            struct Commands;
+
+            impl Commands {
+                #[tauri::command]
+                fn greet(name: String, app: tauri::AppHandle) -> String {
+                    app.emit("greeted", name.clone()).unwrap();
+                    name
+                }
+
+                fn not_a_command() {}
+            }
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+
+        let commands = extract_tauri_commands(&syntax.items, &[], &TypeMapping::default());
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0]["name"], "greet");
+        assert_eq!(commands[0]["args"][0], "name: string");
+
+        let (global_events, _) = extract_events(&syntax.items, &[], &TypeMapping::default());
+        assert_eq!(global_events.len(), 1);
+        assert_eq!(global_events[0].event_name, "greeted");
+    }
+
+    #[test]
+    fn test_extract_from_nested_inline_modules() {
+        let rust_code = r#"
+            mod handlers {
+                pub mod users {
+                    use tauri::AppHandle as Handle;
+
+                    #[derive(Serialize, Deserialize)]
+                    pub struct User {
+                        pub name: String,
+                    }
+
+                    #[tauri::command]
+                    pub fn get_user(app: Handle) -> User {
+                        app.emit("user-loaded", ()).unwrap();
+                        User { name: String::new() }
+                    }
+                }
+            }
+
+            mod declared_elsewhere;
+
+            #[cfg(test)]
+            mod tests {
+                #[tauri::command]
+                fn test_only() {}
+            }
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+
+        let extracted_types =
+            extract_and_convert_types(&syntax.items, "test_file", &TypeMapping::default());
+        assert_eq!(extracted_types.len(), 1);
+        assert_eq!(extracted_types[0].name, "User");
+        assert!(extracted_types[0].is_serializable);
+
+        let commands =
+            extract_tauri_commands(&syntax.items, &extracted_types, &TypeMapping::default());
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0]["name"], "get_user");
+        assert_eq!(commands[0]["args"], serde_json::json!([])); // aliased AppHandle is ignored
+        assert_eq!(commands[0]["return_type"], "T.User");
+
+        let (global_events, _) =
+            extract_events(&syntax.items, &extracted_types, &TypeMapping::default());
+        assert_eq!(global_events[0].event_name, "user-loaded");
+    }
+
+    #[test]
+    fn test_extract_events_from_handle_variables() {
+        let rust_code = r#"
+            use tauri::{AppHandle, Emitter, Manager, State};
+
+            struct AppState {
+                window: tauri::WebviewWindow,
+                name: String,
+            }
+
+            struct Notifier {
+                handle: AppHandle,
+            }
+
+            impl Notifier {
+                fn notify(&self) {
+                    self.handle.emit("from-self", ()).unwrap();
+                }
+            }
+
+            #[tauri::command]
+            fn cloned(app: AppHandle, state: State<'_, AppState>) {
+                let handle = app.clone();
+                handle.emit("from-clone", ()).unwrap();
+
+                let main = handle.get_webview_window("main").unwrap();
+                main.emit("from-window", ()).unwrap();
+
+                state.window.emit("from-state", ()).unwrap();
+
+                let handle = String::new();
+                handle.emit("not-a-handle", ()).unwrap();
+                state.name.emit("not-a-handle", ()).unwrap();
+            }
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let (global_events, _) = extract_events(&syntax.items, &[], &TypeMapping::default());
+
+        let event_names: Vec<&str> = global_events
+            .iter()
+            .map(|event| event.event_name.as_str())
+            .collect();
+        assert_eq!(
+            event_names,
+            vec!["from-self", "from-clone", "from-window", "from-state"]
+        );
+    }
+
+    #[test]
+    fn test_extract_events_from_local_bindings() {
+        let rust_code = r#"
+            #[derive(Clone, Serialize)]
+            pub struct MainPayload { pub message: String }
+
+            #[tauri::command]
+            fn notify(app: tauri::AppHandle) {
+                let payload = MainPayload { message: String::new() };
+                app.emit("struct-literal", payload).unwrap();
+
+                let annotated: MainPayload = build_payload();
+                app.emit_to("main", "annotated", annotated).unwrap();
+
+                let count = 3u64;
+                app.emit("count", count).unwrap();
+
+                let unknown = build_payload();
+                app.emit("unknown", unknown).unwrap();
+            }
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let extracted_types =
+            extract_and_convert_types(&syntax.items, "test_file", &TypeMapping::default());
+        let (global_events, window_events) =
+            extract_events(&syntax.items, &extracted_types, &TypeMapping::default());
+
+        let payload_types: Vec<(&str, &str)> = global_events
+            .iter()
+            .map(|event| (event.event_name.as_str(), event.payload_type.as_str()))
+            .collect();
+        assert_eq!(
+            payload_types,
+            vec![
+                ("struct-literal", "T.MainPayload"),
+                ("count", "number"),
+                ("unknown", "any"),
+            ]
+        );
+        assert_eq!(window_events[0].payload_type, "T.MainPayload");
+    }
+
+    #[test]
+    fn test_extract_events_borrowed_cloned_and_constructed_payloads() {
+        let rust_code = r#"
+            #[derive(Clone, Serialize)]
+            pub struct MainPayload { pub message: String }
+
+            impl MainPayload {
+                fn new() -> Self {
+                    Self { message: String::new() }
+                }
+            }
+
+            #[tauri::command]
+            fn notify(app: tauri::AppHandle, payload: MainPayload) {
+                app.emit("borrowed", &payload).unwrap();
+                app.emit("cloned", payload.clone()).unwrap();
+                app.emit("constructed", MainPayload::new()).unwrap();
+                app.emit_to("main", "constructed-path", crate::MainPayload::new()).unwrap();
+
+                let local = MainPayload::new();
+                app.emit("constructed-local", &local).unwrap();
+            }
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let extracted_types =
+            extract_and_convert_types(&syntax.items, "test_file", &TypeMapping::default());
+        let (global_events, window_events) =
+            extract_events(&syntax.items, &extracted_types, &TypeMapping::default());
+
+        for event in &global_events {
+            assert_eq!(event.payload_type, "T.MainPayload", "{}", event.event_name);
+        }
+        assert_eq!(global_events.len(), 4);
+        assert_eq!(window_events[0].payload_type, "T.MainPayload");
+    }
+
+    #[test]
+    fn test_extract_events_generic_struct_literal_payload() {
+        let rust_code = r#"
+            #[derive(Clone, Serialize)]
+            pub struct Wrapper<T> { pub value: T }
+
+            #[tauri::command]
+            fn notify(app: tauri::AppHandle) {
+                app.emit("wrapped", Wrapper::<u32> { value: 1 }).unwrap();
+                app.emit("inferred", Wrapper { value: 1 }).unwrap();
+            }
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let extracted_types =
+            extract_and_convert_types(&syntax.items, "test_file", &TypeMapping::default());
+        let (global_events, _) =
+            extract_events(&syntax.items, &extracted_types, &TypeMapping::default());
+
+        assert_eq!(global_events[0].payload_type, "T.Wrapper<number>");
+        assert_eq!(global_events[1].payload_type, "T.Wrapper");
+    }
+
+    #[test]
+    fn test_extract_tauri_commands_rename_all() {
+        let rust_code = r#"
+            #[tauri::command]
+            fn default_case(user_name: String) {}
+
+            #[tauri::command(rename_all = "snake_case")]
+            fn snake_case(user_name: String, id: u32) {}
+
+            #[tauri::command(rename_all = "camelCase")]
+            fn camel_case(user_name: String) {}
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let commands = extract_tauri_commands(&syntax.items, &[], &TypeMapping::default());
+
+        assert_eq!(commands[0]["args"][0], "userName: string");
+        assert_eq!(commands[0]["invoke_args"][0], "userName: userName");
+
+        assert_eq!(commands[1]["args"][0], "userName: string");
+        assert_eq!(commands[1]["invoke_args"][0], "user_name: userName");
+        assert_eq!(commands[1]["invoke_args"][1], "id: id");
+
+        assert_eq!(commands[2]["args"][0], "userName: string");
+        assert_eq!(commands[2]["invoke_args"][0], "userName: userName");
+    }
 
     #[test]
-    fn test_extract_and_convert_types() {
+    fn test_extract_tauri_commands_attribute_args() {
         let rust_code = r#"
-            #[derive(Serialize, Deserialize)]
-            struct MyStruct {
-                field1: String,
-            }
+            #[tauri::command(rename_all = "camelCase")]
+            fn camel_case(user_name: String) {}
+
+            #[tauri::command(async, rename_all = "snake_case")]
+            fn run_in_background(user_name: String) {}
+
+            #[command(root = "crate", rename_all = "snake_case")]
+            fn custom_root(user_name: String) {}
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let commands = extract_tauri_commands(&syntax.items, &[], &TypeMapping::default());
+
+        // 引数付きの属性でもコマンドとして検出され、関数名がそのまま使われる
+        assert_eq!(commands.len(), 3);
+        assert_eq!(commands[0]["name"], "camel_case");
+        assert_eq!(commands[0]["invoke_args"][0], "userName: userName");
+        assert_eq!(commands[0]["is_async"], false);
+
+        // キーワードの async があっても後続の rename_all は読み取られる
+        assert_eq!(commands[1]["name"], "run_in_background");
+        assert_eq!(commands[1]["invoke_args"][0], "user_name: userName");
+        assert_eq!(commands[1]["is_async"], true);
+
+        assert_eq!(commands[2]["name"], "custom_root");
+        assert_eq!(commands[2]["invoke_args"][0], "user_name: userName");
+    }
+
+    #[test]
+    fn test_extract_tauri_commands_path_args() {
+        let rust_code = r#"
+            use std::path::{Path, PathBuf};
+
+            #[tauri::command]
+            fn open_file(path: PathBuf, base: &Path) -> Vec<PathBuf> {}
+
+            #[tauri::command]
+            fn list_files(paths: Vec<std::path::PathBuf>) -> Option<PathBuf> {}
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let commands = extract_tauri_commands(&syntax.items, &[], &TypeMapping::default());
+
+        assert_eq!(commands[0]["args"][0], "path: string");
+        assert_eq!(commands[0]["args"][1], "base: string");
+        assert_eq!(commands[0]["return_type"], "string[]");
+        assert_eq!(commands[1]["args"][0], "paths: string[]");
+        assert_eq!(commands[1]["return_type"], "string | undefined");
+    }
 
+    #[test]
+    fn test_byte_buffers_in_commands_and_struct_fields() {
+        let rust_code = r#"
             #[derive(Serialize, Deserialize)]
-            enum MyEnum {
-                VariantA,
+            pub struct Attachment {
+                pub data: Vec<u8>,
+                pub raw: bytes::Bytes,
             }
 
-            struct NotConvertedStruct;
-
-            #[derive(Serialize)]
-            struct OnlySerializable;
+            #[tauri::command]
+            fn read_file(path: String) -> Result<Vec<u8>, String> {}
 
-            #[derive(Deserialize)]
-            struct OnlyDeserializable;
+            #[tauri::command]
+            fn write_file(data: &[u8], extra: Vec<u8>) {}
 
-            struct NoDerive;
+            #[tauri::command]
+            fn read_bytes() -> bytes::Bytes {}
         "#;
         let syntax = syn::parse_file(rust_code).unwrap();
-        let extracted_types = extract_and_convert_types(&syntax.items, "test_file");
+        let extracted_types =
+            extract_and_convert_types(&syntax.items, "test_file", &TypeMapping::default());
+        let commands =
+            extract_tauri_commands(&syntax.items, &extracted_types, &TypeMapping::default());
 
-        assert_eq!(extracted_types.len(), 6);
+        // 構造体のフィールドは serde により数値配列としてシリアライズされる
+        let fields = &extracted_types[0].ts_interface["fields"];
+        assert_eq!(fields[0]["type"], "number[]");
+        assert_eq!(fields[1]["type"], "number[]");
 
-        let my_struct = extracted_types
-            .iter()
-            .find(|info| info.name == "MyStruct")
-            .unwrap();
-        assert!(my_struct.is_serializable);
-        assert!(my_struct.is_deserializable);
+        // コマンドの引数・戻り値はバイナリとして Uint8Array になる
+        assert_eq!(commands[0]["return_type"], "Uint8Array");
+        assert_eq!(commands[1]["args"][0], "data: Uint8Array");
+        assert_eq!(commands[1]["args"][1], "extra: Uint8Array");
+        assert_eq!(commands[2]["return_type"], "Uint8Array");
 
-        let my_enum = extracted_types
-            .iter()
-            .find(|info| info.name == "MyEnum")
-            .unwrap();
-        assert!(my_enum.is_serializable);
-        assert!(my_enum.is_deserializable);
+        // Tauri v1 にはバイナリの IPC がないため、コマンドでも数値配列になる
+        let tauri_v1 = TypeMapping {
+            tauri_version: TauriVersion::V1,
+            ..Default::default()
+        };
+        let commands = extract_tauri_commands(&syntax.items, &extracted_types, &tauri_v1);
+        assert_eq!(commands[0]["return_type"], "number[]");
+        assert_eq!(commands[1]["args"][0], "data: number[]");
+        assert_eq!(commands[1]["args"][1], "extra: number[]");
+        assert_eq!(commands[2]["return_type"], "number[]");
+    }
 
-        let not_converted_struct = extracted_types
-            .iter()
-            .find(|info| info.name == "NotConvertedStruct")
-            .unwrap();
-        assert!(!not_converted_struct.is_serializable);
-        assert!(!not_converted_struct.is_deserializable);
+    #[test]
+    fn test_bigint_for_64_bit_integers() {
+        let rust_code = r#"
+            #[derive(Serialize, Deserialize)]
+            pub struct Stats {
+                pub total: u64,
+                pub delta: Option<i64>,
+                pub count: u32,
+                pub initial: char,
+                pub names: HashMap<u64, String>,
+            }
 
-        let only_serializable = extracted_types
-            .iter()
-            .find(|info| info.name == "OnlySerializable")
-            .unwrap();
-        assert!(only_serializable.is_serializable);
-        assert!(!only_serializable.is_deserializable);
+            #[tauri::command]
+            fn get_size(id: u128) -> Result<i128, String> {}
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let mapping = TypeMapping {
+            bigint_for_64: true,
+            ..Default::default()
+        };
+        let extracted_types = extract_and_convert_types(&syntax.items, "test_file", &mapping);
+        let commands = extract_tauri_commands(&syntax.items, &extracted_types, &mapping);
 
-        let only_deserializable = extracted_types
-            .iter()
-            .find(|info| info.name == "OnlyDeserializable")
-            .unwrap();
-        assert!(!only_deserializable.is_serializable);
-        assert!(only_deserializable.is_deserializable);
+        let fields = &extracted_types[0].ts_interface["fields"];
+        assert_eq!(fields[0]["type"], "bigint");
+        assert_eq!(fields[1]["type"], "bigint");
+        assert_eq!(fields[2]["type"], "number");
+        assert_eq!(fields[3]["type"], "string");
+        // Record<bigint, _> は TypeScript で使えないため、マップのキーは number のままにする
+        assert_eq!(fields[4]["type"], "Record<number, string>");
+        assert_eq!(commands[0]["args"][0], "id: bigint");
+        assert_eq!(commands[0]["return_type"], "bigint");
 
-        let no_derive = extracted_types
+        // 既定では number のまま
+        let extracted_types =
+            extract_and_convert_types(&syntax.items, "test_file", &TypeMapping::default());
+        let commands =
+            extract_tauri_commands(&syntax.items, &extracted_types, &TypeMapping::default());
+        let fields = &extracted_types[0].ts_interface["fields"];
+        assert_eq!(fields[0]["type"], "number");
+        assert_eq!(fields[1]["type"], "number");
+        assert_eq!(commands[0]["args"][0], "id: number");
+        assert_eq!(commands[0]["return_type"], "number");
+    }
+
+    #[test]
+    fn test_extract_and_convert_types_const_items() {
+        let rust_code = r#"
+            pub const MAX: u32 = 100;
+            pub const MIN_OFFSET: i64 = -5;
+            pub const RATIO: f64 = 0.5;
+            pub const APP_NAME: &str = "tauria";
+            pub const ENABLED: bool = true;
+            const PRIVATE: u32 = 1;
+            pub const COMPUTED: u32 = MAX * 2;
+            pub const DEFAULT_SIZE: Size = Size(1);
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let extracted_types =
+            extract_and_convert_types(&syntax.items, "test_file", &TypeMapping::default());
+
+        let values: Vec<(&str, &str)> = extracted_types
             .iter()
-            .find(|info| info.name == "NoDerive")
-            .unwrap();
-        assert!(!no_derive.is_serializable);
-        assert!(!no_derive.is_deserializable);
+            .map(|info| {
+                assert_eq!(info.ts_interface["type"], "const");
+                (
+                    info.name.as_str(),
+                    info.ts_interface["value"].as_str().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                ("MAX", "100"),
+                ("MIN_OFFSET", "-5"),
+                ("RATIO", "0.5"),
+                ("APP_NAME", "\"tauria\""),
+                ("ENABLED", "true"),
+            ]
+        );
     }
 
     #[test]
@@ -926,7 +3853,8 @@ mod tests {
         "#;
         let syntax = syn::parse_file(rust_code).unwrap();
         let defined_types = vec![];
-        let functions = extract_tauri_commands(&syntax.items, &defined_types);
+        let functions =
+            extract_tauri_commands(&syntax.items, &defined_types, &TypeMapping::default());
 
         assert_eq!(functions.len(), 4);
 
@@ -997,6 +3925,107 @@ mod tests {
         assert!(!is_ignored_tauri_type(&ty13, &aliases));
     }
 
+    #[test]
+    fn test_is_ignored_tauri_injected_types() {
+        let rust_code = r#"
+            use tauri::{Webview as MyWebview, ipc::Request as MyRequest};
+            use tauri::ipc::{CommandScope as MyCommandScope, GlobalScope as MyGlobalScope};
+            struct Webview {}
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let aliases = extract_use_aliases(&syntax.items);
+
+        // Webview
+        let ty1: Type = syn::parse_str("tauri::Webview").unwrap();
+        assert!(is_ignored_tauri_type(&ty1, &aliases));
+        let ty2: Type = syn::parse_str("&tauri::Webview").unwrap();
+        assert!(is_ignored_tauri_type(&ty2, &aliases));
+        let ty3: Type = syn::parse_str("MyWebview").unwrap();
+        assert!(is_ignored_tauri_type(&ty3, &aliases));
+
+        // Request
+        let ty4: Type = syn::parse_str("tauri::ipc::Request<'_>").unwrap();
+        assert!(is_ignored_tauri_type(&ty4, &aliases));
+        let ty5: Type = syn::parse_str("MyRequest<'_>").unwrap();
+        assert!(is_ignored_tauri_type(&ty5, &aliases));
+
+        // CommandScope
+        let ty6: Type = syn::parse_str("tauri::ipc::CommandScope<ScopeEntry>").unwrap();
+        assert!(is_ignored_tauri_type(&ty6, &aliases));
+        let ty7: Type = syn::parse_str("MyCommandScope<ScopeEntry>").unwrap();
+        assert!(is_ignored_tauri_type(&ty7, &aliases));
+
+        // GlobalScope
+        let ty8: Type = syn::parse_str("tauri::ipc::GlobalScope<ScopeEntry>").unwrap();
+        assert!(is_ignored_tauri_type(&ty8, &aliases));
+        let ty9: Type = syn::parse_str("MyGlobalScope<ScopeEntry>").unwrap();
+        assert!(is_ignored_tauri_type(&ty9, &aliases));
+
+        // 無関係な型
+        let ty10: Type = syn::parse_str("Webview").unwrap();
+        assert!(!is_ignored_tauri_type(&ty10, &aliases));
+        let ty11: Type = syn::parse_str("my_tauri::ipc::Request").unwrap();
+        assert!(!is_ignored_tauri_type(&ty11, &aliases));
+    }
+
+    #[test]
+    fn test_is_ignored_tauri_type_state_with_lifetime() {
+        let rust_code = r#"
+            use tauri::State;
+            use tauri::State as MyState;
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let aliases = extract_use_aliases(&syntax.items);
+
+        for ty in [
+            "tauri::State<'_, Database>",
+            "tauri::State<'a, Database>",
+            "State<'_, Database>",
+            "State<'a, Database>",
+            "MyState<'_, Database>",
+            "&tauri::State<'_, Database>",
+        ] {
+            let ty: Type = syn::parse_str(ty).unwrap();
+            assert!(is_ignored_tauri_type(&ty, &aliases), "{ty:?}");
+        }
+
+        // 前方一致するだけの別の型は無視しない
+        let ty: Type = syn::parse_str("tauri::StateManager").unwrap();
+        assert!(!is_ignored_tauri_type(&ty, &aliases));
+    }
+
+    #[test]
+    fn test_extract_tauri_commands_complex_state_command() {
+        let rust_code = r#"
+            #[derive(serde::Serialize)]
+            struct CustomResponse {
+                message: String,
+                other_val: usize,
+            }
+
+            #[tauri::command]
+            async fn complex_state_command(
+                window: tauri::Window,
+                number: usize,
+                database: tauri::State<'_, Database>,
+            ) -> Result<CustomResponse, String> {
+                todo!()
+            }
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let extracted_types =
+            extract_and_convert_types(&syntax.items, "lib", &TypeMapping::default());
+        let commands =
+            extract_tauri_commands(&syntax.items, &extracted_types, &TypeMapping::default());
+
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0]["args"], serde_json::json!(["number: number"]));
+        assert_eq!(
+            commands[0]["invoke_args"],
+            serde_json::json!(["number: number"])
+        );
+    }
+
     #[test]
     fn test_is_tauri_ipc_response() {
         let rust_code = r#"use tauri::ipc::Response as IpcResponse;"#;
@@ -1093,6 +4122,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_extract_doc_comments_block_comment() {
+        let item_code = r#"
+            /**
+             * @brief Updates a user.
+             *
+             * @param user_name The name of the user.
+             * @return The updated user.
+             */
+            #[tauri::command]
+            fn update_user(user_name: String) -> String {}
+        "#;
+        let syntax = syn::parse_file(item_code).unwrap();
+        let Item::Fn(func) = &syntax.items[0] else {
+            panic!("Expected a function");
+        };
+        assert_eq!(
+            extract_doc_comments(&func.attrs),
+            "Updates a user.\n\n@param user_name The name of the user.\n@returns The updated user."
+        );
+
+        // コマンドの @param は TypeScript の引数名に合わせる
+        let commands = extract_tauri_commands(&syntax.items, &[], &TypeMapping::default());
+        assert_eq!(
+            commands[0]["doc_comment"],
+            "Updates a user.\n\n@param userName The name of the user.\n@returns The updated user."
+        );
+    }
+
     #[test]
     fn test_type_to_ts() {
         let defined_types = vec![
@@ -1103,7 +4161,12 @@ mod tests {
 
         let parse_and_convert = |rust_type_str: &str, is_tauri_command: bool| -> String {
             let ty: Type = syn::parse_str(rust_type_str).unwrap();
-            type_to_ts(&ty, &defined_types, is_tauri_command)
+            type_to_ts(
+                &ty,
+                &defined_types,
+                is_tauri_command,
+                &TypeMapping::default(),
+            )
         };
 
         // Basic types
@@ -1112,6 +4175,14 @@ mod tests {
         assert_eq!(parse_and_convert("u32", false), "number");
         assert_eq!(parse_and_convert("f64", false), "number");
         assert_eq!(parse_and_convert("usize", false), "number");
+        assert_eq!(parse_and_convert("char", false), "string");
+        assert_eq!(parse_and_convert("u64", false), "number");
+        assert_eq!(parse_and_convert("i128", true), "number");
+
+        // impl Trait / dyn Trait
+        assert_eq!(parse_and_convert("impl Serialize", true), "unknown");
+        assert_eq!(parse_and_convert("Box<dyn Trait>", false), "unknown");
+        assert_eq!(parse_and_convert("&dyn Trait", false), "unknown");
 
         // Option<T>
         assert_eq!(
@@ -1125,6 +4196,14 @@ mod tests {
         assert_eq!(
             parse_and_convert("Option<MyStruct>", false),
             "MyStruct | undefined"
+        );
+        assert_eq!(
+            parse_and_convert("Option<Option<String>>", false),
+            "string | undefined"
+        );
+        assert_eq!(
+            parse_and_convert("Option<Option<Option<u32>>>", true),
+            "number | undefined"
         ); // User-defined type
 
         // Vec<T>
@@ -1132,6 +4211,110 @@ mod tests {
         assert_eq!(parse_and_convert("Vec<bool>", false), "boolean[]");
         assert_eq!(parse_and_convert("Vec<MyEnum>", false), "MyEnum[]"); // User-defined type
 
+        // Sets and other sequences (serialized as arrays like Vec<T>)
+        assert_eq!(parse_and_convert("HashSet<String>", false), "string[]");
+        assert_eq!(parse_and_convert("BTreeSet<u32>", true), "number[]");
+        assert_eq!(
+            parse_and_convert("std::collections::HashSet<MyStruct>", false),
+            "MyStruct[]"
+        );
+        assert_eq!(
+            parse_and_convert("HashSet<Option<u32>>", false),
+            "(number | undefined)[]"
+        );
+        assert_eq!(
+            parse_and_convert("VecDeque<Option<u32>>", false),
+            "(number | undefined)[]"
+        );
+        assert_eq!(parse_and_convert("LinkedList<bool>", false), "boolean[]");
+
+        // Well-known types from common crates
+        assert_eq!(parse_and_convert("DateTime<Utc>", false), "string");
+        assert_eq!(
+            parse_and_convert("chrono::DateTime<chrono::Utc>", true),
+            "string"
+        );
+        assert_eq!(parse_and_convert("NaiveDate", false), "string");
+        assert_eq!(parse_and_convert("time::OffsetDateTime", true), "string");
+        assert_eq!(parse_and_convert("Vec<Uuid>", false), "string[]");
+        assert_eq!(
+            parse_and_convert("Option<uuid::Uuid>", true),
+            "string | undefined"
+        );
+        assert_eq!(
+            parse_and_convert("std::time::Duration", true),
+            "{ secs: number; nanos: number }"
+        );
+        assert_eq!(
+            parse_and_convert("Vec<Duration>", false),
+            "{ secs: number; nanos: number }[]"
+        );
+        assert_eq!(
+            parse_and_convert("SystemTime", false),
+            "{ secs_since_epoch: number; nanos_since_epoch: number }"
+        );
+
+        assert_eq!(parse_and_convert("std::path::PathBuf", true), "string");
+        assert_eq!(parse_and_convert("&Path", true), "string");
+        assert_eq!(parse_and_convert("Vec<PathBuf>", false), "string[]");
+
+        // serde_json
+        assert_eq!(parse_and_convert("serde_json::Value", true), "unknown");
+        assert_eq!(parse_and_convert("Value", false), "unknown");
+        assert_eq!(
+            parse_and_convert("Vec<serde_json::Value>", false),
+            "unknown[]"
+        );
+        assert_eq!(
+            parse_and_convert("serde_json::Map<String, serde_json::Value>", true),
+            "Record<string, unknown>"
+        );
+        assert_eq!(parse_and_convert("toml::Value", false), "T.Value");
+        let json_value_as_any = TypeMapping {
+            json_value_as_any: true,
+            ..Default::default()
+        };
+        let ty: Type = syn::parse_str("Vec<serde_json::Value>").unwrap();
+        assert_eq!(
+            type_to_ts(&ty, &defined_types, true, &json_value_as_any),
+            "any[]"
+        );
+
+        // Smart pointers are serialized as their inner value
+        assert_eq!(parse_and_convert("Box<MyStruct>", false), "MyStruct");
+        assert_eq!(parse_and_convert("Box<MyStruct>", true), "T.MyStruct");
+        assert_eq!(parse_and_convert("Arc<str>", false), "string");
+        assert_eq!(parse_and_convert("std::rc::Rc<MyEnum>", false), "MyEnum");
+        assert_eq!(parse_and_convert("Cow<'a, str>", false), "string");
+        assert_eq!(parse_and_convert("Cow<[u8]>", false), "number[]");
+        assert_eq!(
+            parse_and_convert("Box<Vec<Option<u32>>>", false),
+            "(number | undefined)[]"
+        );
+        assert_eq!(
+            parse_and_convert("Arc<Option<String>>", true),
+            "string | undefined"
+        );
+
+        // Fixed-size arrays
+        assert_eq!(
+            parse_and_convert("[u8; 4]", false),
+            "[number, number, number, number]"
+        );
+        assert_eq!(
+            parse_and_convert("[MyStruct; 2]", false),
+            "[MyStruct, MyStruct]"
+        );
+        assert_eq!(
+            parse_and_convert("[MyStruct; 2]", true),
+            "[T.MyStruct, T.MyStruct]"
+        );
+        assert_eq!(parse_and_convert("[u8; 32]", true), "number[]");
+        assert_eq!(
+            parse_and_convert("[Option<u8>; N]", false),
+            "(number | undefined)[]"
+        );
+
         // HashMap<K, V>
         assert_eq!(
             parse_and_convert("HashMap<String, u32>", false),
@@ -1142,6 +4325,17 @@ mod tests {
             "Record<string, MyStruct>"
         );
 
+        // BTreeMap<K, V> (same JSON object representation as HashMap)
+        assert_eq!(
+            parse_and_convert("BTreeMap<String, MyStruct>", false),
+            "Record<string, MyStruct>"
+        );
+        assert_eq!(
+            parse_and_convert("std::collections::BTreeMap<u32, Vec<bool>>", false),
+            "Record<number, boolean[]>"
+        );
+        assert_eq!(parse_and_convert("BTreeMap", false), "Record<any, any>");
+
         // Result<T, E> (should return T)
         assert_eq!(
             parse_and_convert("Result<String, MyError>", false),
@@ -1157,6 +4351,18 @@ mod tests {
         assert_eq!(parse_and_convert("&str", false), "string");
         assert_eq!(parse_and_convert("&String", false), "string");
         assert_eq!(parse_and_convert("&u32", false), "number");
+        assert_eq!(parse_and_convert("&mut String", false), "string");
+        assert_eq!(parse_and_convert("&'a str", false), "string");
+        assert_eq!(parse_and_convert("&'static mut str", false), "string");
+        assert_eq!(parse_and_convert("&&str", false), "string");
+        assert_eq!(parse_and_convert("&&MyStruct", true), "T.MyStruct");
+        assert_eq!(parse_and_convert("&[u32]", false), "number[]");
+        assert_eq!(parse_and_convert("&'a [MyStruct]", true), "T.MyStruct[]");
+        assert_eq!(parse_and_convert("&mut [bool]", false), "boolean[]");
+        assert_eq!(
+            parse_and_convert("&[Option<u32>]", false),
+            "(number | undefined)[]"
+        );
 
         // Tuple types
         assert_eq!(parse_and_convert("()", false), "void");
@@ -1173,6 +4379,16 @@ mod tests {
             "[string, [number, boolean]]"
         );
 
+        // Byte buffers (Uint8Array only in command positions)
+        assert_eq!(parse_and_convert("Vec<u8>", true), "Uint8Array");
+        assert_eq!(parse_and_convert("Vec<u8>", false), "number[]");
+        assert_eq!(parse_and_convert("&[u8]", true), "Uint8Array");
+        assert_eq!(parse_and_convert("&[u8]", false), "number[]");
+        assert_eq!(parse_and_convert("bytes::Bytes", true), "Uint8Array");
+        assert_eq!(parse_and_convert("Bytes", false), "number[]");
+        assert_eq!(parse_and_convert("&[String]", true), "string[]");
+        assert_eq!(parse_and_convert("Vec<u16>", true), "number[]");
+
         // User-defined types
         assert_eq!(parse_and_convert("MyStruct", false), "MyStruct"); // Not a tauri command type, so no T. prefix
         assert_eq!(parse_and_convert("MyStruct", true), "T.MyStruct"); // Is a tauri command type, so T. prefix
@@ -1206,4 +4422,60 @@ mod tests {
             "T.SomeUnknownType"
         ); // Not in defined_types, so T. prefix
     }
+
+    #[test]
+    fn test_extract_generate_handler_commands() {
+        let code = r#"
+            pub fn run() {
+                tauri::Builder::default()
+                    .invoke_handler(tauri::generate_handler![greet, commands::get_user,])
+                    .run(tauri::generate_context!())
+                    .expect("error while running tauri application");
+            }
+        "#;
+        let syntax = syn::parse_file(code).unwrap();
+        assert_eq!(
+            extract_generate_handler_commands(&syntax.items),
+            vec!["greet", "get_user"]
+        );
+    }
+
+    #[test]
+    fn test_extract_tauri_commands_with_channel() {
+        let rust_code = r#"
+            use tauri::ipc::Channel;
+
+            #[derive(Clone, Serialize)]
+            pub struct DownloadEvent { pub progress: u32 }
+
+            #[tauri::command]
+            fn download(url: String, on_event: Channel<DownloadEvent>) {}
+
+            #[tauri::command]
+            fn stream(channel: tauri::ipc::Channel<u64>) {}
+        "#;
+        let syntax = syn::parse_file(rust_code).unwrap();
+        let extracted_types =
+            extract_and_convert_types(&syntax.items, "test_file", &TypeMapping::default());
+        let commands =
+            extract_tauri_commands(&syntax.items, &extracted_types, &TypeMapping::default());
+
+        assert_eq!(
+            commands[0]["args"],
+            serde_json::json!(["url: string", "onEvent: (msg: T.DownloadEvent) => void"])
+        );
+        assert_eq!(
+            commands[0]["invoke_args"][1],
+            "onEvent: Object.assign(new Channel<T.DownloadEvent>(), { onmessage: onEvent })"
+        );
+        assert_eq!(commands[0]["uses_channel"], true);
+        assert_eq!(
+            commands[0]["referenced_types"],
+            serde_json::json!(["DownloadEvent", "String"])
+        );
+        assert_eq!(
+            commands[1]["args"],
+            serde_json::json!(["channel: (msg: number) => void"])
+        );
+    }
 }
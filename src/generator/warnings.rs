@@ -0,0 +1,730 @@
+use crate::generator::type_extractor::{
+    DroppedArgument, EventInfo, ExtractedTypeInfo, WindowEventInfo,
+};
+use std::collections::HashSet;
+use std::fmt;
+
+/// Which optional cross-file checks are run, each reported as a `Warning`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WarningOptions {
+    /// Whether to warn about serde types not used by any command or event.
+    pub unused_types: bool,
+    /// Whether to cross-check the commands against the `tauri::generate_handler!` invocations.
+    pub generate_handler: bool,
+}
+
+/// A non-fatal diagnostic reported while generating TypeScript.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Warning {
+    /// A serde type is exported but never reachable from any command signature or event payload.
+    UnusedType {
+        type_name: String,
+        file_name: String,
+    },
+    /// The same type name is defined differently in two files; only the first definition is emitted.
+    ConflictingTypeDefinition {
+        type_name: String,
+        first_file_name: String,
+        second_file_name: String,
+    },
+    /// A Tauri command is not registered with `tauri::generate_handler!`.
+    UnregisteredCommand { command_name: String },
+    /// `tauri::generate_handler!` registers a command that was not found in the input files.
+    UnknownHandlerCommand { command_name: String },
+    /// The same event is emitted with different payload types; their union is used.
+    ConflictingEventPayload {
+        event_name: String,
+        /// The target window of `emit_to`, or `None` for a global event.
+        window_name: Option<String>,
+        payload_types: Vec<String>,
+    },
+    /// A command argument is left out of the wrapper because its type is not Deserializable.
+    DroppedArgument(DroppedArgument),
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Warning::UnusedType {
+                type_name,
+                file_name,
+            } => write!(
+                f,
+                "Type `{type_name}` in {file_name}.rs is not used by any command or event"
+            ),
+            Warning::ConflictingTypeDefinition {
+                type_name,
+                first_file_name,
+                second_file_name,
+            } => write!(
+                f,
+                "Type `{type_name}` is defined differently in {first_file_name}.rs and {second_file_name}.rs; using the definition from {first_file_name}.rs"
+            ),
+            Warning::UnregisteredCommand { command_name } => write!(
+                f,
+                "Command `{command_name}` is not registered in tauri::generate_handler!"
+            ),
+            Warning::UnknownHandlerCommand { command_name } => write!(
+                f,
+                "tauri::generate_handler! registers `{command_name}`, but no such command was found"
+            ),
+            Warning::ConflictingEventPayload {
+                event_name,
+                window_name,
+                payload_types,
+            } => {
+                let payload_types = payload_types.join(" | ");
+                match window_name {
+                    Some(window_name) => write!(
+                        f,
+                        "Event `{event_name}` to window `{window_name}` is emitted with different payload types; using `{payload_types}`"
+                    ),
+                    None => write!(
+                        f,
+                        "Event `{event_name}` is emitted with different payload types; using `{payload_types}`"
+                    ),
+                }
+            }
+            Warning::DroppedArgument(DroppedArgument {
+                command_name,
+                arg_name,
+                arg_type,
+                type_name,
+            }) => write!(
+                f,
+                "Argument `{arg_name}: {arg_type}` of command `{command_name}` is left out of the TypeScript wrapper because `{type_name}` does not derive Deserialize"
+            ),
+        }
+    }
+}
+
+/// Collects the type names referenced by event payloads (e.g. `T.EventPayload` -> `EventPayload`).
+pub fn event_payload_type_names(
+    global_events: &[EventInfo],
+    window_events: &[WindowEventInfo],
+) -> Vec<String> {
+    global_events
+        .iter()
+        .map(|event| event.payload_type.as_str())
+        .chain(
+            window_events
+                .iter()
+                .map(|event| event.payload_type.as_str()),
+        )
+        .flat_map(|payload_type| payload_type.split(|c: char| !c.is_alphanumeric() && c != '_'))
+        .filter(|name| !name.is_empty() && *name != "T")
+        .map(str::to_string)
+        .collect()
+}
+
+/// Reports exported types that cannot be reached from the given root type names.
+///
+/// A type counts as used when it is referenced directly by a root (a command signature
+/// or an event payload) or transitively through the fields of another used type.
+///
+/// # Arguments
+///
+/// * `all_extracted_types` - All extracted user-defined types.
+/// * `used_type_names` - The type names referenced by command signatures and event payloads.
+///
+/// # Returns
+///
+/// A `Warning::UnusedType` for each unreachable type, in the order of `all_extracted_types`.
+pub fn find_unused_types(
+    all_extracted_types: &[ExtractedTypeInfo],
+    used_type_names: &[String],
+) -> Vec<Warning> {
+    let mut reachable: HashSet<&str> = HashSet::new();
+    let mut pending: Vec<&str> = used_type_names.iter().map(String::as_str).collect();
+
+    while let Some(name) = pending.pop() {
+        if !reachable.insert(name) {
+            continue;
+        }
+        for info in all_extracted_types.iter().filter(|info| info.name == name) {
+            pending.extend(info.referenced_types.iter().map(String::as_str));
+        }
+    }
+
+    all_extracted_types
+        .iter()
+        .filter(|info| info.is_serializable || info.is_deserializable)
+        .filter(|info| !reachable.contains(info.name.as_str()))
+        .map(|info| Warning::UnusedType {
+            type_name: info.name.clone(),
+            file_name: info.original_file_name.clone(),
+        })
+        .collect()
+}
+
+/// Cross-checks the extracted commands against the commands registered with `generate_handler!`.
+///
+/// # Arguments
+///
+/// * `command_names` - The Rust names of all extracted `#[tauri::command]` functions.
+/// * `handler_command_names` - The commands listed in all `tauri::generate_handler!` invocations.
+///
+/// # Returns
+///
+/// A `Warning::UnregisteredCommand` for each command missing from the handler, followed by a
+/// `Warning::UnknownHandlerCommand` for each registered command that was not extracted.
+pub fn check_generate_handler(
+    command_names: &[String],
+    handler_command_names: &[String],
+) -> Vec<Warning> {
+    let commands: HashSet<&str> = command_names.iter().map(String::as_str).collect();
+    let registered: HashSet<&str> = handler_command_names.iter().map(String::as_str).collect();
+
+    let unregistered = command_names
+        .iter()
+        .filter(|name| !registered.contains(name.as_str()))
+        .map(|name| Warning::UnregisteredCommand {
+            command_name: name.clone(),
+        });
+    let unknown = handler_command_names
+        .iter()
+        .filter(|name| !commands.contains(name.as_str()))
+        .map(|name| Warning::UnknownHandlerCommand {
+            command_name: name.clone(),
+        });
+    unregistered.chain(unknown).collect()
+}
+
+/// Merges events emitted from several places into one event each, keeping the first position.
+///
+/// Global events are merged by name and window events by window and name. An event emitted with
+/// different payload types gets the union of those types, so its handler accepts every payload.
+///
+/// # Returns
+///
+/// A `Warning::ConflictingEventPayload` for each merged event with more than one payload type.
+pub fn deduplicate_events(
+    global_events: &mut Vec<EventInfo>,
+    window_events: &mut Vec<WindowEventInfo>,
+) -> Vec<Warning> {
+    let mut warnings = Vec::new();
+    for (event, payload_types) in merge_events(
+        global_events,
+        |a, b| a.event_name == b.event_name,
+        |event| &mut event.payload_type,
+    ) {
+        warnings.push(Warning::ConflictingEventPayload {
+            event_name: event.event_name.clone(),
+            window_name: None,
+            payload_types,
+        });
+    }
+    for (event, payload_types) in merge_events(
+        window_events,
+        |a, b| a.window_name == b.window_name && a.event_name == b.event_name,
+        |event| &mut event.payload_type,
+    ) {
+        warnings.push(Warning::ConflictingEventPayload {
+            event_name: event.event_name.clone(),
+            window_name: Some(event.window_name.clone()),
+            payload_types,
+        });
+    }
+    warnings
+}
+
+/// Keeps the first of the events for which `is_same` holds, setting its payload type to the
+/// union of theirs, and returns the events with more than one payload type.
+fn merge_events<E: Clone>(
+    events: &mut Vec<E>,
+    is_same: impl Fn(&E, &E) -> bool,
+    payload_type: impl Fn(&mut E) -> &mut String,
+) -> Vec<(E, Vec<String>)> {
+    let mut kept: Vec<(E, Vec<String>)> = Vec::with_capacity(events.len());
+    for mut event in events.drain(..) {
+        let event_payload_type = payload_type(&mut event).clone();
+        match kept
+            .iter_mut()
+            .find(|(existing, _)| is_same(existing, &event))
+        {
+            Some((_, payload_types)) => {
+                if !payload_types.contains(&event_payload_type) {
+                    payload_types.push(event_payload_type);
+                }
+            }
+            None => kept.push((event, vec![event_payload_type])),
+        }
+    }
+
+    let mut conflicts = Vec::new();
+    for (mut event, payload_types) in kept {
+        if payload_types.len() > 1 {
+            *payload_type(&mut event) = payload_types.join(" | ");
+            conflicts.push((event.clone(), payload_types));
+        }
+        events.push(event);
+    }
+    conflicts
+}
+
+/// Removes types whose name was already extracted from another file, keeping the first definition.
+///
+/// Declaring the same name twice in `interface/types/index.ts` is a TypeScript error, so only one
+/// declaration is kept. Definitions that differ in more than their doc comments and source lines
+/// are reported.
+///
+/// # Returns
+///
+/// A `Warning::ConflictingTypeDefinition` for each removed definition that differs from the kept one.
+pub fn deduplicate_types(all_extracted_types: &mut Vec<ExtractedTypeInfo>) -> Vec<Warning> {
+    let mut warnings = Vec::new();
+    let mut kept: Vec<ExtractedTypeInfo> = Vec::with_capacity(all_extracted_types.len());
+    for info in all_extracted_types.drain(..) {
+        match kept.iter().find(|existing| existing.name == info.name) {
+            Some(existing) => {
+                if comparable_definition(&existing.ts_interface)
+                    != comparable_definition(&info.ts_interface)
+                {
+                    warnings.push(Warning::ConflictingTypeDefinition {
+                        type_name: info.name.clone(),
+                        first_file_name: existing.original_file_name.clone(),
+                        second_file_name: info.original_file_name.clone(),
+                    });
+                }
+            }
+            None => kept.push(info),
+        }
+    }
+    *all_extracted_types = kept;
+    warnings
+}
+
+/// Returns a copy of a type's JSON representation without its `doc_comment` entries and the
+/// `line` it was declared at (see `--source-lines`), which do not change the emitted type.
+fn comparable_definition(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => map
+            .iter()
+            .filter(|(key, _)| !matches!(key.as_str(), "doc_comment" | "line"))
+            .map(|(key, value)| (key.clone(), comparable_definition(value)))
+            .collect(),
+        serde_json::Value::Array(values) => values.iter().map(comparable_definition).collect(),
+        _ => value.clone(),
+    }
+}
+
+/// Finds the fallbacks of one file that `--strict` rejects.
+///
+/// These are `any` types in the command signatures and exported type definitions (unless
+/// `allow_any`, as with `--json-value-as-any`), return and error types replaced by `unknown`
+/// because they do not derive `Serialize`, and arguments left out of the wrappers.
+/// Unresolved `T.` references need the types of all files; see `find_unresolved_type_refs`.
+///
+/// # Returns
+///
+/// One message per violation, in the order of the commands and types.
+pub fn find_strict_violations(
+    functions: &[serde_json::Value],
+    types: &[ExtractedTypeInfo],
+    allow_any: bool,
+) -> Vec<String> {
+    let mut violations = Vec::new();
+    for func in functions {
+        let command_name = func["name"].as_str().unwrap_or_default();
+        if !allow_any {
+            for arg in func["args"].as_array().into_iter().flatten() {
+                if let Some((arg_name, arg_type)) = arg.as_str().and_then(|a| a.split_once(": "))
+                    && contains_any(arg_type)
+                {
+                    violations.push(format!(
+                        "Command `{command_name}`: argument `{arg_name}` falls back to `any` (`{arg_type}`)"
+                    ));
+                }
+            }
+            if let Some(return_type) = func["return_type"].as_str()
+                && contains_any(return_type)
+            {
+                violations.push(format!(
+                    "Command `{command_name}`: the return type falls back to `any` (`{return_type}`)"
+                ));
+            }
+        }
+        for fallback in func["fallbacks"].as_array().into_iter().flatten() {
+            if let Some(fallback) = fallback.as_str() {
+                violations.push(format!("Command `{command_name}`: {fallback}"));
+            }
+        }
+        let dropped_args: Vec<DroppedArgument> =
+            serde_json::from_value(func["dropped_args"].clone()).unwrap_or_default();
+        violations.extend(
+            dropped_args
+                .into_iter()
+                .map(|dropped| Warning::DroppedArgument(dropped).to_string()),
+        );
+    }
+    if !allow_any {
+        for info in types.iter().filter(|info| is_exported(info)) {
+            for ts_type in type_definition_ts_types(&info.ts_interface) {
+                if contains_any(ts_type) {
+                    violations.push(format!(
+                        "Type `{}` in {}.rs falls back to `any` (`{ts_type}`)",
+                        info.name, info.original_file_name
+                    ));
+                }
+            }
+        }
+    }
+    violations
+}
+
+/// Collects the names referenced as `T.<Name>` by the command signatures and the exported
+/// type definitions, e.g. `Page` and `User` for `T.Page<T.User>`.
+pub fn namespace_type_refs(
+    functions: &[serde_json::Value],
+    types: &[ExtractedTypeInfo],
+) -> Vec<String> {
+    let signature_types = functions.iter().flat_map(|func| {
+        let args = func["args"].as_array().into_iter().flatten();
+        args.chain([&func["return_type"], &func["error_type"]])
+            .filter_map(|ts_type| ts_type.as_str())
+    });
+    let definition_types = types
+        .iter()
+        .filter(|info| is_exported(info))
+        .flat_map(|info| type_definition_ts_types(&info.ts_interface));
+    let mut refs: Vec<String> = signature_types
+        .chain(definition_types)
+        .flat_map(|ts_type| {
+            ts_type
+                .match_indices("T.")
+                // 識別子の途中 (例: "DateT.") は名前空間の参照ではない
+                .filter(|(i, _)| {
+                    !ts_type[..*i].ends_with(|c: char| c.is_alphanumeric() || c == '_' || c == '.')
+                })
+                .map(|(i, _)| {
+                    ts_type[i + 2..]
+                        .split(|c: char| !c.is_alphanumeric() && c != '_')
+                        .next()
+                        .unwrap_or_default()
+                        .to_string()
+                })
+                .collect::<Vec<_>>()
+        })
+        .filter(|name| !name.is_empty())
+        .collect();
+    refs.sort();
+    refs.dedup();
+    refs
+}
+
+/// Reports `T.<Name>` references (see `namespace_type_refs`) to types that are not exported
+/// from any input file, e.g. types of other crates that the generator cannot see.
+pub fn find_unresolved_type_refs(
+    type_refs: &[String],
+    all_extracted_types: &[ExtractedTypeInfo],
+) -> Vec<String> {
+    let exported: HashSet<&str> = all_extracted_types
+        .iter()
+        .filter(|info| is_exported(info))
+        .map(|info| info.name.as_str())
+        .collect();
+    let mut unresolved: Vec<&String> = type_refs
+        .iter()
+        .filter(|name| !exported.contains(name.as_str()))
+        .collect();
+    unresolved.sort();
+    unresolved.dedup();
+    unresolved
+        .into_iter()
+        .map(|name| format!("`T.{name}` does not refer to an exported type of the input files"))
+        .collect()
+}
+
+/// Returns `true` if the type is written to `interface/types/index.ts`.
+fn is_exported(info: &ExtractedTypeInfo) -> bool {
+    info.is_serializable || info.is_deserializable || info.ts_interface["type"] == "const"
+}
+
+/// The TypeScript types used in a type definition: field, variant, alias and base types.
+fn type_definition_ts_types(ts_interface: &serde_json::Value) -> Vec<&str> {
+    let fields = ts_interface["fields"].as_array().into_iter().flatten();
+    let variants = ts_interface["variants"].as_array().into_iter().flatten();
+    let extends = ts_interface["extends"].as_array().into_iter().flatten();
+    let intersections = ts_interface["intersections"]
+        .as_array()
+        .into_iter()
+        .flatten();
+    let flattened = ts_interface["flattened"].as_array().into_iter().flatten();
+    fields
+        .map(|field| &field["type"])
+        .chain(variants.map(|variant| &variant["ts_type"]))
+        .chain(flattened.map(|entry| &entry["type"]))
+        .chain(extends)
+        .chain(intersections)
+        .chain([&ts_interface["ts_type"]])
+        .filter_map(|ts_type| ts_type.as_str())
+        .collect()
+}
+
+/// Returns `true` if a TypeScript type contains the `any` type, ignoring string literal types.
+fn contains_any(ts_type: &str) -> bool {
+    let mut in_string = false;
+    let mut token = String::new();
+    for c in ts_type.chars().chain([' ']) {
+        if c == '"' {
+            in_string = !in_string;
+        }
+        if !in_string && (c.is_alphanumeric() || c == '_' || c == '$' || c == '.') {
+            token.push(c);
+            continue;
+        }
+        if token == "any" {
+            return true;
+        }
+        token.clear();
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::options::{GenerateOptions, TypeMapping};
+    use crate::generator::ts_file_generator::generate_ts_files;
+    use crate::generator::type_extractor::extract_and_convert_types;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_deduplicate_types() {
+        let mut types = Vec::new();
+        for (file_name, rust_code) in [
+            (
+                "a",
+                "/// A\n#[derive(Serialize)] pub struct Same { pub id: u32 }\n#[derive(Serialize)] pub struct Changed { pub id: u32 }",
+            ),
+            (
+                "b",
+                "/// B\n#[derive(Serialize)] pub struct Same { pub id: u32 }\n#[derive(Serialize)] pub struct Changed { pub id: String }",
+            ),
+        ] {
+            let syntax = syn::parse_file(rust_code).unwrap();
+            types.extend(extract_and_convert_types(
+                &syntax.items,
+                file_name,
+                &TypeMapping::default(),
+            ));
+        }
+
+        let warnings = deduplicate_types(&mut types);
+        let kept: Vec<(&str, &str)> = types
+            .iter()
+            .map(|info| (info.name.as_str(), info.original_file_name.as_str()))
+            .collect();
+        assert_eq!(kept, vec![("Same", "a"), ("Changed", "a")]);
+        // doc コメントのみの違いは報告しない
+        assert_eq!(
+            warnings,
+            vec![Warning::ConflictingTypeDefinition {
+                type_name: "Changed".to_string(),
+                first_file_name: "a".to_string(),
+                second_file_name: "b".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_deduplicate_types_ignores_source_lines() {
+        let mut types = Vec::new();
+        for (file_name, rust_code) in [
+            ("a", "#[derive(Serialize)] pub struct Same { pub id: u32 }"),
+            (
+                "b",
+                "use serde::Serialize;\n\n#[derive(Serialize)] pub struct Same { pub id: u32 }",
+            ),
+        ] {
+            let syntax = syn::parse_file(rust_code).unwrap();
+            types.extend(extract_and_convert_types(
+                &syntax.items,
+                file_name,
+                &TypeMapping::default(),
+            ));
+        }
+        assert_ne!(types[0].ts_interface["line"], types[1].ts_interface["line"]);
+
+        // 宣言された行の違いは報告しない
+        assert!(deduplicate_types(&mut types).is_empty());
+        assert_eq!(types.len(), 1);
+    }
+
+    #[test]
+    fn test_find_unused_types_nesting_type_test() {
+        let rust_code = std::fs::read_to_string(
+            PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .join("test/data/nesting_type_test/src/nesting_type_test.rs"),
+        )
+        .unwrap();
+        let result =
+            generate_ts_files(&rust_code, "nesting_type_test", &GenerateOptions::default())
+                .unwrap();
+
+        // AppendEx は Data -> Append 経由でのみ参照されるため使用済みとして扱う
+        let warnings = find_unused_types(&result.types, &result.command_type_names);
+        assert!(warnings.is_empty(), "unexpected warnings: {warnings:?}");
+    }
+
+    #[test]
+    fn test_find_unused_types_reports_unreferenced_type() {
+        let rust_code = r#"
+            #[derive(Serialize, Deserialize)]
+            pub struct Used { pub value: u32 }
+
+            #[derive(Serialize, Deserialize)]
+            pub struct Legacy { pub value: u32 }
+
+            struct Internal { pub value: u32 }
+
+            #[derive(Clone, Serialize)]
+            pub struct Payload { pub message: String }
+
+            #[tauri::command]
+            fn get_used(app: tauri::AppHandle) -> Used {
+                app.emit("payload", Payload { message: String::new() }).unwrap();
+                Used { value: 1 }
+            }
+        "#;
+        let result = generate_ts_files(rust_code, "legacy", &GenerateOptions::default()).unwrap();
+
+        let mut used_type_names = result.command_type_names.clone();
+        used_type_names.extend(event_payload_type_names(
+            &result.global_events,
+            &result.window_events,
+        ));
+        let warnings = find_unused_types(&result.types, &used_type_names);
+        assert_eq!(
+            warnings,
+            vec![Warning::UnusedType {
+                type_name: "Legacy".to_string(),
+                file_name: "legacy".to_string(),
+            }]
+        );
+        assert_eq!(
+            warnings[0].to_string(),
+            "Type `Legacy` in legacy.rs is not used by any command or event"
+        );
+    }
+
+    #[test]
+    fn test_check_generate_handler_reports_mismatch() {
+        let rust_code = r#"
+            #[tauri::command]
+            fn greet(name: String) -> String { name }
+
+            #[tauri::command]
+            fn get_user_name() -> String { String::new() }
+
+            pub fn run() {
+                tauri::Builder::default()
+                    .invoke_handler(tauri::generate_handler![greet, get_user])
+                    .run(tauri::generate_context!())
+                    .expect("error while running tauri application");
+            }
+        "#;
+        let result = generate_ts_files(rust_code, "lib", &GenerateOptions::default()).unwrap();
+
+        let warnings = check_generate_handler(&result.command_names, &result.handler_command_names);
+        assert_eq!(
+            warnings,
+            vec![
+                Warning::UnregisteredCommand {
+                    command_name: "get_user_name".to_string(),
+                },
+                Warning::UnknownHandlerCommand {
+                    command_name: "get_user".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            warnings[0].to_string(),
+            "Command `get_user_name` is not registered in tauri::generate_handler!"
+        );
+    }
+
+    #[test]
+    fn test_deduplicate_events() {
+        let rust_code = r#"
+            #[derive(Clone, Serialize)]
+            pub struct Progress { pub percent: u32 }
+
+            #[tauri::command]
+            fn start(app: tauri::AppHandle, progress: Progress) {
+                app.emit("progress", progress).unwrap();
+                app.emit("status", "started").unwrap();
+                app.emit_to("main", "refresh", 1).unwrap();
+            }
+
+            #[tauri::command]
+            fn finish(app: tauri::AppHandle, progress: Progress) {
+                app.emit("progress", progress).unwrap();
+                app.emit("status", false).unwrap();
+                app.emit_to("main", "refresh", 2).unwrap();
+                app.emit_to("settings", "refresh", "all").unwrap();
+            }
+        "#;
+        let result = generate_ts_files(rust_code, "tasks", &GenerateOptions::default()).unwrap();
+        let mut global_events = result.global_events;
+        let mut window_events = result.window_events;
+
+        let warnings = deduplicate_events(&mut global_events, &mut window_events);
+        let global: Vec<(&str, &str)> = global_events
+            .iter()
+            .map(|event| (event.event_name.as_str(), event.payload_type.as_str()))
+            .collect();
+        assert_eq!(
+            global,
+            vec![("progress", "T.Progress"), ("status", "string | boolean")]
+        );
+        let window: Vec<(&str, &str, &str)> = window_events
+            .iter()
+            .map(|event| {
+                (
+                    event.window_name.as_str(),
+                    event.event_name.as_str(),
+                    event.payload_type.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            window,
+            vec![
+                ("main", "refresh", "number"),
+                ("settings", "refresh", "string")
+            ]
+        );
+        assert_eq!(
+            warnings,
+            vec![Warning::ConflictingEventPayload {
+                event_name: "status".to_string(),
+                window_name: None,
+                payload_types: vec!["string".to_string(), "boolean".to_string()],
+            }]
+        );
+        assert_eq!(
+            warnings[0].to_string(),
+            "Event `status` is emitted with different payload types; using `string | boolean`"
+        );
+    }
+
+    #[test]
+    fn test_namespace_type_refs_and_contains_any() {
+        let functions = vec![serde_json::json!({
+            "name": "get_page",
+            "args": ["cursor: T.Cursor | undefined", "onEvent: (msg: T.Progress) => void"],
+            "return_type": "T.Page<T.User>",
+            "error_type": null,
+        })];
+        assert_eq!(
+            namespace_type_refs(&functions, &[]),
+            vec!["Cursor", "Page", "Progress", "User"]
+        );
+
+        assert!(contains_any("any"));
+        assert!(contains_any("Record<string, any>"));
+        assert!(!contains_any("T.Company"));
+        assert!(!contains_any("\"any\" | \"all\""));
+    }
+}
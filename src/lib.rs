@@ -0,0 +1,2063 @@
+//! Generates TypeScript bindings for the commands, events and types of a Tauri application.
+//!
+//! The `tauria-tsgen` binary is a thin wrapper around [`run_app`]. To call the generator from a
+//! build script or another tool, use [`generate`]:
+//!
+//! ```
+//! use tauria_tsgen::{GenerateOptions, generate};
+//!
+//! let input_dir = tempfile::tempdir()?;
+//! std::fs::write(
+//!     input_dir.path().join("greeting.rs"),
+//!     "#[tauri::command]\nfn greet(name: String) -> String { format!(\"Hello, {name}\") }",
+//! )?;
+//! let output_dir = tempfile::tempdir()?;
+//!
+//! let summary = generate(input_dir.path(), output_dir.path(), GenerateOptions::default())?;
+//! assert_eq!(summary.commands, 1);
+//! assert!(output_dir.path().join("tauria-api/commands/Greeting.ts").exists());
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+// stdout は --report や --print-config の機械可読な出力専用とする (print_stdout を参照)
+#![warn(clippy::dbg_macro, clippy::print_stdout)]
+
+mod cache;
+pub mod cli;
+pub mod generator;
+mod watch;
+
+pub use generator::options::{
+    Framework, GenerateOptions, OutputDirs, OutputFormat, SortOrder, TypeMapping,
+};
+
+use anyhow::Context;
+use cache::GenerationCache;
+use cli::{Cli, ReportFormat, load_config};
+use generator::index_file_generator::{render_index_files, render_user_types_files};
+use generator::single_file_generator::generate_single_file;
+use generator::ts_file_generator::generate_event_handler_files;
+use generator::ts_file_generator::{generate_ts_files, write_generated_files};
+use generator::type_extractor::{DroppedArgument, resolve_flattened_fields};
+use generator::warnings::{
+    Warning, WarningOptions, check_generate_handler, deduplicate_events, deduplicate_types,
+    event_payload_type_names, find_unresolved_type_refs, find_unused_types,
+};
+use globset::{Glob, GlobBuilder, GlobMatcher, GlobSet, GlobSetBuilder};
+use log::{debug, error, info, warn};
+use rayon::prelude::*;
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use watch::RustFileWatcher;
+
+/// A summary of a generation run, returned by [`generate`] and printed with `--report`.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+pub struct GenerateSummary {
+    /// The number of input `.rs` files processed.
+    pub files_processed: usize,
+    /// The number of Tauri commands found in the input files.
+    pub commands: usize,
+    /// The Rust names of the Tauri commands, keyed by the base name of the file defining them.
+    /// Files without commands are left out.
+    pub command_names: BTreeMap<String, Vec<String>>,
+    /// The number of user-defined types written to the output.
+    pub types: usize,
+    /// The number of global events found in the input files.
+    pub global_events: usize,
+    /// The number of window events found in the input files.
+    pub window_events: usize,
+    /// The paths of the generated (or, in a dry run, planned) files.
+    pub files: Vec<PathBuf>,
+}
+
+impl GenerateSummary {
+    /// Renders the summary in the given `--report` format.
+    pub fn render(&self, format: ReportFormat) -> anyhow::Result<String> {
+        match format {
+            ReportFormat::Json => {
+                serde_json::to_string_pretty(self).context("Failed to serialize the report")
+            }
+            ReportFormat::Text => {
+                let mut report = format!(
+                    "Processed {} files: {} commands, {} types, {} global events, {} window events\n\
+                     Generated {} files:",
+                    self.files_processed,
+                    self.commands,
+                    self.types,
+                    self.global_events,
+                    self.window_events,
+                    self.files.len()
+                );
+                for file in &self.files {
+                    report.push_str(&format!("\n  {}", file.display()));
+                }
+                Ok(report)
+            }
+        }
+    }
+}
+
+/// Generates the TypeScript bindings for all Rust files under `input_dir` into `output_dir`.
+///
+/// This is the library counterpart of running the binary with `--input-path` and
+/// `--output-path`. The output directory is created if it does not exist.
+///
+/// # Arguments
+///
+/// * `input_dir` - The directory containing the input Rust code.
+/// * `output_dir` - The directory where the generated TypeScript files will be written.
+/// * `options` - Options controlling which TypeScript files are generated. The generation
+///   cache is only read and written if `options.use_cache` is set.
+///
+/// # Returns
+///
+/// A `GenerateSummary` of the run, or an `anyhow::Result` error if generation fails.
+pub fn generate(
+    input_dir: &Path,
+    output_dir: &Path,
+    options: GenerateOptions,
+) -> anyhow::Result<GenerateSummary> {
+    options.output_dirs.validate()?;
+    if let Some(templates_dir) = &options.templates_dir
+        && !templates_dir.is_dir()
+    {
+        anyhow::bail!("Templates directory does not exist: {templates_dir:?}");
+    }
+    fs::create_dir_all(output_dir).context("Failed to create output directory")?;
+    generate_bindings(
+        &InputFiles::directory(input_dir, GlobSet::empty()),
+        Output::Directory(output_dir),
+        &options,
+        WarningOptions::default(),
+        false,
+    )
+}
+
+/// Runs the main application logic.
+///
+/// This function orchestrates the process of reading Rust files from the input directory,
+/// generating TypeScript files (interfaces, Tauri API wrappers, event handlers, and index files),
+/// and handling any errors that occur during these operations.
+///
+/// # Arguments
+///
+/// * `cli` - The parsed command-line arguments and configuration.
+///
+/// # Returns
+///
+/// `Ok(())` if the application runs successfully, otherwise an `anyhow::Result` error.
+pub fn run_app(cli: Cli) -> anyhow::Result<()> {
+    let config = load_config(&cli).context("Failed to load configuration")?;
+    if cli.print_config {
+        print_stdout(&config.to_pretty_json()?);
+        return Ok(());
+    }
+    let output_dir = PathBuf::from(&config.output_path);
+    let mut options = GenerateOptions {
+        generate_mock_api: cli.mock_api,
+        api_object: cli.api_object,
+        split_types: cli.split_types,
+        api_style: cli.api_style,
+        framework: cli.framework,
+        output_format: config.output_format,
+        invoke_import: config.invoke_import,
+        declarations_only: cli.declarations_only,
+        sort_order: config.sort_order,
+        type_mapping: TypeMapping {
+            bigint_for_64: config.bigint_for_64,
+            json_value_as_any: config.json_value_as_any,
+            option_repr: config.option_repr,
+            tauri_version: config.tauri_version,
+        },
+        format: cli.format,
+        output_dirs: config.output_dirs,
+        templates_dir: cli.templates.as_ref().map(PathBuf::from),
+        strict: cli.strict,
+        validate_args: cli.validate_args,
+        source_lines: cli.source_lines,
+        use_cache: cli.cache,
+    };
+
+    let single_file = cli.single_file.as_ref().map(PathBuf::from);
+    let input = InputFiles::resolve(&config.input_path, build_exclude_set(&config.exclude)?)?;
+    options.output_dirs.validate()?;
+
+    info!("Input: {:?}", config.input_path);
+    info!("Output directory: {output_dir:?}");
+    if cli.no_cache {
+        warn!("--no-cache is deprecated: the cache is only used with --cache");
+    }
+    if single_file.is_some() && (options.generate_mock_api || options.framework.is_some()) {
+        warn!("--mock-api and --framework are ignored in single-file mode");
+    }
+    if single_file.is_some() && options.api_object {
+        warn!("--api-object is ignored in single-file mode");
+    }
+    if single_file.is_some() && options.split_types {
+        warn!("--split-types is ignored in single-file mode");
+    }
+    if single_file.is_some() && options.output_dirs != OutputDirs::default() {
+        warn!("output_dirs is ignored in single-file mode");
+        // 単一ファイルへの結合は既定のディレクトリ構成を前提とする
+        options.output_dirs = OutputDirs::default();
+    }
+    if single_file.is_some() && options.output_format != OutputFormat::Esm {
+        warn!("--output-format is ignored in single-file mode, which always uses ES modules");
+        // 単一ファイルへの結合は ES モジュールの import を前提とする
+        options.output_format = OutputFormat::Esm;
+    }
+    if options.declarations_only {
+        if single_file.is_some() {
+            warn!("--declarations-only is ignored in single-file mode");
+            options.declarations_only = false;
+        } else if options.generate_mock_api || options.framework.is_some() {
+            warn!("--mock-api and --framework are ignored with --declarations-only");
+        }
+    }
+
+    if cli.dry_run {
+        info!("Dry run: no files will be written");
+    } else if !output_dir.exists() {
+        info!("Output directory does not exist, creating: {output_dir:?}");
+        fs::create_dir_all(&output_dir).context("Failed to create output directory")?;
+    }
+
+    let warnings = WarningOptions {
+        unused_types: cli.warn_unused_types,
+        generate_handler: cli.check_handler,
+    };
+
+    if cli.stdin {
+        let name = cli
+            .name
+            .as_deref()
+            .context("--name is required when reading from stdin")?;
+        let summary = generate_from_reader(
+            std::io::stdin().lock(),
+            name,
+            &output_dir,
+            &options,
+            cli.dry_run,
+        )?;
+        return print_report(&summary, cli.report);
+    }
+    let output = match &single_file {
+        Some(path) => Output::SingleFile(path),
+        None => Output::Directory(&output_dir),
+    };
+    let summary = generate_bindings(&input, output, &options, warnings, cli.dry_run)?;
+    print_report(&summary, cli.report)?;
+
+    if cli.watch {
+        let watcher = RustFileWatcher::new(&input.dir, watch::DEFAULT_DEBOUNCE)?;
+        info!(
+            "Watching {:?} for changes. Press Ctrl-C to stop.",
+            input.dir
+        );
+        loop {
+            watcher.wait_for_change()?;
+            info!("Change detected, regenerating...");
+            // 監視中は生成エラーで終了せず、次の変更を待つ
+            match generate_bindings(&input, output, &options, warnings, cli.dry_run) {
+                Ok(summary) => print_report(&summary, cli.report)?,
+                Err(e) => error!("Regeneration failed: {e:?}"),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints the summary of a run to stdout if `--report` is given.
+///
+/// The report goes to stdout rather than the log, so CI can consume it while the logs go to stderr.
+fn print_report(summary: &GenerateSummary, format: Option<ReportFormat>) -> anyhow::Result<()> {
+    if let Some(format) = format {
+        print_stdout(&summary.render(format)?);
+    }
+    Ok(())
+}
+
+/// Writes the machine-readable output of `--report` and `--print-config`, the only output that
+/// goes to stdout. Diagnostics go through the logger to stderr instead.
+#[allow(clippy::print_stdout)]
+fn print_stdout(content: &str) {
+    println!("{content}");
+}
+
+/// Where `generate_bindings` writes the generated TypeScript.
+#[derive(Debug, Clone, Copy)]
+enum Output<'a> {
+    /// The output directory tree (`interface/`, `tauria-api/`, ...).
+    Directory(&'a Path),
+    /// A single bundled `.ts` file (`--single-file`).
+    SingleFile(&'a Path),
+}
+
+/// Generates all TypeScript files for the input Rust files.
+///
+/// This is a single generation pass, run once by `run_app` and again on every change in watch mode.
+///
+/// # Arguments
+///
+/// * `input` - The input Rust files (see `InputFiles`).
+/// * `output` - The output directory or single file to write the generated TypeScript to.
+/// * `options` - Options controlling which TypeScript files are generated. With
+///   `options.use_cache`, unchanged input files are taken from the cache in the output directory
+///   (see `GenerationCache`); the cache is not used for single-file output.
+/// * `warnings` - Which optional checks to run and log warnings for.
+/// * `dry_run` - Whether to only log the files that would be generated, without writing anything.
+///
+/// # Returns
+///
+/// A `GenerateSummary` with the paths of the generated (or, in a dry run, planned) files.
+fn generate_bindings(
+    input: &InputFiles,
+    output: Output,
+    options: &GenerateOptions,
+    warnings: WarningOptions,
+    dry_run: bool,
+) -> anyhow::Result<GenerateSummary> {
+    let mut file_names = Vec::new();
+    let mut all_ts_interfaces: Vec<crate::generator::type_extractor::ExtractedTypeInfo> =
+        Vec::new();
+    let mut all_global_events: Vec<crate::generator::type_extractor::EventInfo> = Vec::new();
+    let mut all_window_events: Vec<crate::generator::type_extractor::WindowEventInfo> = Vec::new();
+    let mut all_command_type_names: Vec<String> = Vec::new();
+    // コマンド名ごとに定義されている入力ファイルを記録する
+    let mut command_files: Vec<(String, PathBuf)> = Vec::new();
+    // 入力ファイルごとに、そのファイルで定義されたコマンド名を記録する
+    let mut command_names_by_file: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut handler_command_names: Vec<String> = Vec::new();
+    let mut dropped_args = Vec::new();
+    let mut type_refs: Vec<String> = Vec::new();
+    let mut all_files = Vec::new();
+
+    let input_dir = input.dir.as_path();
+    let rust_files = collect_rust_files(input)?;
+    // ファイルごとの解析と生成は独立しているため並列に行い、結果は入力ファイルの順に集約する
+    let mut cache = match output {
+        Output::Directory(output_dir) if options.use_cache => {
+            Some(GenerationCache::load(output_dir, options))
+        }
+        _ => None,
+    };
+    let results = assign_output_names(input_dir, &rust_files)
+        .into_par_iter()
+        .map(|(path, file_name)| {
+            let code = fs::read_to_string(&path).context("Failed to read file")?;
+            let cache_key = cache_key(input_dir, &path);
+            if let Some(result) = cache
+                .as_ref()
+                .and_then(|cache| cache.get(&cache_key, &file_name, &code))
+            {
+                debug!("Unchanged file, using cached result: {path:?}");
+                return Ok((path, cache_key, code, file_name, result));
+            }
+            info!("Processing file: {path:?}");
+            let result = generate_ts_files(&code, &file_name, options)
+                .context("Failed to generate TypeScript wrapper")?;
+            Ok((path, cache_key, code, file_name, result))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    for (path, cache_key, code, file_name, result) in results {
+        if let Some(cache) = &mut cache {
+            cache.insert(cache_key, &file_name, &code, &result);
+        }
+        all_ts_interfaces.extend(result.types);
+        all_global_events.extend(result.global_events);
+        all_window_events.extend(result.window_events);
+        all_command_type_names.extend(result.command_type_names);
+        command_files.extend(
+            result
+                .command_names
+                .iter()
+                .map(|name| (name.clone(), path.clone())),
+        );
+        handler_command_names.extend(result.handler_command_names);
+        dropped_args.extend(result.dropped_args);
+        type_refs.extend(result.type_refs);
+        all_files.extend(result.files);
+
+        if result.has_command {
+            command_names_by_file
+                .entry(file_name.clone())
+                .or_default()
+                .extend(result.command_names);
+            file_names.push(file_name);
+        }
+    }
+
+    check_command_name_collisions(input_dir, &command_files)?;
+    report_dropped_args(dropped_args);
+
+    for warning in deduplicate_events(&mut all_global_events, &mut all_window_events) {
+        warn!("{warning}");
+    }
+
+    all_files.extend(generate_event_handler_files(
+        &all_global_events,
+        &all_window_events,
+        options,
+    )?);
+
+    for warning in deduplicate_types(&mut all_ts_interfaces) {
+        warn!("{warning}");
+    }
+    if options.strict {
+        check_type_refs(&type_refs, &all_ts_interfaces)?;
+    }
+    let unresolved_flattened = resolve_flattened_fields(&mut all_ts_interfaces);
+    if options.strict && !unresolved_flattened.is_empty() {
+        anyhow::bail!(
+            "Some flattened fields could not be resolved (--strict):\n{}",
+            unresolved_flattened.join("\n")
+        );
+    }
+    for message in unresolved_flattened {
+        warn!("{message}");
+    }
+    // ソース順では、入力ファイルのパス順と各ファイル内の宣言順をそのまま使う
+    if options.sort_order == SortOrder::Alphabetical {
+        file_names.sort();
+        all_ts_interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+
+    if warnings.unused_types {
+        let mut used_type_names = all_command_type_names;
+        used_type_names.extend(event_payload_type_names(
+            &all_global_events,
+            &all_window_events,
+        ));
+        for warning in find_unused_types(&all_ts_interfaces, &used_type_names) {
+            warn!("{warning}");
+        }
+    }
+    let command_names: Vec<String> = command_files.iter().map(|(name, _)| name.clone()).collect();
+    if warnings.generate_handler {
+        for warning in check_generate_handler(&command_names, &handler_command_names) {
+            warn!("{warning}");
+        }
+    }
+
+    let generated_paths = match output {
+        Output::Directory(output_dir) => {
+            let types_files = render_user_types_files(&all_ts_interfaces, options)?;
+            let has_user_types = types_files
+                .iter()
+                .any(|file| !file.content.trim().is_empty());
+            all_files.extend(types_files);
+            all_files.extend(render_index_files(
+                &file_names,
+                &command_names,
+                options,
+                &all_global_events,
+                &all_window_events,
+                has_user_types,
+            )?);
+
+            if dry_run {
+                for file in &all_files {
+                    info!(
+                        "Would generate: {} ({} bytes)",
+                        file.path.display(),
+                        file.content.len()
+                    );
+                }
+            } else {
+                write_generated_files(output_dir, &all_files)?;
+                if let Some(cache) = &cache {
+                    cache.save()?;
+                }
+            }
+            all_files
+                .into_iter()
+                .map(|file| output_dir.join(file.path))
+                .collect()
+        }
+        Output::SingleFile(path) => {
+            let content = generate_single_file(&all_ts_interfaces, &all_files, options)?;
+            if dry_run {
+                info!(
+                    "Would generate: {} ({} bytes)",
+                    path.display(),
+                    content.len()
+                );
+            } else {
+                fs::write(path, content).context("Failed to write single output file")?;
+                info!("Generated: {}", path.display());
+            }
+            vec![path.to_path_buf()]
+        }
+    };
+
+    if dry_run {
+        info!(
+            "Dry run completed, {} files planned.",
+            generated_paths.len()
+        );
+    } else {
+        info!("✅ Tauri wrapper generation completed.");
+    }
+    Ok(GenerateSummary {
+        files_processed: rust_files.len(),
+        commands: command_files.len(),
+        command_names: command_names_by_file,
+        types: all_ts_interfaces.len(),
+        global_events: all_global_events.len(),
+        window_events: all_window_events.len(),
+        files: generated_paths,
+    })
+}
+
+/// Generates the TypeScript files for a single Rust source read from `reader` (`--stdin`).
+///
+/// Only the files generated from the source itself are written. The index files and event
+/// handlers are built from all input files, so they are left untouched.
+///
+/// # Arguments
+///
+/// * `reader` - The reader providing the Rust source.
+/// * `name` - The base name of the source file, used to name the generated files.
+/// * `output_dir` - The directory to write the generated TypeScript files to.
+/// * `options` - Options controlling which TypeScript files are generated.
+/// * `dry_run` - Whether to only log the files that would be generated, without writing anything.
+///
+/// # Returns
+///
+/// A `GenerateSummary` of the source, or an `anyhow::Result` error if reading or generation fails.
+fn generate_from_reader(
+    mut reader: impl Read,
+    name: &str,
+    output_dir: &Path,
+    options: &GenerateOptions,
+    dry_run: bool,
+) -> anyhow::Result<GenerateSummary> {
+    let mut code = String::new();
+    reader
+        .read_to_string(&mut code)
+        .context("Failed to read Rust source from stdin")?;
+    info!("Processing stdin as {name}.rs");
+    let result =
+        generate_ts_files(&code, name, options).context("Failed to generate TypeScript wrapper")?;
+    report_dropped_args(result.dropped_args.clone());
+    if options.strict {
+        check_type_refs(&result.type_refs, &result.types)?;
+    }
+
+    if dry_run {
+        for file in &result.files {
+            info!(
+                "Would generate: {} ({} bytes)",
+                file.path.display(),
+                file.content.len()
+            );
+        }
+    } else {
+        write_generated_files(output_dir, &result.files)?;
+    }
+    Ok(GenerateSummary {
+        files_processed: 1,
+        commands: result.command_names.len(),
+        command_names: if result.has_command {
+            BTreeMap::from([(name.to_string(), result.command_names.clone())])
+        } else {
+            BTreeMap::new()
+        },
+        types: result.types.len(),
+        global_events: result.global_events.len(),
+        window_events: result.window_events.len(),
+        files: result
+            .files
+            .into_iter()
+            .map(|file| output_dir.join(file.path))
+            .collect(),
+    })
+}
+
+/// Logs a warning for each command argument left out of the generated wrappers.
+///
+/// With `--strict`, `generate_ts_files` fails on these arguments instead.
+fn report_dropped_args(dropped_args: Vec<DroppedArgument>) {
+    for dropped in dropped_args {
+        warn!("{}", Warning::DroppedArgument(dropped));
+    }
+}
+
+/// Fails if a `T.<Name>` reference does not resolve to an exported type (`--strict`).
+fn check_type_refs(
+    type_refs: &[String],
+    all_extracted_types: &[crate::generator::type_extractor::ExtractedTypeInfo],
+) -> anyhow::Result<()> {
+    let unresolved = find_unresolved_type_refs(type_refs, all_extracted_types);
+    if !unresolved.is_empty() {
+        anyhow::bail!(
+            "Some types could not be resolved (--strict):\n{}",
+            unresolved.join("\n")
+        );
+    }
+    Ok(())
+}
+
+/// The key of an input file in the generation cache: its path relative to `input_dir`.
+fn cache_key(input_dir: &Path, path: &Path) -> String {
+    path.strip_prefix(input_dir)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Builds the matcher for the `exclude` globs of the configuration.
+fn build_exclude_set(patterns: &[String]) -> anyhow::Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        builder.add(
+            Glob::new(pattern).with_context(|| format!("Invalid exclude pattern: {pattern}"))?,
+        );
+    }
+    builder.build().context("Failed to build exclude patterns")
+}
+
+/// The input Rust files: the `.rs` files under `dir` selected by `selection`, except those whose
+/// path relative to `dir` matches `exclude`.
+struct InputFiles {
+    dir: PathBuf,
+    selection: InputSelection,
+    exclude: GlobSet,
+}
+
+/// Which of the `.rs` files under `InputFiles::dir` are input files.
+enum InputSelection {
+    /// Every file, recursively.
+    All,
+    /// Only this file.
+    File(PathBuf),
+    /// The files whose path relative to the directory matches the glob.
+    Glob(GlobMatcher),
+}
+
+impl InputFiles {
+    /// Selects all `.rs` files under `dir`.
+    fn directory(dir: &Path, exclude: GlobSet) -> Self {
+        Self {
+            dir: dir.to_path_buf(),
+            selection: InputSelection::All,
+            exclude,
+        }
+    }
+
+    /// Resolves the `input_path` of the configuration: a directory, a single `.rs` file, or a glob
+    /// such as `src/commands/**/*.rs`.
+    ///
+    /// A glob is searched for under its leading components without glob metacharacters
+    /// (`src/commands` in the example). `*` does not match `/`, while `**` matches any number of
+    /// directories. Paths that do not exist are treated as a directory, which fails to be read.
+    fn resolve(input_path: &str, exclude: GlobSet) -> anyhow::Result<Self> {
+        let path = Path::new(input_path);
+        if path.is_file() {
+            let dir = path
+                .parent()
+                .filter(|parent| !parent.as_os_str().is_empty())
+                .unwrap_or(Path::new("."));
+            return Ok(Self {
+                dir: dir.to_path_buf(),
+                selection: InputSelection::File(path.to_path_buf()),
+                exclude,
+            });
+        }
+        let is_glob_component = |component: &std::path::Component| {
+            component
+                .as_os_str()
+                .to_string_lossy()
+                .contains(['*', '?', '[', '{'])
+        };
+        if path.is_dir()
+            || !path
+                .components()
+                .any(|component| is_glob_component(&component))
+        {
+            return Ok(Self::directory(path, exclude));
+        }
+
+        let dir: PathBuf = path
+            .components()
+            .take_while(|component| !is_glob_component(component))
+            .collect();
+        let pattern = path
+            .components()
+            .skip_while(|component| !is_glob_component(component))
+            .map(|component| component.as_os_str().to_string_lossy().into_owned())
+            .collect::<Vec<_>>()
+            .join("/");
+        let matcher = GlobBuilder::new(&pattern)
+            .literal_separator(true)
+            .build()
+            .with_context(|| format!("Invalid input pattern: {input_path}"))?
+            .compile_matcher();
+        Ok(Self {
+            dir: if dir.as_os_str().is_empty() {
+                PathBuf::from(".")
+            } else {
+                dir
+            },
+            selection: InputSelection::Glob(matcher),
+            exclude,
+        })
+    }
+}
+
+/// Collects the input `.rs` files (see `InputFiles`), sorted by path.
+///
+/// Directories are searched recursively. Symbolic links to directories are not followed.
+fn collect_rust_files(input: &InputFiles) -> anyhow::Result<Vec<PathBuf>> {
+    let input_dir = input.dir.as_path();
+    let is_excluded = |path: &Path| {
+        let excluded = input
+            .exclude
+            .is_match(path.strip_prefix(input_dir).unwrap_or(path));
+        if excluded {
+            info!("Skipping excluded file: {path:?}");
+        }
+        excluded
+    };
+    if let InputSelection::File(path) = &input.selection {
+        return Ok(if is_excluded(path) {
+            Vec::new()
+        } else {
+            vec![path.clone()]
+        });
+    }
+
+    let mut rust_files = Vec::new();
+    let mut pending_dirs = vec![input_dir.to_path_buf()];
+
+    while let Some(dir) = pending_dirs.pop() {
+        for entry in fs::read_dir(&dir).context("Failed to read input directory")? {
+            let entry = entry.context("Failed to read directory entry")?;
+            let path = entry.path();
+            let file_type = entry
+                .file_type()
+                .context("Failed to read directory entry")?;
+
+            if file_type.is_dir() {
+                pending_dirs.push(path);
+            } else if path.is_file() && path.extension().is_some_and(|ext| ext == "rs") {
+                if let InputSelection::Glob(matcher) = &input.selection
+                    && !matcher.is_match(path.strip_prefix(input_dir).unwrap_or(&path))
+                {
+                    debug!("Skipping file not matching the input pattern: {path:?}");
+                    continue;
+                }
+                if is_excluded(&path) {
+                    continue;
+                }
+                rust_files.push(path);
+            } else {
+                info!("Skipping: {path:?}");
+            }
+        }
+    }
+
+    rust_files.sort();
+    Ok(rust_files)
+}
+
+/// Fails if the same command name is defined in more than one input file.
+///
+/// Tauri invokes commands by name, so `invoke('get_data')` could not tell two `get_data`
+/// commands apart, and the generated wrappers would call whichever one is registered.
+fn check_command_name_collisions(
+    input_dir: &Path,
+    command_files: &[(String, PathBuf)],
+) -> anyhow::Result<()> {
+    let mut files_by_command: Vec<(&str, Vec<String>)> = Vec::new();
+    for (name, path) in command_files {
+        let relative_path = path
+            .strip_prefix(input_dir)
+            .unwrap_or(path)
+            .display()
+            .to_string();
+        match files_by_command
+            .iter_mut()
+            .find(|(command, _)| command == name)
+        {
+            Some((_, files)) => files.push(relative_path),
+            None => files_by_command.push((name, vec![relative_path])),
+        }
+    }
+
+    let collisions: Vec<String> = files_by_command
+        .into_iter()
+        .filter(|(_, files)| files.len() > 1)
+        .map(|(command, files)| format!("`{command}` ({})", files.join(", ")))
+        .collect();
+    if !collisions.is_empty() {
+        anyhow::bail!(
+            "Commands with the same name are defined in multiple files: {}",
+            collisions.join("; ")
+        );
+    }
+    Ok(())
+}
+
+/// Assigns the output name (used for generated file and class names) of each Rust file.
+///
+/// The file stem is used by default (`mod.rs` uses its parent directory name). When several
+/// files in different directories would get the same name, each of them is named after its
+/// path relative to the input directory instead (e.g. `admin/user.rs` -> `admin_user`).
+fn assign_output_names(input_dir: &Path, rust_files: &[PathBuf]) -> Vec<(PathBuf, String)> {
+    let relative_stem_components = |path: &Path| -> Vec<String> {
+        let relative = path.strip_prefix(input_dir).unwrap_or(path);
+        let mut components: Vec<String> = relative
+            .with_extension("")
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        // mod.rs はディレクトリ名で代表する
+        if components.len() > 1 && components.last().is_some_and(|last| last == "mod") {
+            components.pop();
+        }
+        components
+    };
+
+    let base_names: Vec<String> = rust_files
+        .iter()
+        .map(|path| relative_stem_components(path).pop().unwrap_or_default())
+        .collect();
+
+    let mut used_names = HashSet::new();
+    rust_files
+        .iter()
+        .zip(&base_names)
+        .map(|(path, base_name)| {
+            let is_collision = base_names.iter().filter(|name| *name == base_name).count() > 1;
+            let mut name = if is_collision {
+                relative_stem_components(path).join("_")
+            } else {
+                base_name.clone()
+            };
+            // パスから作った名前が他のファイル名と衝突した場合は連番を付ける
+            let mut suffix = 2;
+            while !used_names.insert(name.clone()) {
+                name = format!("{}_{suffix}", relative_stem_components(path).join("_"));
+                suffix += 1;
+            }
+            if name != *base_name {
+                warn!("Multiple input files are named {base_name}; generating {path:?} as {name}");
+            }
+            (path.clone(), name)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::banner::strip_banner;
+    use tempfile::tempdir;
+
+    // Helper function to create a dummy Rust file
+    fn create_dummy_rust_file(dir: &std::path::Path, file_name: &str, content: &str) -> PathBuf {
+        let file_path = dir.join(file_name);
+        std::fs::write(&file_path, content).expect("Failed to write dummy Rust file");
+        file_path
+    }
+
+    #[test]
+    fn test_run_app_success() {
+        let input_dir = tempdir().expect("Failed to create temp input dir");
+        let output_dir = tempdir().expect("Failed to create temp output dir");
+
+        create_dummy_rust_file(
+            input_dir.path(),
+            "test_commands.rs",
+            r#"
+                #[tauri::command]
+                fn greet(name: String) -> String {
+                    format!("Hello, {}!", name)
+                }
+            "#,
+        );
+
+        let cli = Cli {
+            config: None,
+            input_path: Some(input_dir.path().to_str().unwrap().to_string()),
+            output_path: Some(output_dir.path().to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+
+        let result = run_app(cli);
+        assert!(result.is_ok());
+
+        // Verify generated files exist
+        assert!(
+            output_dir
+                .path()
+                .join("tauria-api")
+                .join("commands")
+                .join("TestCommands.ts")
+                .exists()
+        );
+        assert!(
+            output_dir
+                .path()
+                .join("interface")
+                .join("commands")
+                .join("TestCommands.ts")
+                .exists()
+        );
+        assert!(output_dir.path().join("index.ts").exists());
+    }
+
+    #[test]
+    fn test_run_app_input_dir_not_found() {
+        let input_dir = PathBuf::from("/nonexistent/input/dir");
+        let output_dir = tempdir().expect("Failed to create temp output dir");
+
+        let cli = Cli {
+            config: None,
+            input_path: Some(input_dir.to_str().unwrap().to_string()),
+            output_path: Some(output_dir.path().to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+
+        let result = run_app(cli);
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Failed to read input directory")
+        );
+    }
+
+    #[test]
+    fn test_run_app_print_config() {
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let output_dir = temp_dir.path().join("bindings");
+
+        let cli = Cli {
+            input_path: Some("/nonexistent/input/dir".to_string()),
+            output_path: Some(output_dir.to_str().unwrap().to_string()),
+            print_config: true,
+            ..Default::default()
+        };
+
+        // 設定を表示するだけで、入力の読み込みや出力ディレクトリの作成は行わない
+        run_app(cli).expect("--print-config should not generate anything");
+        assert!(!output_dir.exists());
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn test_run_app_output_dir_creation_failure() {
+        // Simulate a directory that cannot be created (e.g., due to permissions)
+        // This is tricky to test reliably without actual permission issues.
+        // For now, we'll rely on the `fs::create_dir_all`'s error handling.
+        // A more robust test might involve mocking `fs` operations, but that's beyond current scope.
+
+        let input_dir = tempdir().expect("Failed to create temp input dir");
+        create_dummy_rust_file(
+            input_dir.path(),
+            "test_commands.rs",
+            r#"
+                #[tauri::command]
+                fn greet(name: String) -> String {
+                    format!("Hello, {}!", name)
+                }
+            "#,
+        );
+
+        // Attempt to create output in a read-only location (e.g., root on Unix-like systems)
+        // This test might require specific OS permissions to fail as expected.
+        let output_dir = PathBuf::from("/root/nonexistent_output"); // This path is usually not writable by normal users
+
+        let cli = Cli {
+            config: None,
+            input_path: Some(input_dir.path().to_str().unwrap().to_string()),
+            output_path: Some(output_dir.to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+
+        let result = run_app(cli);
+        assert!(result.is_err());
+        // The error message might vary by OS, but should indicate a creation/permission issue
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Failed to create output directory")
+        );
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn test_run_app_file_read_failure() {
+        let input_dir = tempdir().expect("Failed to create temp input dir");
+        let output_dir = tempdir().expect("Failed to create temp output dir");
+
+        // Create a file that cannot be read (e.g., due to permissions)
+        let file_path = input_dir.path().join("unreadable.rs");
+        std::fs::write(&file_path, "// some content").expect("Failed to write file");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&file_path, PermissionsExt::from_mode(0o000)).unwrap();
+        }
+
+        let cli = Cli {
+            config: None,
+            input_path: Some(input_dir.path().to_str().unwrap().to_string()),
+            output_path: Some(output_dir.path().to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+
+        let result = run_app(cli);
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Failed to read file")
+        );
+    }
+
+    // Helper function to list all files under a directory as sorted, '/'-separated relative paths
+    fn list_relative_files(root: &std::path::Path) -> Vec<String> {
+        fn walk(root: &std::path::Path, dir: &std::path::Path, files: &mut Vec<String>) {
+            for entry in std::fs::read_dir(dir).expect("Failed to read directory") {
+                let path = entry.expect("Failed to read directory entry").path();
+                if path.is_dir() {
+                    walk(root, &path, files);
+                } else {
+                    let relative = path.strip_prefix(root).unwrap();
+                    let components: Vec<_> = relative
+                        .components()
+                        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+                        .collect();
+                    files.push(components.join("/"));
+                }
+            }
+        }
+
+        let mut files = Vec::new();
+        walk(root, root, &mut files);
+        files.sort();
+        files
+    }
+
+    #[test]
+    fn test_run_app_ignores_non_rust_files() {
+        let input_dir = tempdir().expect("Failed to create temp input dir");
+        let output_dir = tempdir().expect("Failed to create temp output dir");
+
+        create_dummy_rust_file(
+            input_dir.path(),
+            "basic.rs",
+            r#"
+                #[tauri::command]
+                fn greet(name: String) -> String {
+                    format!("Hello, {}!", name)
+                }
+            "#,
+        );
+        create_dummy_rust_file(input_dir.path(), "Cargo.toml", "[package]\nname = \"app\"");
+        create_dummy_rust_file(input_dir.path(), "README.md", "#[tauri::command]");
+
+        let cli = Cli {
+            config: None,
+            input_path: Some(input_dir.path().to_str().unwrap().to_string()),
+            output_path: Some(output_dir.path().to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+
+        run_app(cli).expect("run_app failed");
+
+        assert_eq!(
+            list_relative_files(&output_dir.path().join("interface").join("commands")),
+            vec!["Basic.ts"]
+        );
+        assert_eq!(
+            list_relative_files(&output_dir.path().join("tauria-api").join("commands")),
+            vec!["Basic.ts"]
+        );
+    }
+
+    #[test]
+    fn test_run_app_recursive_directories() {
+        let input_dir = tempdir().expect("Failed to create temp input dir");
+        let output_dir = tempdir().expect("Failed to create temp output dir");
+        let commands_dir = input_dir.path().join("commands");
+        std::fs::create_dir_all(&commands_dir).expect("Failed to create commands dir");
+
+        create_dummy_rust_file(
+            &commands_dir,
+            "basic.rs",
+            r#"
+                #[tauri::command]
+                fn greet(name: String) -> String {
+                    format!("Hello, {}!", name)
+                }
+            "#,
+        );
+        create_dummy_rust_file(
+            &commands_dir,
+            "auth.rs",
+            r#"
+                #[tauri::command]
+                fn login(user: String, password: String) -> bool {
+                    true
+                }
+            "#,
+        );
+        create_dummy_rust_file(input_dir.path(), "Cargo.toml", "[package]\nname = \"app\"");
+        create_dummy_rust_file(&commands_dir, "NOTES.md", "#[tauri::command]");
+
+        let cli = Cli {
+            config: None,
+            input_path: Some(input_dir.path().to_str().unwrap().to_string()),
+            output_path: Some(output_dir.path().to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+
+        run_app(cli).expect("run_app failed");
+
+        // Both nested input files are generated, and nothing else
+        let interface_files =
+            list_relative_files(&output_dir.path().join("interface").join("commands"));
+        assert_eq!(interface_files.len(), 2, "{interface_files:?}");
+        assert!(interface_files.iter().any(|f| f.ends_with("Basic.ts")));
+        assert!(interface_files.iter().any(|f| f.ends_with("Auth.ts")));
+        let api_files = list_relative_files(&output_dir.path().join("tauria-api").join("commands"));
+        assert_eq!(api_files.len(), 2, "{api_files:?}");
+
+        // The index files re-export the commands of both files
+        let interface_index =
+            std::fs::read_to_string(output_dir.path().join("interface").join("index.ts")).unwrap();
+        let api_index =
+            std::fs::read_to_string(output_dir.path().join("tauria-api").join("index.ts")).unwrap();
+        for index in [&interface_index, &api_index] {
+            assert!(index.contains("Basic\";"), "{index}");
+            assert!(index.contains("Auth\";"), "{index}");
+        }
+
+        let interface_basic = std::fs::read_to_string(
+            output_dir.path().join("interface").join("commands").join(
+                interface_files
+                    .iter()
+                    .find(|f| f.ends_with("Basic.ts"))
+                    .unwrap(),
+            ),
+        )
+        .unwrap();
+        assert!(interface_basic.contains("greet"));
+        assert!(!interface_basic.contains("login"));
+    }
+
+    #[test]
+    fn test_watch_regenerates_on_file_change() {
+        let input_dir = tempdir().expect("Failed to create temp input dir");
+        let output_dir = tempdir().expect("Failed to create temp output dir");
+        let options = GenerateOptions::default();
+        let rust_file = create_dummy_rust_file(
+            input_dir.path(),
+            "commands.rs",
+            "#[tauri::command]\nfn greet() -> String { String::new() }",
+        );
+        let interface_file = output_dir
+            .path()
+            .join("interface")
+            .join("commands")
+            .join("Commands.ts");
+
+        generate_bindings(
+            &InputFiles::directory(input_dir.path(), GlobSet::empty()),
+            Output::Directory(output_dir.path()),
+            &options,
+            WarningOptions::default(),
+            false,
+        )
+        .unwrap();
+        assert!(
+            !fs::read_to_string(&interface_file)
+                .unwrap()
+                .contains("farewell")
+        );
+
+        let watcher =
+            RustFileWatcher::new(input_dir.path(), std::time::Duration::from_millis(50)).unwrap();
+        std::fs::write(
+            &rust_file,
+            "#[tauri::command]\nfn greet() -> String { String::new() }\n\
+             #[tauri::command]\nfn farewell() -> String { String::new() }",
+        )
+        .unwrap();
+        assert!(
+            watcher
+                .next_change(Some(std::time::Duration::from_secs(5)))
+                .unwrap(),
+            "no change detected"
+        );
+
+        generate_bindings(
+            &InputFiles::directory(input_dir.path(), GlobSet::empty()),
+            Output::Directory(output_dir.path()),
+            &options,
+            WarningOptions::default(),
+            false,
+        )
+        .unwrap();
+        let content = fs::read_to_string(&interface_file).unwrap();
+        assert!(content.contains("greet"));
+        assert!(content.contains("farewell"));
+    }
+
+    #[test]
+    fn test_generate_bindings_dropped_argument() {
+        let input_dir = tempdir().expect("Failed to create temp input dir");
+        let output_dir = tempdir().expect("Failed to create temp output dir");
+        create_dummy_rust_file(
+            input_dir.path(),
+            "secrets.rs",
+            r#"
+            #[derive(Serialize)]
+            pub struct Secret { pub value: String }
+
+            #[tauri::command]
+            fn store_secret(name: String, secret: Secret) {}
+            "#,
+        );
+        let run = |strict: bool| {
+            generate_bindings(
+                &InputFiles::directory(input_dir.path(), GlobSet::empty()),
+                Output::Directory(output_dir.path()),
+                &GenerateOptions {
+                    strict,
+                    ..Default::default()
+                },
+                WarningOptions::default(),
+                false,
+            )
+        };
+
+        // 既定では警告のみで、引数を省いたラッパーが生成される
+        run(false).unwrap();
+        let interface =
+            fs::read_to_string(output_dir.path().join("interface/commands/Secrets.ts")).unwrap();
+        assert!(interface.contains("storeSecret(name: string): Promise<void>;"));
+
+        let error = format!("{:#}", run(true).unwrap_err());
+        assert!(error.contains(
+            "Argument `secret: T.Secret` of command `store_secret` is left out of the TypeScript wrapper because `Secret` does not derive Deserialize"
+        ));
+    }
+
+    #[test]
+    fn test_generate_bindings_strict_unresolved_type() {
+        let input_dir = tempdir().expect("Failed to create temp input dir");
+        let output_dir = tempdir().expect("Failed to create temp output dir");
+        create_dummy_rust_file(
+            input_dir.path(),
+            "users.rs",
+            r#"
+            #[derive(Serialize, Deserialize)]
+            pub struct User { pub name: String }
+
+            #[tauri::command]
+            fn get_user(location: geo::Point) -> User { todo!() }
+            "#,
+        );
+        // 他のファイルで定義された型は解決済みとして扱う
+        create_dummy_rust_file(
+            input_dir.path(),
+            "groups.rs",
+            "#[tauri::command]\nfn get_members() -> Vec<User> { todo!() }",
+        );
+        let run = |strict: bool| {
+            generate_bindings(
+                &InputFiles::directory(input_dir.path(), GlobSet::empty()),
+                Output::Directory(output_dir.path()),
+                &GenerateOptions {
+                    strict,
+                    ..Default::default()
+                },
+                WarningOptions::default(),
+                true,
+            )
+        };
+
+        assert!(run(false).is_ok());
+        let error = format!("{:#}", run(true).unwrap_err());
+        assert!(error.contains("`T.Point` does not refer to an exported type"));
+        assert!(!error.contains("T.User"));
+    }
+
+    #[test]
+    fn test_generate_bindings_flattened_fields() {
+        let input_dir = tempdir().expect("Failed to create temp input dir");
+        let output_dir = tempdir().expect("Failed to create temp output dir");
+        create_dummy_rust_file(
+            input_dir.path(),
+            "requests.rs",
+            r#"
+            #[derive(Serialize)]
+            pub struct Request {
+                #[serde(flatten)]
+                pub kind: Kind,
+                #[serde(flatten)]
+                pub extra: HashMap<String, String>,
+                pub name: String,
+            }
+
+            #[tauri::command]
+            fn get_request() -> Request { todo!() }
+            "#,
+        );
+        // 他のファイルで定義された enum は交差型になる
+        create_dummy_rust_file(
+            input_dir.path(),
+            "kinds.rs",
+            "#[derive(Serialize)]\npub enum Kind { A, B }",
+        );
+        let run = |strict: bool| {
+            generate_bindings(
+                &InputFiles::directory(input_dir.path(), GlobSet::empty()),
+                Output::Directory(output_dir.path()),
+                &GenerateOptions {
+                    strict,
+                    format: true,
+                    ..Default::default()
+                },
+                WarningOptions::default(),
+                false,
+            )
+        };
+
+        run(false).unwrap();
+        let types = fs::read_to_string(output_dir.path().join("interface/types/index.ts")).unwrap();
+        assert!(types.contains(
+            "export type Request = {\n    readonly name: string;\n\n    readonly extra: Record<string, string>;\n} & Kind;"
+        ));
+
+        let error = format!("{:#}", run(true).unwrap_err());
+        assert!(error.contains("Some flattened fields could not be resolved (--strict)"));
+        assert!(error.contains("Field Request.extra is flattened"));
+    }
+
+    #[test]
+    fn test_assign_output_names_resolves_collisions() {
+        let input_dir = PathBuf::from("input");
+        let rust_files = vec![
+            input_dir.join("admin").join("user.rs"),
+            input_dir.join("admin_user.rs"),
+            input_dir.join("commands").join("mod.rs"),
+            input_dir.join("lib.rs"),
+            input_dir.join("user.rs"),
+        ];
+
+        let names: Vec<String> = assign_output_names(&input_dir, &rust_files)
+            .into_iter()
+            .map(|(_, name)| name)
+            .collect();
+
+        assert_eq!(
+            names,
+            vec!["admin_user", "admin_user_2", "commands", "lib", "user"]
+        );
+    }
+
+    #[test]
+    fn test_run_app_recursive_stem_collision() {
+        let input_dir = tempdir().expect("Failed to create temp input dir");
+        let output_dir = tempdir().expect("Failed to create temp output dir");
+        let admin_dir = input_dir.path().join("admin");
+        std::fs::create_dir_all(&admin_dir).expect("Failed to create admin dir");
+
+        create_dummy_rust_file(
+            input_dir.path(),
+            "user.rs",
+            "#[tauri::command]\nfn get_user() -> String { String::new() }",
+        );
+        create_dummy_rust_file(
+            &admin_dir,
+            "user.rs",
+            "#[tauri::command]\nfn ban_user() -> bool { true }",
+        );
+
+        let cli = Cli {
+            config: None,
+            input_path: Some(input_dir.path().to_str().unwrap().to_string()),
+            output_path: Some(output_dir.path().to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+
+        run_app(cli).expect("run_app failed");
+
+        let commands_dir = output_dir.path().join("interface").join("commands");
+        assert_eq!(
+            list_relative_files(&commands_dir),
+            vec!["AdminUser.ts", "User.ts"]
+        );
+        let admin_user = std::fs::read_to_string(commands_dir.join("AdminUser.ts")).unwrap();
+        assert!(admin_user.contains("banUser"));
+        let user = std::fs::read_to_string(commands_dir.join("User.ts")).unwrap();
+        assert!(user.contains("getUser"));
+    }
+
+    #[test]
+    fn test_run_app_single_file() {
+        let input_dir =
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test/data/multiple_files_test/src");
+        let output_dir = tempdir().expect("Failed to create temp output dir");
+        let single_file = output_dir.path().join("bindings").join("tauri.ts");
+
+        let cli = Cli {
+            input_path: Some(input_dir.to_str().unwrap().to_string()),
+            single_file: Some(single_file.to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+        run_app(cli).expect("run_app failed");
+
+        // ディレクトリツリーは生成されない
+        assert_eq!(
+            list_relative_files(output_dir.path()),
+            vec!["bindings/tauri.ts"]
+        );
+
+        let content = fs::read_to_string(&single_file).unwrap();
+        for expected in [
+            "export interface User {",
+            "export interface Product {",
+            "export interface ICmd1 {",
+            "export interface ICmd2 {",
+            "getUserData(id: number): Promise<User>;",
+            "async getProductData(productId: string): Promise<Product> {",
+            "export function createCmd1(): ICmd1 {",
+            "export function createCmd2(): ICmd2 {",
+        ] {
+            assert!(
+                content.contains(expected),
+                "missing `{expected}`:\n{content}"
+            );
+        }
+
+        // 型定義がコマンドより前に出力され、ファイル間の import は含まれない
+        assert!(
+            content.find("export interface User {").unwrap()
+                < content.find("export interface ICmd1 {").unwrap()
+        );
+        assert_eq!(
+            content
+                .matches("import { invoke } from '@tauri-apps/api/core';")
+                .count(),
+            1
+        );
+        assert!(!content.contains("import * as T"));
+        assert!(!content.contains("T."));
+    }
+
+    #[test]
+    fn test_run_app_exclude() {
+        let input_dir = tempdir().expect("Failed to create temp input dir");
+        let output_dir = tempdir().expect("Failed to create temp output dir");
+        let internal_dir = input_dir.path().join("internal");
+        std::fs::create_dir_all(&internal_dir).expect("Failed to create internal dir");
+
+        create_dummy_rust_file(
+            input_dir.path(),
+            "commands.rs",
+            "#[tauri::command]\nfn greet() -> String { String::new() }",
+        );
+        create_dummy_rust_file(
+            input_dir.path(),
+            "legacy.rs",
+            "#[tauri::command]\nfn old_greet() -> String { String::new() }",
+        );
+        create_dummy_rust_file(
+            &internal_dir,
+            "debug.rs",
+            "#[tauri::command]\nfn dump() -> String { String::new() }",
+        );
+
+        let cli = Cli {
+            input_path: Some(input_dir.path().to_str().unwrap().to_string()),
+            output_path: Some(output_dir.path().to_str().unwrap().to_string()),
+            exclude: vec!["legacy.rs".to_string(), "internal/**".to_string()],
+            ..Default::default()
+        };
+        run_app(cli).expect("run_app failed");
+
+        let files = list_relative_files(output_dir.path());
+        assert!(files.contains(&"interface/commands/Commands.ts".to_string()));
+        assert!(
+            !files
+                .iter()
+                .any(|f| f.contains("Legacy") || f.contains("Debug")),
+            "excluded files were generated: {files:?}"
+        );
+
+        let interface_index =
+            fs::read_to_string(output_dir.path().join("interface").join("index.ts")).unwrap();
+        assert_eq!(
+            strip_banner(&interface_index),
+            "export * from \"./commands/Commands\";"
+        );
+    }
+
+    /// Creates `commands/user.rs`, `commands/admin/role.rs` and `main.rs`, each with one command.
+    fn create_input_tree(input_dir: &Path) {
+        let admin_dir = input_dir.join("commands").join("admin");
+        std::fs::create_dir_all(&admin_dir).expect("Failed to create admin dir");
+        for (dir, file_name, command) in [
+            (input_dir.join("commands"), "user.rs", "get_user"),
+            (admin_dir, "role.rs", "get_role"),
+            (input_dir.to_path_buf(), "main.rs", "ping"),
+        ] {
+            create_dummy_rust_file(
+                &dir,
+                file_name,
+                &format!("#[tauri::command]\nfn {command}() -> String {{ String::new() }}"),
+            );
+        }
+    }
+
+    fn generated_command_files(input_path: &Path) -> Vec<String> {
+        let output_dir = tempdir().expect("Failed to create temp output dir");
+        let cli = Cli {
+            input_path: Some(input_path.to_str().unwrap().to_string()),
+            output_path: Some(output_dir.path().to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+        run_app(cli).expect("run_app failed");
+        list_relative_files(output_dir.path())
+            .into_iter()
+            .filter(|file| file.starts_with("tauria-api/commands/"))
+            .collect()
+    }
+
+    #[test]
+    fn test_run_app_single_file_input() {
+        let input_dir = tempdir().expect("Failed to create temp input dir");
+        create_input_tree(input_dir.path());
+
+        let files = generated_command_files(&input_dir.path().join("commands").join("user.rs"));
+        assert_eq!(files, ["tauria-api/commands/User.ts"]);
+    }
+
+    #[test]
+    fn test_run_app_glob_input() {
+        let input_dir = tempdir().expect("Failed to create temp input dir");
+        create_input_tree(input_dir.path());
+
+        let files = generated_command_files(&input_dir.path().join("commands").join("**/*.rs"));
+        assert_eq!(
+            files,
+            ["tauria-api/commands/Role.ts", "tauria-api/commands/User.ts"]
+        );
+        // `*` は `/` をまたがない
+        let files = generated_command_files(&input_dir.path().join("commands").join("*.rs"));
+        assert_eq!(files, ["tauria-api/commands/User.ts"]);
+        let files = generated_command_files(&input_dir.path().join("*/admin/*.rs"));
+        assert_eq!(files, ["tauria-api/commands/Role.ts"]);
+    }
+
+    #[test]
+    fn test_run_app_invalid_input_pattern() {
+        let cli = Cli {
+            input_path: Some("src/[a.rs".to_string()),
+            output_path: Some("/tmp/output".to_string()),
+            dry_run: true,
+            ..Default::default()
+        };
+        let err = run_app(cli).unwrap_err();
+        assert!(err.to_string().contains("Invalid input pattern: src/[a.rs"));
+    }
+
+    #[test]
+    fn test_run_app_deduplicates_types_across_files() {
+        let input_dir = tempdir().expect("Failed to create temp input dir");
+        let output_dir = tempdir().expect("Failed to create temp output dir");
+
+        let product = "#[derive(Serialize, Deserialize)]\npub struct Product { pub id: u32 }\n";
+        create_dummy_rust_file(
+            input_dir.path(),
+            "cmd2.rs",
+            &format!("{product}#[tauri::command]\nfn get_product() -> Product {{ todo!() }}"),
+        );
+        create_dummy_rust_file(
+            input_dir.path(),
+            "struct_test.rs",
+            &format!(
+                "/// Same shape, other doc.\n{product}#[tauri::command]\nfn list() -> Vec<Product> {{ todo!() }}"
+            ),
+        );
+
+        let cli = Cli {
+            input_path: Some(input_dir.path().to_str().unwrap().to_string()),
+            output_path: Some(output_dir.path().to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+        run_app(cli).expect("run_app failed");
+
+        let types_index = fs::read_to_string(
+            output_dir
+                .path()
+                .join("interface")
+                .join("types")
+                .join("index.ts"),
+        )
+        .unwrap();
+        assert_eq!(
+            types_index.matches("export interface Product").count(),
+            1,
+            "{types_index}"
+        );
+    }
+
+    #[test]
+    fn test_run_app_command_name_collision() {
+        let input_dir = tempdir().expect("Failed to create temp input dir");
+        let output_dir = tempdir().expect("Failed to create temp output dir");
+        let admin_dir = input_dir.path().join("admin");
+        std::fs::create_dir_all(&admin_dir).expect("Failed to create admin dir");
+
+        let command = "#[tauri::command]\nfn get_data() -> String { String::new() }";
+        create_dummy_rust_file(input_dir.path(), "data.rs", command);
+        create_dummy_rust_file(&admin_dir, "reports.rs", command);
+
+        let cli = Cli {
+            input_path: Some(input_dir.path().to_str().unwrap().to_string()),
+            output_path: Some(output_dir.path().to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+        let message = format!("{:#}", run_app(cli).unwrap_err());
+        assert!(
+            message.contains(
+                "Commands with the same name are defined in multiple files: `get_data` (admin/reports.rs, data.rs)"
+            ),
+            "{message}"
+        );
+        // 何も書き込まれない
+        assert!(list_relative_files(output_dir.path()).is_empty());
+    }
+
+    #[test]
+    fn test_generate_bindings_dry_run() {
+        let input_dir = tempdir().expect("Failed to create temp input dir");
+        let output_root = tempdir().expect("Failed to create temp output dir");
+        let output_dir = output_root.path().join("bindings");
+        create_dummy_rust_file(
+            input_dir.path(),
+            "basic.rs",
+            "#[derive(Serialize)]\nstruct User { name: String }\n\
+             #[tauri::command]\nfn get_user() -> User { todo!() }",
+        );
+
+        let summary = generate_bindings(
+            &InputFiles::directory(input_dir.path(), GlobSet::empty()),
+            Output::Directory(&output_dir),
+            &GenerateOptions::default(),
+            WarningOptions::default(),
+            true,
+        )
+        .unwrap();
+        assert_eq!((summary.commands, summary.types), (1, 1));
+        let mut planned: Vec<String> = summary
+            .files
+            .iter()
+            .map(|path| {
+                path.strip_prefix(&output_dir)
+                    .unwrap()
+                    .to_string_lossy()
+                    .replace('\\', "/")
+            })
+            .collect();
+        planned.sort();
+        assert_eq!(
+            planned,
+            vec![
+                "index.ts",
+                "interface/commands/Basic.ts",
+                "interface/index.ts",
+                "interface/types/index.ts",
+                "tauria-api/commands/Basic.ts",
+                "tauria-api/index.ts",
+            ]
+        );
+        assert!(!output_dir.exists());
+
+        // run_app も出力ディレクトリを作成しない
+        let cli = Cli {
+            input_path: Some(input_dir.path().to_str().unwrap().to_string()),
+            output_path: Some(output_dir.to_str().unwrap().to_string()),
+            dry_run: true,
+            ..Default::default()
+        };
+        run_app(cli).unwrap();
+        assert!(!output_dir.exists());
+    }
+
+    #[test]
+    fn test_run_app_sort_order_for_types() {
+        let input_dir = tempdir().expect("Failed to create temp input dir");
+        create_dummy_rust_file(
+            input_dir.path(),
+            "shop.rs",
+            "#[derive(Serialize)]\nstruct Order { id: u32 }\n\
+             #[derive(Serialize)]\nstruct Item { id: u32 }\n\
+             #[tauri::command]\nfn get_order() -> Order { todo!() }",
+        );
+
+        for (sort_order, expected) in [
+            (SortOrder::Alphabetical, ["Item", "Order"]),
+            (SortOrder::Source, ["Order", "Item"]),
+        ] {
+            let output_dir = tempdir().expect("Failed to create temp output dir");
+            let cli = Cli {
+                input_path: Some(input_dir.path().to_str().unwrap().to_string()),
+                output_path: Some(output_dir.path().to_str().unwrap().to_string()),
+                sort_order: Some(sort_order),
+                ..Default::default()
+            };
+            run_app(cli).unwrap();
+            let types =
+                fs::read_to_string(output_dir.path().join("interface/types/index.ts")).unwrap();
+            let first = types.find(&format!("interface {}", expected[0])).unwrap();
+            let second = types.find(&format!("interface {}", expected[1])).unwrap();
+            assert!(first < second, "{sort_order:?}");
+        }
+    }
+
+    #[test]
+    fn test_generate_from_reader() {
+        let output_dir = tempdir().expect("Failed to create temp output dir");
+        let code = "#[tauri::command]\nfn greet(name: String) -> String { todo!() }";
+
+        let summary = generate_from_reader(
+            code.as_bytes(),
+            "greeting",
+            output_dir.path(),
+            &GenerateOptions::default(),
+            false,
+        )
+        .unwrap();
+
+        let command_file = output_dir.path().join("tauria-api/commands/Greeting.ts");
+        assert!(summary.files.contains(&command_file));
+        assert_eq!(summary.commands, 1);
+        assert!(
+            fs::read_to_string(&command_file)
+                .unwrap()
+                .contains("invoke('greet'")
+        );
+        // インデックスファイルは全入力ファイルから作られるため、標準入力モードでは生成しない
+        assert!(!output_dir.path().join("tauria-api/index.ts").exists());
+    }
+
+    #[test]
+    fn test_json_report_for_nesting_type_test() {
+        let output_dir = tempdir().expect("Failed to create temp output dir");
+        let summary = generate_bindings(
+            &InputFiles::directory(
+                Path::new("test/data/nesting_type_test/src"),
+                GlobSet::empty(),
+            ),
+            Output::Directory(output_dir.path()),
+            &GenerateOptions::default(),
+            WarningOptions::default(),
+            false,
+        )
+        .unwrap();
+
+        let report: serde_json::Value =
+            serde_json::from_str(&summary.render(ReportFormat::Json).unwrap()).unwrap();
+        assert_eq!(report["files_processed"], 1);
+        assert_eq!(report["commands"], 1);
+        assert_eq!(
+            report["command_names"],
+            serde_json::json!({ "nesting_type_test": ["process_message"] })
+        );
+        assert_eq!(report["types"], 4);
+        assert_eq!(report["global_events"], 0);
+        assert_eq!(report["window_events"], 0);
+        assert_eq!(
+            report["files"].as_array().unwrap().len(),
+            summary.files.len()
+        );
+
+        let text = summary.render(ReportFormat::Text).unwrap();
+        assert!(text.starts_with("Processed 1 files: 1 commands, 4 types"));
+    }
+
+    #[test]
+    fn test_generate_command_name_union_for_basic() {
+        let output_dir = tempdir().expect("Failed to create temp output dir");
+        generate(
+            Path::new("test/data/basic/src"),
+            output_dir.path(),
+            GenerateOptions::default(),
+        )
+        .unwrap();
+
+        let tauri_api_index =
+            fs::read_to_string(output_dir.path().join("tauria-api/index.ts")).unwrap();
+        assert!(tauri_api_index.contains(
+            "export type CommandName = \"add\" | \"get_user\" | \"greet\" | \"update_user\";"
+        ));
+    }
+
+    #[test]
+    fn test_generate_writes_cache_only_when_enabled() {
+        let output_dir = tempdir().expect("Failed to create temp output dir");
+        let input_dir = Path::new("test/data/basic/src");
+        // ライブラリの既定ではキャッシュファイルを出力ディレクトリに残さない
+        generate(input_dir, output_dir.path(), GenerateOptions::default()).unwrap();
+        assert!(!output_dir.path().join(cache::CACHE_FILE_NAME).exists());
+
+        let options = GenerateOptions {
+            use_cache: true,
+            ..Default::default()
+        };
+        generate(input_dir, output_dir.path(), options).unwrap();
+        assert!(output_dir.path().join(cache::CACHE_FILE_NAME).exists());
+    }
+
+    #[test]
+    fn test_generate_custom_output_dirs() {
+        let input_dir = tempdir().expect("Failed to create temp input dir");
+        fs::write(
+            input_dir.path().join("user.rs"),
+            r#"
+            #[derive(Serialize, Deserialize)]
+            pub struct User { pub name: String }
+
+            #[tauri::command]
+            fn get_user() -> User { todo!() }
+
+            fn notify(app: tauri::AppHandle, user: User) {
+                app.emit("user-changed", user).unwrap();
+            }
+            "#,
+        )
+        .unwrap();
+        let output_dir = tempdir().expect("Failed to create temp output dir");
+        let options = GenerateOptions {
+            generate_mock_api: true,
+            output_dirs: OutputDirs {
+                interface: "generated/types".to_string(),
+                tauria_api: "generated/api".to_string(),
+                mock_api: "mocks".to_string(),
+            },
+            ..Default::default()
+        };
+        generate(input_dir.path(), output_dir.path(), options).unwrap();
+
+        let read = |path: &str| fs::read_to_string(output_dir.path().join(path)).unwrap();
+        assert!(read("generated/types/commands/User.ts").contains("getUser()"));
+        assert!(read("generated/types/types/index.ts").contains("interface User"));
+        assert!(read("generated/types/index.ts").contains("export * from \"./commands/User\";"));
+        assert!(read("generated/api/commands/User.ts").contains("from '../../types/';"));
+        assert!(
+            read("generated/api/events/TauriGlobalEventHandlers.ts")
+                .contains("from \"../../types/types/index\"")
+        );
+        assert!(read("generated/api/index.ts").contains("export * from \"./commands/User\";"));
+        assert!(read("mocks/User.ts").contains("from '../generated/types/';"));
+        assert!(
+            read("mocks/events/TauriGlobalEventHandlers.ts")
+                .contains("from \"../../generated/types/types/index\"")
+        );
+        let root_index = read("index.ts");
+        assert!(root_index.contains("export * from \"./generated/api\";"));
+        assert!(root_index.contains("// export * from \"./mocks\";"));
+        for default_dir in ["interface", "tauria-api", "mock-api"] {
+            assert!(!output_dir.path().join(default_dir).exists());
+        }
+    }
+
+    #[test]
+    fn test_generate_rejects_invalid_output_dirs() {
+        let output_dir = tempdir().expect("Failed to create temp output dir");
+        let options = GenerateOptions {
+            output_dirs: OutputDirs {
+                mock_api: "tauria-api".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let result = generate(Path::new("test/data/basic/src"), output_dir.path(), options);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_generate_format() {
+        let output_dir = tempdir().expect("Failed to create temp output dir");
+        let options = GenerateOptions {
+            generate_mock_api: true,
+            format: true,
+            ..Default::default()
+        };
+        let summary = generate(
+            Path::new("test/data/event_window_many/src"),
+            output_dir.path(),
+            options,
+        )
+        .unwrap();
+
+        assert!(!summary.files.is_empty());
+        for path in &summary.files {
+            let content = fs::read_to_string(path).unwrap();
+            assert!(!content.contains("\n\n\n"), "{path:?}");
+            assert!(
+                content.ends_with(";\n") || content.ends_with("}\n"),
+                "{path:?}"
+            );
+            assert!(!content.ends_with("\n\n"), "{path:?}");
+        }
+    }
+
+    /// Returns the modification time of every file under `dir`, keyed by path.
+    fn modified_times(dir: &Path) -> Vec<(PathBuf, std::time::SystemTime)> {
+        let mut times = Vec::new();
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                times.extend(modified_times(&path));
+            } else {
+                times.push((
+                    path.clone(),
+                    fs::metadata(&path).unwrap().modified().unwrap(),
+                ));
+            }
+        }
+        times.sort();
+        times
+    }
+
+    #[test]
+    fn test_run_app_second_run_without_changes_writes_nothing() {
+        let input_dir = tempdir().expect("Failed to create temp input dir");
+        create_dummy_rust_file(
+            input_dir.path(),
+            "basic.rs",
+            "#[derive(Serialize)]\nstruct User { id: u32 }\n\
+             #[tauri::command]\nfn get_user() -> User { todo!() }",
+        );
+        let output_dir = tempdir().expect("Failed to create temp output dir");
+        let cli = || Cli {
+            input_path: Some(input_dir.path().to_str().unwrap().to_string()),
+            output_path: Some(output_dir.path().to_str().unwrap().to_string()),
+            cache: true,
+            ..Default::default()
+        };
+
+        run_app(cli()).unwrap();
+        assert!(output_dir.path().join(cache::CACHE_FILE_NAME).exists());
+        let first = modified_times(output_dir.path());
+
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        run_app(cli()).unwrap();
+        assert_eq!(modified_times(output_dir.path()), first);
+
+        // 生成ファイルが削除された場合は再生成する
+        fs::remove_file(output_dir.path().join("tauria-api/commands/Basic.ts")).unwrap();
+        run_app(cli()).unwrap();
+        assert!(
+            output_dir
+                .path()
+                .join("tauria-api/commands/Basic.ts")
+                .exists()
+        );
+    }
+
+    #[test]
+    fn test_generate_bindings_parallel_matches_sequential() {
+        let input_dir = tempdir().expect("Failed to create temp input dir");
+        for i in 0..40 {
+            let dir = input_dir.path().join(format!("group{}", i % 4));
+            std::fs::create_dir_all(&dir).expect("Failed to create input dir");
+            create_dummy_rust_file(
+                &dir,
+                &format!("module{i}.rs"),
+                &format!(
+                    "#[derive(Serialize)]\nstruct Item{i} {{ id: u32 }}\n\
+                     #[tauri::command]\nfn get_item{i}() -> Item{i} {{ todo!() }}\n\
+                     #[tauri::command]\nfn notify{i}(app: AppHandle) {{ app.emit(\"changed{i}\", {i}).unwrap(); }}"
+                ),
+            );
+        }
+
+        let generate = |threads: usize| {
+            let output_dir = tempdir().expect("Failed to create temp output dir");
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap();
+            pool.install(|| {
+                generate_bindings(
+                    &InputFiles::directory(input_dir.path(), GlobSet::empty()),
+                    Output::Directory(output_dir.path()),
+                    &GenerateOptions::default(),
+                    WarningOptions::default(),
+                    false,
+                )
+            })
+            .unwrap();
+            list_relative_files(output_dir.path())
+                .into_iter()
+                .map(|file| {
+                    let content = fs::read_to_string(output_dir.path().join(&file)).unwrap();
+                    (file, content)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let sequential = generate(1);
+        assert_eq!(sequential.len(), 40 * 2 + 5);
+        assert_eq!(generate(8), sequential);
+    }
+
+    #[test]
+    fn test_run_app_invalid_exclude_pattern() {
+        let input_dir = tempdir().expect("Failed to create temp input dir");
+        let output_dir = tempdir().expect("Failed to create temp output dir");
+        let cli = Cli {
+            input_path: Some(input_dir.path().to_str().unwrap().to_string()),
+            output_path: Some(output_dir.path().to_str().unwrap().to_string()),
+            exclude: vec!["a[".to_string()],
+            ..Default::default()
+        };
+        let err = run_app(cli).unwrap_err();
+        assert!(err.to_string().contains("Invalid exclude pattern: a["));
+    }
+
+    // #[test]
+    // fn test_run_app_invalid_file_name_non_utf8() {
+    //     let input_dir = tempdir().expect("Failed to create temp input dir");
+    //     let output_dir = tempdir().expect("Failed to create temp output dir");
+
+    //     // Create a file with a non-UTF8 name (tricky to do directly in Rust, often OS-dependent)
+    //     // This test might be difficult to make cross-platform and reliable.
+    //     // For demonstration, we'll simulate the error path if `to_str()` returns None.
+    //     // In a real scenario, you might need to use `std::os::unix::ffi::OsStrExt` for non-UTF8 paths.
+
+    //     // Simulate `to_str()` returning None by creating a path that is not valid UTF-8
+    //     // This is a bit of a hack for testing purposes.
+    //     let file_path = input_dir.path().join(std::ffi::OsString::from_vec(vec![0xff, 0xfe, 0xfd]));
+    //     std::fs::write(&file_path, "// some content").expect("Failed to write file");
+
+    //     let cli = Cli {
+    //         config: None,
+    //         input_path: Some(input_dir.path().to_str().unwrap().to_string()),
+    //         output_path: Some(output_dir.path().to_str().unwrap().to_string()),
+    //         mock_api: false,
+    //     };
+
+    //     let result = run_app(cli);
+    //     assert!(result.is_err());
+    //     assert!(result.unwrap_err().to_string().contains("Invalid file name"));
+    // }
+}
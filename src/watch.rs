@@ -0,0 +1,91 @@
+use anyhow::Context;
+use log::debug;
+use notify_debouncer_mini::notify::{RecommendedWatcher, RecursiveMode};
+use notify_debouncer_mini::{DebounceEventResult, Debouncer, new_debouncer};
+use std::path::Path;
+use std::sync::mpsc::{Receiver, RecvTimeoutError, channel};
+use std::time::Duration;
+
+/// The default debounce period, so a burst of editor saves triggers a single regeneration.
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Watches an input directory recursively for changes to `.rs` files.
+pub struct RustFileWatcher {
+    // Dropping the debouncer stops watching, so it is kept alive alongside the receiver.
+    _debouncer: Debouncer<RecommendedWatcher>,
+    events: Receiver<DebounceEventResult>,
+}
+
+impl RustFileWatcher {
+    /// Starts watching `input_dir`. Changes within `debounce` of each other are reported once.
+    pub fn new(input_dir: &Path, debounce: Duration) -> anyhow::Result<Self> {
+        let (tx, events) = channel();
+        let mut debouncer = new_debouncer(debounce, tx).context("Failed to create file watcher")?;
+        debouncer
+            .watcher()
+            .watch(input_dir, RecursiveMode::Recursive)
+            .context("Failed to watch input directory")?;
+        Ok(Self {
+            _debouncer: debouncer,
+            events,
+        })
+    }
+
+    /// Blocks until a `.rs` file under the input directory changes.
+    pub fn wait_for_change(&self) -> anyhow::Result<()> {
+        while !self.next_change(None)? {}
+        Ok(())
+    }
+
+    /// Waits for the next batch of debounced events, or until `timeout` elapses.
+    ///
+    /// Returns `Ok(true)` if the batch contains a change to a `.rs` file.
+    pub fn next_change(&self, timeout: Option<Duration>) -> anyhow::Result<bool> {
+        let result = match timeout {
+            Some(timeout) => match self.events.recv_timeout(timeout) {
+                Ok(result) => result,
+                Err(RecvTimeoutError::Timeout) => return Ok(false),
+                Err(RecvTimeoutError::Disconnected) => {
+                    anyhow::bail!("File watcher stopped unexpectedly")
+                }
+            },
+            None => self
+                .events
+                .recv()
+                .context("File watcher stopped unexpectedly")?,
+        };
+        Self::contains_rust_file_change(result)
+    }
+
+    fn contains_rust_file_change(result: DebounceEventResult) -> anyhow::Result<bool> {
+        let events = result.context("File watcher error")?;
+        for event in &events {
+            debug!("File change detected: {:?}", event.path);
+        }
+        Ok(events
+            .iter()
+            .any(|event| event.path.extension().is_some_and(|ext| ext == "rs")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_wait_for_change_ignores_non_rust_files() {
+        let input_dir = tempdir().expect("Failed to create temp input dir");
+        let watcher = RustFileWatcher::new(input_dir.path(), Duration::from_millis(50)).unwrap();
+
+        std::fs::write(input_dir.path().join("notes.md"), "# notes").unwrap();
+        assert!(
+            !watcher
+                .next_change(Some(Duration::from_millis(500)))
+                .unwrap()
+        );
+
+        std::fs::write(input_dir.path().join("lib.rs"), "fn main() {}").unwrap();
+        assert!(watcher.next_change(Some(Duration::from_secs(5))).unwrap());
+    }
+}
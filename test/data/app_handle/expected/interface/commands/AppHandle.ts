@@ -8,19 +8,23 @@
 export interface IAppHandle {
 
     /**
-**/
+     * @remarks Runs on the async runtime of Tauri instead of the main thread.
+     */
     testAppHandle(): Promise<string>;
 
     /**
-**/
+     * @remarks Runs on the async runtime of Tauri instead of the main thread.
+     */
     testAppHandle2(name: string): Promise<string>;
 
     /**
-**/
+     * @remarks Runs on the async runtime of Tauri instead of the main thread.
+     */
     testAppHandle3(name: string): Promise<string>;
 
     /**
-**/
+     * @remarks Runs on the async runtime of Tauri instead of the main thread.
+     */
     testAppHandle4(name: string): Promise<string>;
 
 }
\ No newline at end of file
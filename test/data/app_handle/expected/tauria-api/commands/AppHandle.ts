@@ -17,25 +17,33 @@ class AppHandle implements T.IAppHandle {
     //#region #tauri command
     
     /**
-**/
+     * @remarks Runs on the async runtime of Tauri instead of the main thread.
+     * @throws {string} when the command returns an error.
+     */
     async testAppHandle(): Promise<string> {
-        return await invoke('test_app_handle', {  });
+        return await invoke('test_app_handle');
     }
     
     /**
-**/
+     * @remarks Runs on the async runtime of Tauri instead of the main thread.
+     * @throws {string} when the command returns an error.
+     */
     async testAppHandle2(name: string): Promise<string> {
         return await invoke('test_app_handle2', { name: name });
     }
     
     /**
-**/
+     * @remarks Runs on the async runtime of Tauri instead of the main thread.
+     * @throws {string} when the command returns an error.
+     */
     async testAppHandle3(name: string): Promise<string> {
         return await invoke('test_app_handle3', { name: name });
     }
     
     /**
-**/
+     * @remarks Runs on the async runtime of Tauri instead of the main thread.
+     * @throws {string} when the command returns an error.
+     */
     async testAppHandle4(name: string): Promise<string> {
         return await invoke('test_app_handle4', { name: name });
     }
@@ -1 +1,4 @@
-export * from "./commands/AppHandle";
\ No newline at end of file
+export * from "./commands/AppHandle";
+
+/** The names of all Tauri commands, as passed to `invoke`. */
+export type CommandName = "test_app_handle" | "test_app_handle2" | "test_app_handle3" | "test_app_handle4";
\ No newline at end of file
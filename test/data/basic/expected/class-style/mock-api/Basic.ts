@@ -0,0 +1,92 @@
+// --- --- ---
+// Note: This file was automatically generated by tauria-tsgen.
+// Manually modifying it may break the interface and lead to unintended consequences. 
+// Please exercise great caution if you choose to make manual changes.
+//
+// target file : basic.rs
+
+
+import * as T from '../interface/';
+
+// The names of the mock commands of this file.
+export type BasicCommandName = keyof T.IBasic;
+
+// Overrides of the mock command responses.
+// Assign a function to change a command's response at runtime (e.g. in tests),
+// and delete it to restore the default response.
+export const basicMocks: { [K in BasicCommandName]?: T.IBasic[K] } = {};
+
+// mock command class
+// Every command resolves to a default value of its return type without calling Tauri,
+// unless it is overridden in basicMocks.
+// target file : basic.rs
+export class BasicApi implements T.IBasic {
+
+    //#region #mock command
+    
+    /**
+     * Adds two numbers.
+     * @param a The first number.
+     * @param b The second number.
+     * @returns The sum of the two numbers.
+     */
+    async add(a: number, b: number): Promise<number> {
+        const mock = basicMocks.add;
+        if (mock) {
+            return await mock(a, b);
+        }
+        return 0;
+    }
+    
+    /**
+     * Gets a user by ID.
+     * @param id The ID of the user.
+     * @returns The user with the specified ID.
+     */
+    async getUser(id: number): Promise<string> {
+        const mock = basicMocks.getUser;
+        if (mock) {
+            return await mock(id);
+        }
+        return "";
+    }
+    
+    /**
+     * Greets the user.
+     * @param name The name of the user.
+     * @returns A greeting message.
+     */
+    async greet(name: string): Promise<string> {
+        const mock = basicMocks.greet;
+        if (mock) {
+            return await mock(name);
+        }
+        return "";
+    }
+    
+    /**
+     * Updates a user.
+     * @param userName The name of the user to update.
+     * @returns A message indicating the user has been updated.
+     */
+    async updateUser(userName: string): Promise<string> {
+        const mock = basicMocks.updateUser;
+        if (mock) {
+            return await mock(userName);
+        }
+        return "";
+    }
+    
+    //#endregion
+
+
+    //#region #endregion
+    constructor() {}
+    //#endregion
+
+}
+
+// factory function
+export function createBasic(): T.IBasic {
+    return new BasicApi();
+}
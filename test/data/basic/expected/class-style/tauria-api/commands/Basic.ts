@@ -0,0 +1,67 @@
+// --- --- ---
+// Note: This file was automatically generated by tauria-tsgen.
+// Manually modifying it may break the interface and lead to unintended consequences. 
+// Please exercise great caution if you choose to make manual changes.
+//
+// target file : basic.rs
+
+
+import { invoke } from '@tauri-apps/api/core';
+import * as T from '../../interface/';
+
+// tauri command interface class 
+// target file : basic.rs
+export class BasicApi implements T.IBasic {
+
+    //#region #tauri command
+    
+    /**
+     * Adds two numbers.
+     * @param a The first number.
+     * @param b The second number.
+     * @returns The sum of the two numbers.
+     */
+    async add(a: number, b: number): Promise<number> {
+        return await invoke('add', { a: a, b: b });
+    }
+    
+    /**
+     * Gets a user by ID.
+     * @param id The ID of the user.
+     * @returns The user with the specified ID.
+     */
+    async getUser(id: number): Promise<string> {
+        return await invoke('get_user', { id: id });
+    }
+    
+    /**
+     * Greets the user.
+     * @param name The name of the user.
+     * @returns A greeting message.
+     */
+    async greet(name: string): Promise<string> {
+        return await invoke('greet', { name: name });
+    }
+    
+    /**
+     * Updates a user.
+     * @param userName The name of the user to update.
+     * @returns A message indicating the user has been updated.
+     */
+    async updateUser(userName: string): Promise<string> {
+        return await invoke('update_user', { userName: userName });
+    }
+    
+    //#endregion
+
+
+    //#region #endregion
+    constructor() {}
+    //#endregion
+
+}
+
+// factory function
+export function createBasic(): T.IBasic {
+    return new BasicApi();
+}
@@ -0,0 +1,39 @@
+// --- --- ---
+// Note: This file was automatically generated by tauria-tsgen.
+// Manually modifying it may break the interface and lead to unintended consequences. 
+// Please exercise great caution if you choose to make manual changes.
+
+
+
+export interface IBasic {
+
+    /**
+     * Adds two numbers.
+     * @param a The first number.
+     * @param b The second number.
+     * @returns The sum of the two numbers.
+     */
+    add(a: number, b: number): Promise<number>;
+
+    /**
+     * Gets a user by ID.
+     * @param id The ID of the user.
+     * @returns The user with the specified ID.
+     */
+    getUser(id: number): Promise<string>;
+
+    /**
+     * Greets the user.
+     * @param name The name of the user.
+     * @returns A greeting message.
+     */
+    greet(name: string): Promise<string>;
+
+    /**
+     * Updates a user.
+     * @param userName The name of the user to update.
+     * @returns A message indicating the user has been updated.
+     */
+    updateUser(userName: string): Promise<string>;
+
+}
\ No newline at end of file
@@ -0,0 +1,86 @@
+
+// --- --- ---
+// Note: This file was automatically generated by tauria-tsgen.
+// Manually modifying it may break the interface and lead to unintended consequences.
+// Please exercise great caution if you choose to make manual changes.
+//
+// target file : basic.rs
+
+
+import { defineStore } from 'pinia';
+import { invoke } from '@tauri-apps/api/core';
+
+
+// pinia store for tauri commands
+// target file : basic.rs
+export const defineBasicStore = defineStore('basic', {
+    state: () => ({
+        result: null as number | string | null,
+        error: null as string | null,
+        loading: false,
+    }),
+
+    actions: {
+        
+        async add(a: number, b: number): Promise<number> {
+            this.loading = true;
+            this.error = null;
+            try {
+                const result = await invoke<number>('add', { a: a, b: b });
+                this.result = result;
+                return result;
+            } catch (e) {
+                this.error = String(e);
+                throw e;
+            } finally {
+                this.loading = false;
+            }
+        },
+        
+        async getUser(id: number): Promise<string> {
+            this.loading = true;
+            this.error = null;
+            try {
+                const result = await invoke<string>('get_user', { id: id });
+                this.result = result;
+                return result;
+            } catch (e) {
+                this.error = String(e);
+                throw e;
+            } finally {
+                this.loading = false;
+            }
+        },
+        
+        async greet(name: string): Promise<string> {
+            this.loading = true;
+            this.error = null;
+            try {
+                const result = await invoke<string>('greet', { name: name });
+                this.result = result;
+                return result;
+            } catch (e) {
+                this.error = String(e);
+                throw e;
+            } finally {
+                this.loading = false;
+            }
+        },
+        
+        async updateUser(userName: string): Promise<string> {
+            this.loading = true;
+            this.error = null;
+            try {
+                const result = await invoke<string>('update_user', { userName: userName });
+                this.result = result;
+                return result;
+            } catch (e) {
+                this.error = String(e);
+                throw e;
+            } finally {
+                this.loading = false;
+            }
+        },
+        
+    },
+});
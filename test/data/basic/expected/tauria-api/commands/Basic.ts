@@ -17,27 +17,40 @@ class Basic implements T.IBasic {
     //#region #tauri command
     
     /**
-* * @brief Greets the user.* * @param name The name of the user.* * @returns A greeting message.*/
-    async greet(name: string): Promise<string> {
-        return await invoke('greet', { name: name });
-    }
-    
-    /**
-* * @brief Adds two numbers.* * @param a The first number.* * @param b The second number.* * @returns The sum of the two numbers.*/
+     * Adds two numbers.
+     * @param a The first number.
+     * @param b The second number.
+     * @returns The sum of the two numbers.
+     */
     async add(a: number, b: number): Promise<number> {
         return await invoke('add', { a: a, b: b });
     }
     
     /**
-* * @brief Gets a user by ID.* * @param id The ID of the user.* * @returns The user with the specified ID.*/
+     * Gets a user by ID.
+     * @param id The ID of the user.
+     * @returns The user with the specified ID.
+     */
     async getUser(id: number): Promise<string> {
         return await invoke('get_user', { id: id });
     }
     
     /**
-* * @brief Updates a user.* * @param user_name The name of the user to update.* * @returns A message indicating the user has been updated.*/
-    async updateUser(user_name: string): Promise<string> {
-        return await invoke('update_user', { user_name: user_name });
+     * Greets the user.
+     * @param name The name of the user.
+     * @returns A greeting message.
+     */
+    async greet(name: string): Promise<string> {
+        return await invoke('greet', { name: name });
+    }
+    
+    /**
+     * Updates a user.
+     * @param userName The name of the user to update.
+     * @returns A message indicating the user has been updated.
+     */
+    async updateUser(userName: string): Promise<string> {
+        return await invoke('update_user', { userName: userName });
     }
     
     //#endregion
@@ -1 +1,4 @@
-export * from "./commands/Basic";
\ No newline at end of file
+export * from "./commands/Basic";
+
+/** The names of all Tauri commands, as passed to `invoke`. */
+export type CommandName = "add" | "get_user" | "greet" | "update_user";
\ No newline at end of file
@@ -1,29 +1,26 @@
 //- Generated from enum_test.rs
 
-export enum Message {
-
+export type Message =
 
     /**
-* Quit the application.
-*/
-    Quit = "Quit", // TODO: Handle different enum types (tuple, struct variants)
+     * Quit the application.
+     */
+    | "Quit"
 
     /**
-* Move to a new position.
-*/
-    Move = "Move", // TODO: Handle different enum types (tuple, struct variants)
+     * Move to a new position.
+     */
+    | { Move: { x: number; y: number } }
 
     /**
-* Write a message.
-*/
-    Write = "Write", // TODO: Handle different enum types (tuple, struct variants)
+     * Write a message.
+     */
+    | { Write: string }
 
     /**
-* Change the color.
-*/
-    ChangeColor = "ChangeColor", // TODO: Handle different enum types (tuple, struct variants)
-
-
-}
+     * Change the color.
+     */
+    | { ChangeColor: [number, number, number] }
+;
 
 
@@ -17,7 +17,10 @@ class EnumTest implements T.IEnumTest {
     //#region #tauri command
     
     /**
-* * @brief Processes a given message.* * @param msg The message to process.* * @returns A string indicating the processed message.*/
+     * Processes a given message.
+     * @param msg The message to process.
+     * @returns A string indicating the processed message.
+     */
     async processMessage(msg: T.Message): Promise<string> {
         return await invoke('process_message', { msg: msg });
     }
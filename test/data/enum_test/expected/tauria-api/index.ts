@@ -1 +1,4 @@
-export * from "./commands/EnumTest";
\ No newline at end of file
+export * from "./commands/EnumTest";
+
+/** The names of all Tauri commands, as passed to `invoke`. */
+export type CommandName = "process_message";
\ No newline at end of file
@@ -0,0 +1,12 @@
+// --- --- ---
+// Note: This file was automatically generated by tauria-tsgen.
+// Manually modifying it may break the interface and lead to unintended consequences. 
+// Please exercise great caution if you choose to make manual changes.
+
+
+
+export interface IEventEmitAll {
+
+    startDownload(): Promise<void>;
+
+}
\ No newline at end of file
@@ -0,0 +1,2 @@
+export * from "./commands/EventEmitAll";
+export * from "./types/";
\ No newline at end of file
@@ -0,0 +1,14 @@
+//- Generated from event_emit_all.rs
+
+export interface Progress {
+
+
+    /**
+* 
+*/
+    percent: number;
+
+
+}
+
+
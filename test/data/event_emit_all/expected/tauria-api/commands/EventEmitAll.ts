@@ -0,0 +1,39 @@
+
+// --- --- ---
+// Note: This file was automatically generated by tauria-tsgen.
+// Manually modifying it may break the interface and lead to unintended consequences. 
+// Please exercise great caution if you choose to make manual changes.
+//
+// target file : event_emit_all.rs
+
+
+import { invoke } from '@tauri-apps/api/core';
+import * as T from '../../interface/';
+
+// tauri command interface class 
+// target file : event_emit_all.rs
+class EventEmitAll implements T.IEventEmitAll {
+
+    //#region #tauri command
+    
+    async startDownload(): Promise<void> {
+        return await invoke('start_download');
+    }
+    
+    //#endregion
+
+
+    //#region #endregion
+    private constructor() {}
+
+    static create(): T.IEventEmitAll  {
+        return new EventEmitAll();
+    }
+    //#endregion
+
+}
+
+// factory function
+export function createEventEmitAll(): T.IEventEmitAll {
+    return EventEmitAll.create();
+}
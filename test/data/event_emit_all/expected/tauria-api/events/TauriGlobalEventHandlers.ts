@@ -0,0 +1,82 @@
+import { Event, listen, once, UnlistenFn } from "@tauri-apps/api/event";
+
+
+import * as T from "../../interface/types/index"
+
+
+// The unlisten functions of the listeners registered in this file that have not been removed yet.
+const globalEventUnlistenFns = new Set<UnlistenFn>();
+
+// Records a listener so that `unlistenAll` can remove it, and returns its unlisten function.
+async function registerGlobalEventListener(listener: Promise<UnlistenFn>): Promise<UnlistenFn> {
+    const unlistenFn = await listener;
+    const unlisten: UnlistenFn = () => {
+        globalEventUnlistenFns.delete(unlisten);
+        unlistenFn();
+    };
+    globalEventUnlistenFns.add(unlisten);
+    return unlisten;
+}
+
+export abstract class TauriGlobalEventHandlers {
+    private readonly unlistenFns: Promise<UnlistenFn>[] = [];
+
+    protected constructor() {
+        
+        this.unlistenFns.push(
+            registerGlobalEventListener(listen<T.Progress>('download-progress', (event) => { this.OnDownloadProgress(event); })));
+        
+        this.unlistenFns.push(
+            registerGlobalEventListener(listen<string>('download-started', (event) => { this.OnDownloadStarted(event); })));
+        
+    }
+
+    public async Unlisten() {
+        for (const x of this.unlistenFns) {
+            (await x)();
+        }
+    }
+
+    /**
+     * Removes all listeners registered for global events, by handler instances and by the `on` / `once` functions.
+     */
+    public static unlistenAll(): void {
+        for (const unlisten of [...globalEventUnlistenFns]) {
+            unlisten();
+        }
+    }
+
+    
+    abstract OnDownloadProgress(event: Event<T.Progress>): void;
+    
+    abstract OnDownloadStarted(event: Event<string>): void;
+    
+}
+
+/**
+ * Listens to the `download-progress` event until the returned unlisten function is called.
+ */
+export function onDownloadProgress(callback: (event: Event<T.Progress>) => void): Promise<UnlistenFn> {
+    return registerGlobalEventListener(listen<T.Progress>('download-progress', callback));
+}
+
+/**
+ * Listens to the next `download-progress` event only.
+ */
+export function onceDownloadProgress(callback: (event: Event<T.Progress>) => void): Promise<UnlistenFn> {
+    return registerGlobalEventListener(once<T.Progress>('download-progress', callback));
+}
+
+/**
+ * Listens to the `download-started` event until the returned unlisten function is called.
+ */
+export function onDownloadStarted(callback: (event: Event<string>) => void): Promise<UnlistenFn> {
+    return registerGlobalEventListener(listen<string>('download-started', callback));
+}
+
+/**
+ * Listens to the next `download-started` event only.
+ */
+export function onceDownloadStarted(callback: (event: Event<string>) => void): Promise<UnlistenFn> {
+    return registerGlobalEventListener(once<string>('download-started', callback));
+}
@@ -0,0 +1,5 @@
+export * from "./commands/EventEmitAll";
+export * from "./events/TauriGlobalEventHandlers";
+
+/** The names of all Tauri commands, as passed to `invoke`. */
+export type CommandName = "start_download";
\ No newline at end of file
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, EventTarget};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Progress {
+    pub percent: u32,
+}
+
+#[tauri::command]
+fn start_download(app: AppHandle) {
+    app.emit_all("download-started", "started").unwrap();
+    app.emit_filter("download-progress", Progress { percent: 50 }, |target| {
+        matches!(target, EventTarget::WebviewWindow { label } if label == "main")
+    })
+    .unwrap();
+}
@@ -7,8 +7,6 @@
 
 export interface IEventGlobal {
 
-    /**
-**/
     appHandleCommand(): Promise<string>;
 
 }
\ No newline at end of file
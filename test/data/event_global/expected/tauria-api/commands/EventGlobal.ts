@@ -17,9 +17,10 @@ class EventGlobal implements T.IEventGlobal {
     //#region #tauri command
     
     /**
-**/
+     * @throws {string} when the command returns an error.
+     */
     async appHandleCommand(): Promise<string> {
-        return await invoke('app_handle_command', {  });
+        return await invoke('app_handle_command');
     }
     
     //#endregion
@@ -1,20 +1,43 @@
-import { Event, listen, UnlistenFn } from "@tauri-apps/api/event";
+import { Event, listen, once, UnlistenFn } from "@tauri-apps/api/event";
 
 
 
+// The unlisten functions of the listeners registered in this file that have not been removed yet.
+const globalEventUnlistenFns = new Set<UnlistenFn>();
+
+// Records a listener so that `unlistenAll` can remove it, and returns its unlisten function.
+async function registerGlobalEventListener(listener: Promise<UnlistenFn>): Promise<UnlistenFn> {
+    const unlistenFn = await listener;
+    const unlisten: UnlistenFn = () => {
+        globalEventUnlistenFns.delete(unlisten);
+        unlistenFn();
+    };
+    globalEventUnlistenFns.add(unlisten);
+    return unlisten;
+}
+
 export abstract class TauriGlobalEventHandlers {
     private readonly unlistenFns: Promise<UnlistenFn>[] = [];
 
     protected constructor() {
         
         this.unlistenFns.push(
-            listen<string>('global', (event) => { this.OnGlobal(event); }));
+            registerGlobalEventListener(listen<string>('global', (event) => { this.OnGlobal(event); })));
         
     }
 
     public async Unlisten() {
         for (const x of this.unlistenFns) {
-            await x;
+            (await x)();
+        }
+    }
+
+    /**
+     * Removes all listeners registered for global events, by handler instances and by the `on` / `once` functions.
+     */
+    public static unlistenAll(): void {
+        for (const unlisten of [...globalEventUnlistenFns]) {
+            unlisten();
         }
     }
 
@@ -22,3 +45,17 @@ export abstract class TauriGlobalEventHandlers {
     abstract OnGlobal(event: Event<string>): void;
     
 }
+
+/**
+ * Listens to the `global` event until the returned unlisten function is called.
+ */
+export function onGlobal(callback: (event: Event<string>) => void): Promise<UnlistenFn> {
+    return registerGlobalEventListener(listen<string>('global', callback));
+}
+
+/**
+ * Listens to the next `global` event only.
+ */
+export function onceGlobal(callback: (event: Event<string>) => void): Promise<UnlistenFn> {
+    return registerGlobalEventListener(once<string>('global', callback));
+}
@@ -1,2 +1,5 @@
 export * from "./commands/EventGlobal";
-export * from "./events/TauriGlobalEventHandlers";
\ No newline at end of file
+export * from "./events/TauriGlobalEventHandlers";
+
+/** The names of all Tauri commands, as passed to `invoke`. */
+export type CommandName = "app_handle_command";
\ No newline at end of file
@@ -7,8 +7,6 @@
 
 export interface IEventTest {
 
-    /**
-**/
     eventTestCommand(): Promise<void>;
 
 }
\ No newline at end of file
@@ -6,7 +6,7 @@ export interface EventPayload {
     /**
 * 
 */
-    message: string;
+    readonly message: string;
 
 
 }
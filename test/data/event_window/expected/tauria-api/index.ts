@@ -1,2 +1,5 @@
 export * from "./commands/EventTest";
-export * from "./events/TauriMainWindowEventHandlers";
\ No newline at end of file
+export * from "./events/TauriMainWindowEventHandlers";
+
+/** The names of all Tauri commands, as passed to `invoke`. */
+export type CommandName = "event_test_command";
\ No newline at end of file
@@ -7,8 +7,6 @@
 
 export interface IEventTest1 {
 
-    /**
-**/
     eventTestCommand(): Promise<void>;
 
 }
\ No newline at end of file
@@ -9,16 +9,10 @@ import * as T from "../types/index"
 
 export interface IEventWindowMany {
 
-    /**
-**/
     emitMainEvent(payload: T.MainPayload): Promise<void>;
 
-    /**
-**/
     emitSubEvent(payload: T.SubPayload): Promise<void>;
 
-    /**
-**/
     emitAnotherMainEvent(): Promise<void>;
 
 }
\ No newline at end of file
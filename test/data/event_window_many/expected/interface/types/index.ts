@@ -6,7 +6,7 @@ export interface EventPayload {
     /**
 * 
 */
-    message: string;
+    readonly message: string;
 
 
 }
@@ -20,7 +20,7 @@ export interface MainPayload {
     /**
 * 
 */
-    message: string;
+    readonly message: string;
 
     /**
 * 
@@ -16,10 +16,8 @@ class EventTest1 implements T.IEventTest1 {
 
     //#region #tauri command
     
-    /**
-**/
     async eventTestCommand(): Promise<void> {
-        return await invoke('event_test_command', {  });
+        return await invoke('event_test_command');
     }
     
     //#endregion
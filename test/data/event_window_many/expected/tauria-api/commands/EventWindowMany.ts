@@ -16,22 +16,16 @@ class EventWindowMany implements T.IEventWindowMany {
 
     //#region #tauri command
     
-    /**
-**/
     async emitMainEvent(payload: T.MainPayload): Promise<void> {
         return await invoke('emit_main_event', { payload: payload });
     }
     
-    /**
-**/
     async emitSubEvent(payload: T.SubPayload): Promise<void> {
         return await invoke('emit_sub_event', { payload: payload });
     }
     
-    /**
-**/
     async emitAnotherMainEvent(): Promise<void> {
-        return await invoke('emit_another_main_event', {  });
+        return await invoke('emit_another_main_event');
     }
     
     //#endregion
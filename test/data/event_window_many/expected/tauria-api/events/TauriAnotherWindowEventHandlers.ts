@@ -1,5 +1,18 @@
 import { Event, listen, UnlistenFn } from "@tauri-apps/api/event";
-import * as T from "../../interface/types";
+
+// The unlisten functions of the listeners registered in this file that have not been removed yet.
+const anotherWindowEventUnlistenFns = new Set<UnlistenFn>();
+
+// Records a listener so that `unlistenAll` can remove it, and returns its unlisten function.
+async function registerAnotherWindowEventListener(listener: Promise<UnlistenFn>): Promise<UnlistenFn> {
+    const unlistenFn = await listener;
+    const unlisten: UnlistenFn = () => {
+        anotherWindowEventUnlistenFns.delete(unlisten);
+        unlistenFn();
+    };
+    anotherWindowEventUnlistenFns.add(unlisten);
+    return unlisten;
+}
 
 export abstract class TauriAnotherWindowEventHandlers {
     private readonly unlistenFns: Promise<UnlistenFn>[] = [];
@@ -7,13 +20,22 @@ export abstract class TauriAnotherWindowEventHandlers {
     protected constructor() {
         
         this.unlistenFns.push(
-            listen<string>('another_main_event', (event) => { this.OnAnotherMainEvent(event); }));
+            registerAnotherWindowEventListener(listen<string>('another_main_event', (event) => { this.OnAnotherMainEvent(event); })));
         
     }
 
     public async Unlisten() {
         for (const x of this.unlistenFns) {
-            await x;
+            (await x)();
+        }
+    }
+
+    /**
+     * Removes all listeners registered by the handler instances of this window.
+     */
+    public static unlistenAll(): void {
+        for (const unlisten of [...anotherWindowEventUnlistenFns]) {
+            unlisten();
         }
     }
 
@@ -1,22 +1,45 @@
-import { Event, listen, UnlistenFn } from "@tauri-apps/api/event";
+import { Event, listen, once, UnlistenFn } from "@tauri-apps/api/event";
 
 
 import * as T from "../../interface/types/index"
 
 
+// The unlisten functions of the listeners registered in this file that have not been removed yet.
+const globalEventUnlistenFns = new Set<UnlistenFn>();
+
+// Records a listener so that `unlistenAll` can remove it, and returns its unlisten function.
+async function registerGlobalEventListener(listener: Promise<UnlistenFn>): Promise<UnlistenFn> {
+    const unlistenFn = await listener;
+    const unlisten: UnlistenFn = () => {
+        globalEventUnlistenFns.delete(unlisten);
+        unlistenFn();
+    };
+    globalEventUnlistenFns.add(unlisten);
+    return unlisten;
+}
+
 export abstract class TauriGlobalEventHandlers {
     private readonly unlistenFns: Promise<UnlistenFn>[] = [];
 
     protected constructor() {
         
         this.unlistenFns.push(
-            listen<T.SubPayload>('sub_event', (event) => { this.OnSubEvent(event); }));
+            registerGlobalEventListener(listen<T.SubPayload>('sub_event', (event) => { this.OnSubEvent(event); })));
         
     }
 
     public async Unlisten() {
         for (const x of this.unlistenFns) {
-            await x;
+            (await x)();
+        }
+    }
+
+    /**
+     * Removes all listeners registered for global events, by handler instances and by the `on` / `once` functions.
+     */
+    public static unlistenAll(): void {
+        for (const unlisten of [...globalEventUnlistenFns]) {
+            unlisten();
         }
     }
 
@@ -24,3 +47,17 @@ export abstract class TauriGlobalEventHandlers {
     abstract OnSubEvent(event: Event<T.SubPayload>): void;
     
 }
+
+/**
+ * Listens to the `sub_event` event until the returned unlisten function is called.
+ */
+export function onSubEvent(callback: (event: Event<T.SubPayload>) => void): Promise<UnlistenFn> {
+    return registerGlobalEventListener(listen<T.SubPayload>('sub_event', callback));
+}
+
+/**
+ * Listens to the next `sub_event` event only.
+ */
+export function onceSubEvent(callback: (event: Event<T.SubPayload>) => void): Promise<UnlistenFn> {
+    return registerGlobalEventListener(once<T.SubPayload>('sub_event', callback));
+}
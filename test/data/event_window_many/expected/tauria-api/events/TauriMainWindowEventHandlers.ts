@@ -1,22 +1,45 @@
 import { Event, listen, UnlistenFn } from "@tauri-apps/api/event";
 import * as T from "../../interface/types";
 
+// The unlisten functions of the listeners registered in this file that have not been removed yet.
+const mainWindowEventUnlistenFns = new Set<UnlistenFn>();
+
+// Records a listener so that `unlistenAll` can remove it, and returns its unlisten function.
+async function registerMainWindowEventListener(listener: Promise<UnlistenFn>): Promise<UnlistenFn> {
+    const unlistenFn = await listener;
+    const unlisten: UnlistenFn = () => {
+        mainWindowEventUnlistenFns.delete(unlisten);
+        unlistenFn();
+    };
+    mainWindowEventUnlistenFns.add(unlisten);
+    return unlisten;
+}
+
 export abstract class TauriMainWindowEventHandlers {
     private readonly unlistenFns: Promise<UnlistenFn>[] = [];
 
     protected constructor() {
         
         this.unlistenFns.push(
-            listen<T.MainPayload>('main_event', (event) => { this.OnMainEvent(event); }));
+            registerMainWindowEventListener(listen<T.MainPayload>('main_event', (event) => { this.OnMainEvent(event); })));
         
         this.unlistenFns.push(
-            listen<T.EventPayload>('window-event', (event) => { this.OnWindowEvent(event); }));
+            registerMainWindowEventListener(listen<T.EventPayload>('window-event', (event) => { this.OnWindowEvent(event); })));
         
     }
 
     public async Unlisten() {
         for (const x of this.unlistenFns) {
-            await x;
+            (await x)();
+        }
+    }
+
+    /**
+     * Removes all listeners registered by the handler instances of this window.
+     */
+    public static unlistenAll(): void {
+        for (const unlisten of [...mainWindowEventUnlistenFns]) {
+            unlisten();
         }
     }
 
@@ -2,4 +2,7 @@ export * from "./commands/EventTest1";
 export * from "./commands/EventWindowMany";
 export * from "./events/TauriGlobalEventHandlers";
 export * from "./events/TauriAnotherWindowEventHandlers";
-export * from "./events/TauriMainWindowEventHandlers";
\ No newline at end of file
+export * from "./events/TauriMainWindowEventHandlers";
+
+/** The names of all Tauri commands, as passed to `invoke`. */
+export type CommandName = "emit_another_main_event" | "emit_main_event" | "emit_sub_event" | "event_test_command";
\ No newline at end of file
@@ -0,0 +1,8 @@
+// This file is generated by tauria-tsgen.
+
+// You can switch between tauria-api and mock-api by modifying this file.
+
+
+export * from "./tauria-api";
+
+// export * from "./mock-api";
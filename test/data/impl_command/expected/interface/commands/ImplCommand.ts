@@ -0,0 +1,22 @@
+// --- --- ---
+// Note: This file was automatically generated by tauria-tsgen.
+// Manually modifying it may break the interface and lead to unintended consequences. 
+// Please exercise great caution if you choose to make manual changes.
+
+
+import * as T from "../types/index"
+
+
+export interface IImplCommand {
+
+    /**
+     * Increments the counter.
+     */
+    increment(counter: T.Counter): Promise<T.Counter>;
+
+    /**
+     * Resets the counter.
+     */
+    reset(): Promise<T.Counter>;
+
+}
\ No newline at end of file
@@ -0,0 +1,2 @@
+export * from "./commands/ImplCommand";
+export * from "./types/";
\ No newline at end of file
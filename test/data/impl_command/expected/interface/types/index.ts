@@ -0,0 +1,14 @@
+//- Generated from impl_command.rs
+
+export interface Counter {
+
+
+    /**
+* 
+*/
+    value: number;
+
+
+}
+
+
@@ -0,0 +1,49 @@
+
+// --- --- ---
+// Note: This file was automatically generated by tauria-tsgen.
+// Manually modifying it may break the interface and lead to unintended consequences. 
+// Please exercise great caution if you choose to make manual changes.
+//
+// target file : impl_command.rs
+
+
+import { invoke } from '@tauri-apps/api/core';
+import * as T from '../../interface/';
+
+// tauri command interface class 
+// target file : impl_command.rs
+class ImplCommand implements T.IImplCommand {
+
+    //#region #tauri command
+    
+    /**
+     * Increments the counter.
+     */
+    async increment(counter: T.Counter): Promise<T.Counter> {
+        return await invoke('increment', { counter: counter });
+    }
+    
+    /**
+     * Resets the counter.
+     */
+    async reset(): Promise<T.Counter> {
+        return await invoke('reset');
+    }
+    
+    //#endregion
+
+
+    //#region #endregion
+    private constructor() {}
+
+    static create(): T.IImplCommand  {
+        return new ImplCommand();
+    }
+    //#endregion
+
+}
+
+// factory function
+export function createImplCommand(): T.IImplCommand {
+    return ImplCommand.create();
+}
@@ -0,0 +1,63 @@
+import { Event, listen, once, UnlistenFn } from "@tauri-apps/api/event";
+
+
+import * as T from "../../interface/types/index"
+
+
+// The unlisten functions of the listeners registered in this file that have not been removed yet.
+const globalEventUnlistenFns = new Set<UnlistenFn>();
+
+// Records a listener so that `unlistenAll` can remove it, and returns its unlisten function.
+async function registerGlobalEventListener(listener: Promise<UnlistenFn>): Promise<UnlistenFn> {
+    const unlistenFn = await listener;
+    const unlisten: UnlistenFn = () => {
+        globalEventUnlistenFns.delete(unlisten);
+        unlistenFn();
+    };
+    globalEventUnlistenFns.add(unlisten);
+    return unlisten;
+}
+
+export abstract class TauriGlobalEventHandlers {
+    private readonly unlistenFns: Promise<UnlistenFn>[] = [];
+
+    protected constructor() {
+        
+        this.unlistenFns.push(
+            registerGlobalEventListener(listen<T.Counter>('counter-changed', (event) => { this.OnCounterChanged(event); })));
+        
+    }
+
+    public async Unlisten() {
+        for (const x of this.unlistenFns) {
+            (await x)();
+        }
+    }
+
+    /**
+     * Removes all listeners registered for global events, by handler instances and by the `on` / `once` functions.
+     */
+    public static unlistenAll(): void {
+        for (const unlisten of [...globalEventUnlistenFns]) {
+            unlisten();
+        }
+    }
+
+    
+    abstract OnCounterChanged(event: Event<T.Counter>): void;
+    
+}
+
+/**
+ * Listens to the `counter-changed` event until the returned unlisten function is called.
+ */
+export function onCounterChanged(callback: (event: Event<T.Counter>) => void): Promise<UnlistenFn> {
+    return registerGlobalEventListener(listen<T.Counter>('counter-changed', callback));
+}
+
+/**
+ * Listens to the next `counter-changed` event only.
+ */
+export function onceCounterChanged(callback: (event: Event<T.Counter>) => void): Promise<UnlistenFn> {
+    return registerGlobalEventListener(once<T.Counter>('counter-changed', callback));
+}
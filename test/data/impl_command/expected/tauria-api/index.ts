@@ -0,0 +1,5 @@
+export * from "./commands/ImplCommand";
+export * from "./events/TauriGlobalEventHandlers";
+
+/** The names of all Tauri commands, as passed to `invoke`. */
+export type CommandName = "increment" | "reset";
\ No newline at end of file
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use tauri::Emitter;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Counter {
+    pub value: i32,
+}
+
+pub struct CounterCommands;
+
+impl CounterCommands {
+    /// Increments the counter.
+    #[tauri::command]
+    pub fn increment(counter: Counter, app: tauri::AppHandle) -> Counter {
+        let counter = Counter {
+            value: counter.value + 1,
+        };
+        app.emit("counter-changed", counter.clone()).unwrap();
+        counter
+    }
+
+    /// Not a command.
+    pub fn helper() -> i32 {
+        0
+    }
+}
+
+/// Resets the counter.
+#[tauri::command]
+pub fn reset() -> Counter {
+    Counter { value: 0 }
+}
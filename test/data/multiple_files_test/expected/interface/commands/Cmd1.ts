@@ -10,9 +10,14 @@ import * as T from "../types/index"
 export interface ICmd1 {
 
     /**
-* ユーザー情報を取得するTauriコマンド**
-     * # 引数* * `id` - ユーザーのID**
-     * # 戻り値* 指定されたIDに対応するユーザー情報（ダミーデータ）*/
+     * ユーザー情報を取得するTauriコマンド
+     *
+     * # 引数
+     * * `id` - ユーザーのID
+     *
+     * # 戻り値
+     * 指定されたIDに対応するユーザー情報（ダミーデータ）
+     */
     getUserData(id: number): Promise<T.User>;
 
 }
\ No newline at end of file
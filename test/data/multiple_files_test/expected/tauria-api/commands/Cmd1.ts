@@ -17,9 +17,14 @@ class Cmd1 implements T.ICmd1 {
     //#region #tauri command
     
     /**
-* ユーザー情報を取得するTauriコマンド**
-     * # 引数* * `id` - ユーザーのID**
-     * # 戻り値* 指定されたIDに対応するユーザー情報（ダミーデータ）*/
+     * ユーザー情報を取得するTauriコマンド
+     *
+     * # 引数
+     * * `id` - ユーザーのID
+     *
+     * # 戻り値
+     * 指定されたIDに対応するユーザー情報（ダミーデータ）
+     */
     async getUserData(id: number): Promise<T.User> {
         return await invoke('get_user_data', { id: id });
     }
@@ -17,11 +17,16 @@ class Cmd2 implements T.ICmd2 {
     //#region #tauri command
     
     /**
-* 商品情報を取得するTauriコマンド**
-     * # 引数* * `product_id` - 商品の識別子**
-     * # 戻り値* 指定された商品IDに対応する商品情報（ダミーデータ）*/
-    async getProductData(product_id: string): Promise<T.Product> {
-        return await invoke('get_product_data', { product_id: product_id });
+     * 商品情報を取得するTauriコマンド
+     *
+     * # 引数
+     * * `product_id` - 商品の識別子
+     *
+     * # 戻り値
+     * 指定された商品IDに対応する商品情報（ダミーデータ）
+     */
+    async getProductData(productId: string): Promise<T.Product> {
+        return await invoke('get_product_data', { productId: productId });
     }
     
     //#endregion
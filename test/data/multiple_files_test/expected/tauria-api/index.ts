@@ -1,2 +1,5 @@
 export * from "./commands/Cmd1";
-export * from "./commands/Cmd2";
\ No newline at end of file
+export * from "./commands/Cmd2";
+
+/** The names of all Tauri commands, as passed to `invoke`. */
+export type CommandName = "get_product_data" | "get_user_data";
\ No newline at end of file
@@ -1 +1,4 @@
-export * from "./commands/Cmd";
\ No newline at end of file
+export * from "./commands/Cmd";
+
+/** The names of all Tauri commands, as passed to `invoke`. */
+export type CommandName = "get_user_data";
\ No newline at end of file
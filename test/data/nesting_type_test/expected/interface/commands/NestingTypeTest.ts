@@ -10,7 +10,10 @@ import * as T from "../types/index"
 export interface INestingTypeTest {
 
     /**
-* * @brief Processes a given message.* * @param msg The message to process.* * @returns A string indicating the processed message.*/
+     * Processes a given message.
+     * @param msg The message to process.
+     * @returns A string indicating the processed message.
+     */
     processMessage(msg: T.Data): Promise<string>;
 
 }
\ No newline at end of file
@@ -17,7 +17,10 @@ class NestingTypeTest implements T.INestingTypeTest {
     //#region #tauri command
     
     /**
-* * @brief Processes a given message.* * @param msg The message to process.* * @returns A string indicating the processed message.*/
+     * Processes a given message.
+     * @param msg The message to process.
+     * @returns A string indicating the processed message.
+     */
     async processMessage(msg: T.Data): Promise<string> {
         return await invoke('process_message', { msg: msg });
     }
@@ -1 +1,4 @@
-export * from "./commands/NestingTypeTest";
\ No newline at end of file
+export * from "./commands/NestingTypeTest";
+
+/** The names of all Tauri commands, as passed to `invoke`. */
+export type CommandName = "process_message";
\ No newline at end of file
@@ -7,12 +7,8 @@
 
 export interface IResponse {
 
-    /**
-**/
     readFile1(): Promise<unknown>;
 
-    /**
-**/
     readFile2(): Promise<unknown>;
 
 }
\ No newline at end of file
@@ -16,16 +16,12 @@ class Response implements T.IResponse {
 
     //#region #tauri command
     
-    /**
-**/
     async readFile1(): Promise<unknown> {
-        return await invoke('read_file1', {  });
+        return await invoke('read_file1');
     }
     
-    /**
-**/
     async readFile2(): Promise<unknown> {
-        return await invoke('read_file2', {  });
+        return await invoke('read_file2');
     }
     
     //#endregion
@@ -1 +1,4 @@
-export * from "./commands/Response";
\ No newline at end of file
+export * from "./commands/Response";
+
+/** The names of all Tauri commands, as passed to `invoke`. */
+export type CommandName = "read_file1" | "read_file2";
\ No newline at end of file
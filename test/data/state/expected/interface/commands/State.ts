@@ -7,16 +7,10 @@
 
 export interface IState {
 
-    /**
-**/
     testState(): Promise<void>;
 
-    /**
-**/
     testState2(name: string): Promise<void>;
 
-    /**
-**/
     testState3(name: string): Promise<void>;
 
 }
\ No newline at end of file
@@ -16,20 +16,14 @@ class State implements T.IState {
 
     //#region #tauri command
     
-    /**
-**/
     async testState(): Promise<void> {
-        return await invoke('test_state', {  });
+        return await invoke('test_state');
     }
     
-    /**
-**/
     async testState2(name: string): Promise<void> {
         return await invoke('test_state2', { name: name });
     }
     
-    /**
-**/
     async testState3(name: string): Promise<void> {
         return await invoke('test_state3', { name: name });
     }
@@ -1 +1,4 @@
-export * from "./commands/State";
\ No newline at end of file
+export * from "./commands/State";
+
+/** The names of all Tauri commands, as passed to `invoke`. */
+export type CommandName = "test_state" | "test_state2" | "test_state3";
\ No newline at end of file
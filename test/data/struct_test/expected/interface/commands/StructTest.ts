@@ -10,15 +10,25 @@ import * as T from "../types/index"
 export interface IStructTest {
 
     /**
-* ユーザー情報を取得するTauriコマンド**
-     * # 引数* * `id` - ユーザーのID**
-     * # 戻り値* 指定されたIDに対応するユーザー情報（ダミーデータ）*/
-    getUserData(id: number): Promise<T.User>;
+     * 商品情報を取得するTauriコマンド
+     *
+     * # 引数
+     * * `product_id` - 商品の識別子
+     *
+     * # 戻り値
+     * 指定された商品IDに対応する商品情報（ダミーデータ）
+     */
+    getProductData(productId: string): Promise<T.Product>;
 
     /**
-* 商品情報を取得するTauriコマンド**
-     * # 引数* * `product_id` - 商品の識別子**
-     * # 戻り値* 指定された商品IDに対応する商品情報（ダミーデータ）*/
-    getProductData(product_id: string): Promise<T.Product>;
+     * ユーザー情報を取得するTauriコマンド
+     *
+     * # 引数
+     * * `id` - ユーザーのID
+     *
+     * # 戻り値
+     * 指定されたIDに対応するユーザー情報（ダミーデータ）
+     */
+    getUserData(id: number): Promise<T.User>;
 
 }
\ No newline at end of file
@@ -4,18 +4,18 @@ export interface Product {
 
 
     /**
-* 商品ID（ユニークな識別子）
-*/
+     * 商品ID（ユニークな識別子）
+     */
     product_id: string;
 
     /**
-* 商品の価格（小数対応）
-*/
+     * 商品の価格（小数対応）
+     */
     price: number;
 
     /**
-* 在庫数（単位数）
-*/
+     * 在庫数（単位数）
+     */
     quantity: number;
 
 
@@ -28,19 +28,19 @@ export interface User {
 
 
     /**
-* ユーザーID（ユニークな識別子）
-*/
+     * ユーザーID（ユニークな識別子）
+     */
     id: number;
 
     /**
-* ユーザーの名前
-*/
+     * ユーザーの名前
+     */
     name: string;
 
     /**
-* ユーザーのメールアドレス（オプション）
-*/
-    email: string | undefined;
+     * ユーザーのメールアドレス（オプション）
+     */
+    email?: string;
 
 
 }
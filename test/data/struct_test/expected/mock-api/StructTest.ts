@@ -0,0 +1,78 @@
+
+// --- --- ---
+// Note: This file was automatically generated by tauria-tsgen.
+// Manually modifying it may break the interface and lead to unintended consequences. 
+// Please exercise great caution if you choose to make manual changes.
+//
+// target file : struct_test.rs
+
+
+import * as T from '../interface/';
+
+// The names of the mock commands of this file.
+export type StructTestCommandName = keyof T.IStructTest;
+
+// Overrides of the mock command responses.
+// Assign a function to change a command's response at runtime (e.g. in tests),
+// and delete it to restore the default response.
+export const structTestMocks: { [K in StructTestCommandName]?: T.IStructTest[K] } = {};
+
+// mock command class
+// Every command resolves to a default value of its return type without calling Tauri,
+// unless it is overridden in structTestMocks.
+// target file : struct_test.rs
+class StructTest implements T.IStructTest {
+
+    //#region #mock command
+    
+    /**
+     * 商品情報を取得するTauriコマンド
+     *
+     * # 引数
+     * * `product_id` - 商品の識別子
+     *
+     * # 戻り値
+     * 指定された商品IDに対応する商品情報（ダミーデータ）
+     */
+    async getProductData(productId: string): Promise<T.Product> {
+        const mock = structTestMocks.getProductData;
+        if (mock) {
+            return await mock(productId);
+        }
+        return { product_id: "", price: 0, quantity: 0 };
+    }
+    
+    /**
+     * ユーザー情報を取得するTauriコマンド
+     *
+     * # 引数
+     * * `id` - ユーザーのID
+     *
+     * # 戻り値
+     * 指定されたIDに対応するユーザー情報（ダミーデータ）
+     */
+    async getUserData(id: number): Promise<T.User> {
+        const mock = structTestMocks.getUserData;
+        if (mock) {
+            return await mock(id);
+        }
+        return { id: 0, name: "" };
+    }
+    
+    //#endregion
+
+
+    //#region #endregion
+    private constructor() {}
+
+    static create(): T.IStructTest  {
+        return new StructTest();
+    }
+    //#endregion
+
+}
+
+// factory function
+export function createStructTest(): T.IStructTest {
+    return StructTest.create();
+}
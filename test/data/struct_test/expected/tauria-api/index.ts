@@ -1 +1,4 @@
-export * from "./commands/StructTest";
\ No newline at end of file
+export * from "./commands/StructTest";
+
+/** The names of all Tauri commands, as passed to `invoke`. */
+export type CommandName = "get_product_data" | "get_user_data";
\ No newline at end of file
@@ -0,0 +1,22 @@
+// --- --- ---
+// Note: This file was automatically generated by tauria-tsgen.
+// Manually modifying it may break the interface and lead to unintended consequences. 
+// Please exercise great caution if you choose to make manual changes.
+
+
+import * as T from "../types/index"
+
+
+export interface ITaggedEnumTest {
+
+    /**
+     * Computes the area of a shape.
+     */
+    area(shape: T.Shape): Promise<number>;
+
+    /**
+     * Sends a request.
+     */
+    sendRequest(request: T.Request): Promise<string>;
+
+}
\ No newline at end of file
@@ -0,0 +1,2 @@
+export * from "./commands/TaggedEnumTest";
+export * from "./types/";
\ No newline at end of file
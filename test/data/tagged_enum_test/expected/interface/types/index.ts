@@ -0,0 +1,60 @@
+//- Generated from tagged_enum_test.rs
+
+export interface Point {
+
+
+    x: number;
+
+    y: number;
+
+
+}
+
+
+//- Generated from tagged_enum_test.rs
+
+export type Request =
+
+    /**
+     * Ping the server.
+     */
+    | { kind: "Ping" }
+
+    /**
+     * Echo a message.
+     */
+    | { kind: "Echo"; data: string }
+
+    /**
+     * Move to a position.
+     */
+    | { kind: "Move"; data: { x: number; y: number } }
+
+    /**
+     * Resize a window.
+     */
+    | { kind: "Resize"; data: [number, number] }
+;
+
+
+//- Generated from tagged_enum_test.rs
+
+export type Shape =
+
+    /**
+     * An empty shape.
+     */
+    | { type: "Empty" }
+
+    /**
+     * A circle.
+     */
+    | { type: "Circle"; radius: number }
+
+    /**
+     * A single point.
+     */
+    | { type: "Dot" } & Point
+;
+
+
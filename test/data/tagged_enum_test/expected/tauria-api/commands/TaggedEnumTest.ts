@@ -0,0 +1,49 @@
+
+// --- --- ---
+// Note: This file was automatically generated by tauria-tsgen.
+// Manually modifying it may break the interface and lead to unintended consequences. 
+// Please exercise great caution if you choose to make manual changes.
+//
+// target file : tagged_enum_test.rs
+
+
+import { invoke } from '@tauri-apps/api/core';
+import * as T from '../../interface/';
+
+// tauri command interface class 
+// target file : tagged_enum_test.rs
+class TaggedEnumTest implements T.ITaggedEnumTest {
+
+    //#region #tauri command
+    
+    /**
+     * Computes the area of a shape.
+     */
+    async area(shape: T.Shape): Promise<number> {
+        return await invoke('area', { shape: shape });
+    }
+    
+    /**
+     * Sends a request.
+     */
+    async sendRequest(request: T.Request): Promise<string> {
+        return await invoke('send_request', { request: request });
+    }
+    
+    //#endregion
+
+
+    //#region #endregion
+    private constructor() {}
+
+    static create(): T.ITaggedEnumTest  {
+        return new TaggedEnumTest();
+    }
+    //#endregion
+
+}
+
+// factory function
+export function createTaggedEnumTest(): T.ITaggedEnumTest {
+    return TaggedEnumTest.create();
+}
@@ -0,0 +1,4 @@
+export * from "./commands/TaggedEnumTest";
+
+/** The names of all Tauri commands, as passed to `invoke`. */
+export type CommandName = "area" | "send_request";
\ No newline at end of file
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// A shape, serialized as `{ "type": "...", ...fields }`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Shape {
+    /// An empty shape.
+    Empty,
+    /// A circle.
+    Circle { radius: f64 },
+    /// A single point.
+    Dot(Point),
+}
+
+/// A request, serialized as `{ "kind": "...", "data": payload }`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "data")]
+pub enum Request {
+    /// Ping the server.
+    Ping,
+    /// Echo a message.
+    Echo(String),
+    /// Move to a position.
+    Move { x: i32, y: i32 },
+    /// Resize a window.
+    Resize(u32, u32),
+}
+
+/// Computes the area of a shape.
+#[tauri::command]
+pub fn area(shape: Shape) -> f64 {
+    match shape {
+        Shape::Empty => 0.0,
+        Shape::Circle { radius } => std::f64::consts::PI * radius * radius,
+        Shape::Dot(_) => 0.0,
+    }
+}
+
+/// Sends a request.
+#[tauri::command]
+pub fn send_request(request: Request) -> String {
+    format!("{:?}", request)
+}
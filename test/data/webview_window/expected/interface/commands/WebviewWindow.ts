@@ -8,19 +8,23 @@
 export interface IWebviewWindow {
 
     /**
-**/
+     * @remarks Runs on the async runtime of Tauri instead of the main thread.
+     */
     testWebviewWindow(): Promise<string>;
 
     /**
-**/
+     * @remarks Runs on the async runtime of Tauri instead of the main thread.
+     */
     testWebviewWindow2(name: string): Promise<string>;
 
     /**
-**/
+     * @remarks Runs on the async runtime of Tauri instead of the main thread.
+     */
     testWebviewWindow3(name: string): Promise<string>;
 
     /**
-**/
+     * @remarks Runs on the async runtime of Tauri instead of the main thread.
+     */
     testWebviewWindow4(name: string): Promise<string>;
 
 }
\ No newline at end of file
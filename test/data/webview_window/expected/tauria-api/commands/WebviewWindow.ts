@@ -17,25 +17,33 @@ class WebviewWindow implements T.IWebviewWindow {
     //#region #tauri command
     
     /**
-**/
+     * @remarks Runs on the async runtime of Tauri instead of the main thread.
+     * @throws {string} when the command returns an error.
+     */
     async testWebviewWindow(): Promise<string> {
-        return await invoke('test_webview_window', {  });
+        return await invoke('test_webview_window');
     }
     
     /**
-**/
+     * @remarks Runs on the async runtime of Tauri instead of the main thread.
+     * @throws {string} when the command returns an error.
+     */
     async testWebviewWindow2(name: string): Promise<string> {
         return await invoke('test_webview_window2', { name: name });
     }
     
     /**
-**/
+     * @remarks Runs on the async runtime of Tauri instead of the main thread.
+     * @throws {string} when the command returns an error.
+     */
     async testWebviewWindow3(name: string): Promise<string> {
         return await invoke('test_webview_window3', { name: name });
     }
     
     /**
-**/
+     * @remarks Runs on the async runtime of Tauri instead of the main thread.
+     * @throws {string} when the command returns an error.
+     */
     async testWebviewWindow4(name: string): Promise<string> {
         return await invoke('test_webview_window4', { name: name });
     }
@@ -1 +1,4 @@
-export * from "./commands/WebviewWindow";
\ No newline at end of file
+export * from "./commands/WebviewWindow";
+
+/** The names of all Tauri commands, as passed to `invoke`. */
+export type CommandName = "test_webview_window" | "test_webview_window2" | "test_webview_window3" | "test_webview_window4";
\ No newline at end of file
@@ -8,7 +8,8 @@
 export interface IWindow {
 
     /**
-**/
+     * @remarks Runs on the async runtime of Tauri instead of the main thread.
+     */
     myCustomCommand(): Promise<void>;
 
 }
\ No newline at end of file
@@ -17,9 +17,11 @@ class Window implements T.IWindow {
     //#region #tauri command
     
     /**
-**/
+     * @remarks Runs on the async runtime of Tauri instead of the main thread.
+     * @throws {string} when the command returns an error.
+     */
     async myCustomCommand(): Promise<void> {
-        return await invoke('my_custom_command', {  });
+        return await invoke('my_custom_command');
     }
     
     //#endregion
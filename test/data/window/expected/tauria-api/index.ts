@@ -1 +1,4 @@
-export * from "./commands/Window";
\ No newline at end of file
+export * from "./commands/Window";
+
+/** The names of all Tauri commands, as passed to `invoke`. */
+export type CommandName = "my_custom_command";
\ No newline at end of file